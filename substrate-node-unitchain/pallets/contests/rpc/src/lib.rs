@@ -0,0 +1,170 @@
+//! RPC interface for the contests pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use pallet_contests_runtime_api::{
+	ContestInfo, ContestsApi as ContestsRuntimeApi, EntryInfo, EscrowInfo, PayoutInfo,
+};
+
+/// Max number of entries returned by a single `contests_entries` call.
+const MAX_ENTRIES_PAGE: u32 = 100;
+
+/// Error code of a failed runtime API call.
+const RUNTIME_ERROR: i32 = 1;
+
+#[rpc(server)]
+pub trait ContestsApi<BlockHash, AccountId, AssetId, Balance, BlockNumber> {
+	/// Open or closed contests with `asset_id` as prize token.
+	#[method(name = "contests_byAsset")]
+	fn contests_by_asset(
+		&self,
+		asset_id: AssetId,
+		open: bool,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<ContestInfo<AccountId, AssetId, Balance, BlockNumber>>>;
+
+	/// Open or closed contests created by `creator`.
+	#[method(name = "contests_byCreator")]
+	fn contests_by_creator(
+		&self,
+		creator: AccountId,
+		open: bool,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<ContestInfo<AccountId, AssetId, Balance, BlockNumber>>>;
+
+	/// Up to `limit` entries of a contest starting at `offset`, ordered by entry id.
+	#[method(name = "contests_entries")]
+	fn contest_entries(
+		&self,
+		contest_id: u32,
+		offset: u32,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<EntryInfo<AccountId>>>;
+
+	/// Prizes of a contest still held in escrow.
+	#[method(name = "contests_escrow")]
+	fn contest_escrow(
+		&self,
+		contest_id: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<EscrowInfo<AssetId, Balance>>>;
+
+	/// Prize of each winner of a contest, 1st place first.
+	#[method(name = "contests_payouts")]
+	fn contest_payouts(
+		&self,
+		contest_id: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<PayoutInfo<AccountId, Balance, BlockNumber>>>;
+}
+
+/// Provides RPC methods to query contests.
+pub struct Contests<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Contests<C, Block> {
+	/// Create new `Contests` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+
+	fn block_id(&self, at: Option<<Block as BlockT>::Hash>) -> BlockId<Block>
+	where
+		Block: BlockT,
+		C: HeaderBackend<Block>,
+	{
+		BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash))
+	}
+}
+
+fn runtime_error(err: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		RUNTIME_ERROR,
+		"Unable to query contests.",
+		Some(format!("{:?}", err)),
+	))
+	.into()
+}
+
+#[async_trait]
+impl<C, Block, AccountId, AssetId, Balance, BlockNumber>
+	ContestsApiServer<<Block as BlockT>::Hash, AccountId, AssetId, Balance, BlockNumber>
+	for Contests<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: ContestsRuntimeApi<Block, AccountId, AssetId, Balance, BlockNumber>,
+	AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	AssetId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	Balance: Codec + Serialize + Send + Sync + 'static,
+	BlockNumber: Codec + Serialize + Send + Sync + 'static,
+{
+	fn contests_by_asset(
+		&self,
+		asset_id: AssetId,
+		open: bool,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<ContestInfo<AccountId, AssetId, Balance, BlockNumber>>> {
+		let api = self.client.runtime_api();
+
+		api.contests_by_asset(&self.block_id(at), asset_id, open).map_err(runtime_error)
+	}
+
+	fn contests_by_creator(
+		&self,
+		creator: AccountId,
+		open: bool,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<ContestInfo<AccountId, AssetId, Balance, BlockNumber>>> {
+		let api = self.client.runtime_api();
+
+		api.contests_by_creator(&self.block_id(at), creator, open).map_err(runtime_error)
+	}
+
+	fn contest_entries(
+		&self,
+		contest_id: u32,
+		offset: u32,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<EntryInfo<AccountId>>> {
+		let api = self.client.runtime_api();
+
+		api.contest_entries(&self.block_id(at), contest_id, offset, limit.min(MAX_ENTRIES_PAGE))
+			.map_err(runtime_error)
+	}
+
+	fn contest_escrow(
+		&self,
+		contest_id: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<EscrowInfo<AssetId, Balance>>> {
+		let api = self.client.runtime_api();
+
+		api.contest_escrow(&self.block_id(at), contest_id).map_err(runtime_error)
+	}
+
+	fn contest_payouts(
+		&self,
+		contest_id: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<PayoutInfo<AccountId, Balance, BlockNumber>>> {
+		let api = self.client.runtime_api();
+
+		api.contest_payouts(&self.block_id(at), contest_id).map_err(runtime_error)
+	}
+}
@@ -0,0 +1,32 @@
+//! Runtime API definition for the contests pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_contests::{ContestInfo, EntryInfo, EscrowInfo, PayoutInfo};
+
+sp_api::decl_runtime_apis! {
+	pub trait ContestsApi<AccountId, AssetId, Balance, BlockNumber> where
+		AccountId: Codec,
+		AssetId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Open or closed contests with `asset_id` as prize token.
+		fn contests_by_asset(asset_id: AssetId, open: bool) -> Vec<ContestInfo<AccountId, AssetId, Balance, BlockNumber>>;
+
+		/// Open or closed contests created by `creator`.
+		fn contests_by_creator(creator: AccountId, open: bool) -> Vec<ContestInfo<AccountId, AssetId, Balance, BlockNumber>>;
+
+		/// Up to `limit` entries of a contest starting at `offset`, ordered by entry id.
+		fn contest_entries(contest_id: u32, offset: u32, limit: u32) -> Vec<EntryInfo<AccountId>>;
+
+		/// Prizes of a contest still held in escrow.
+		fn contest_escrow(contest_id: u32) -> Option<EscrowInfo<AssetId, Balance>>;
+
+		/// Prize of each winner of a contest, 1st place first.
+		fn contest_payouts(contest_id: u32) -> Vec<PayoutInfo<AccountId, Balance, BlockNumber>>;
+	}
+}
@@ -0,0 +1,483 @@
+//! Benchmarking for `pallet-contests`.
+
+// Only enable this module for benchmarking.
+#![cfg(feature = "runtime-benchmarks")]
+
+use crate::*;
+use frame_benchmarking::{account, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::benchmarking::{benchmarks, Linear};
+use frame_system::RawOrigin;
+use sp_std::vec;
+
+const SEED: u32 = 0;
+// Prize token of the benchmarked contests, minted to every account that needs it.
+const PRIZE: u128 = 1_000_000_000_000;
+const ENTRY_FEE: u128 = 1_000;
+// Native balance given to every account that needs it.
+const NATIVE: u128 = 1_000_000_000_000_000;
+
+fn prize_token<T: Config>() -> AssetIdOf<T> {
+	T::BenchmarkHelper::asset_id(0)
+}
+
+/// Create `asset_id` if needed and mint `amount` of it to `who`.
+fn fund_asset<T: Config>(asset_id: AssetIdOf<T>, who: &T::AccountId, amount: u128) {
+	if !T::Assets::asset_exists(asset_id) {
+		T::BenchmarkHelper::create_asset(asset_id, Pallet::<T>::account_id(), One::one());
+	}
+
+	T::Assets::mint_into(asset_id, who, AssetBalanceOf::<T>::saturated_from(amount)).unwrap();
+}
+
+fn fund_native<T: Config>(who: &T::AccountId) {
+	T::Currency::make_free_balance_be(who, NativeBalanceOf::<T>::saturated_from(NATIVE));
+}
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let who: T::AccountId = account(name, index, SEED);
+	fund_asset::<T>(prize_token::<T>(), &who, PRIZE);
+	fund_native::<T>(&who);
+	who
+}
+
+fn max_bytes<S: Get<u32>>() -> BoundedVec<u8, S> {
+	BoundedVec::truncate_from(vec![b'x'; S::get() as usize])
+}
+
+fn contest_end_date<T: Config>() -> BlockNumberOf<T> {
+	<frame_system::Pallet<T>>::block_number() + T::MinContestDuration::get()
+}
+
+/// Panel of `MaxJudges` judges that all have to submit their ranking.
+fn full_panel<T: Config>() -> JudgingMode<T> {
+	let judges: Vec<T::AccountId> = (0..T::MaxJudges::get()).map(|i| account("judge", i, SEED)).collect();
+
+	JudgingMode::Panel {
+		quorum: judges.len() as u32,
+		judges: BoundedVec::truncate_from(judges),
+		judging_period: T::MinContestDuration::get(),
+	}
+}
+
+/// Create a contest with the longest title and description, `MaxPrizeTiers` prize tiers,
+/// an entry fee in the prize token and a min balance to enter.
+fn create_max_contest<T: Config>(
+	creator: &T::AccountId,
+	judging: JudgingMode<T>,
+	dispute_period: BlockNumberOf<T>,
+) -> u32 {
+	let tiers = T::MaxPrizeTiers::get();
+	let amounts: Vec<AssetBalanceOf<T>> = (0..tiers)
+		.map(|_| AssetBalanceOf::<T>::saturated_from(PRIZE / 10 / tiers as u128))
+		.collect();
+	let prize = amounts.iter().fold(AssetBalanceOf::<T>::zero(), |acc, amount| acc + *amount);
+
+	let contest_id = Pallet::<T>::next_contest_id();
+
+	Pallet::<T>::contest_new(
+		RawOrigin::Signed(creator.clone()).into(),
+		max_bytes::<T::MaxTitleLength>(),
+		prize_token::<T>(),
+		prize,
+		PrizeTiers::Amounts(BoundedVec::truncate_from(amounts)),
+		max_bytes::<T::MaxTokenSymbolLength>(),
+		contest_end_date::<T>(),
+		max_bytes::<T::MaxDescriptionLength>(),
+		judging,
+		Some(EntryFee::Asset(AssetBalanceOf::<T>::saturated_from(ENTRY_FEE))),
+		FeePolicy::Refund,
+		Some(Eligibility::MinBalance(One::one())),
+		dispute_period
+	)
+	.unwrap();
+
+	contest_id
+}
+
+/// Submit `count` entries to a contest, each from a different funded account.
+fn create_entries<T: Config>(contest_id: u32, count: u32) -> Vec<(T::AccountId, u32)> {
+	(0..count)
+		.map(|i| {
+			let entrant = funded_account::<T>("entrant", i);
+			let entry_id = Pallet::<T>::next_entry_id();
+
+			Pallet::<T>::create_contest_entry(
+				RawOrigin::Signed(entrant.clone()).into(),
+				contest_id,
+				max_bytes::<T::MaxSubmissionLength>()
+			)
+			.unwrap();
+
+			(entrant, entry_id)
+		})
+		.collect()
+}
+
+/// Sponsor a contest with `count` prize components, the native currency first and then
+/// assets other than the prize token.
+fn sponsor<T: Config>(contest_id: u32, count: u32) {
+	let sponsor = funded_account::<T>("sponsor", 0);
+
+	for i in 0..count {
+		let currency = match i {
+			0 => PrizeCurrency::Native,
+			_ => {
+				let asset_id = T::BenchmarkHelper::asset_id(i);
+				fund_asset::<T>(asset_id, &sponsor, PRIZE);
+				PrizeCurrency::Asset(asset_id)
+			},
+		};
+
+		Pallet::<T>::sponsor_contest(RawOrigin::Signed(sponsor.clone()).into(), contest_id, currency, PRIZE / 10).unwrap();
+	}
+}
+
+/// Add `count` contributions in the native currency to a contest, each from a different sponsor
+/// than the one of `sponsor`.
+fn sponsor_by_others<T: Config>(contest_id: u32, count: u32) {
+	for i in 0..count {
+		let sponsor = funded_account::<T>("sponsor", i + 1);
+
+		Pallet::<T>::sponsor_contest(RawOrigin::Signed(sponsor).into(), contest_id, PrizeCurrency::Native, PRIZE / 10)
+			.unwrap();
+	}
+}
+
+/// Sponsor a contest with every prize component and as many contributions as it can hold.
+fn sponsor_max<T: Config>(contest_id: u32) {
+	let components = T::MaxPrizeComponents::get();
+
+	sponsor::<T>(contest_id, components);
+	sponsor_by_others::<T>(contest_id, T::MaxSponsorships::get().saturating_sub(components));
+}
+
+/// Create a contest with every prize component, held for a dispute period, and pay its 1st place
+/// to the first of two entries. Returns the contest and the entrants.
+fn create_held_payout<T: Config>() -> (u32, Vec<(T::AccountId, u32)>) {
+	let creator = funded_account::<T>("creator", 0);
+	let contest_id = create_max_contest::<T>(&creator, JudgingMode::Creator, T::MinContestDuration::get());
+
+	sponsor_max::<T>(contest_id);
+
+	let entries = create_entries::<T>(contest_id, 2);
+
+	Pallet::<T>::assign_contest_winner(RawOrigin::Signed(creator).into(), entries[0].1, 0).unwrap();
+
+	(contest_id, entries)
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn contest_new() {
+		let caller: T::AccountId = whitelisted_caller();
+		fund_asset::<T>(prize_token::<T>(), &caller, PRIZE);
+
+		let tiers = T::MaxPrizeTiers::get();
+		let shares: Vec<u16> = (0..tiers).map(|i| if i == 0 { 1000 - (tiers as u16 - 1) } else { 1 }).collect();
+		let contest_id = Pallet::<T>::next_contest_id();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			max_bytes::<T::MaxTitleLength>(),
+			prize_token::<T>(),
+			AssetBalanceOf::<T>::saturated_from(PRIZE / 10),
+			PrizeTiers::Shares(BoundedVec::truncate_from(shares)),
+			max_bytes::<T::MaxTokenSymbolLength>(),
+			contest_end_date::<T>(),
+			max_bytes::<T::MaxDescriptionLength>(),
+			full_panel::<T>(),
+			Some(EntryFee::Asset(AssetBalanceOf::<T>::saturated_from(ENTRY_FEE))),
+			FeePolicy::Refund,
+			Some(Eligibility::MinBalance(One::one())),
+			T::MinContestDuration::get()
+		);
+
+		assert!(ContestsMap::<T>::contains_key(contest_id));
+		assert!(ContestsByCreator::<T>::contains_key(caller, contest_id));
+	}
+
+	#[benchmark]
+	fn update_contest() {
+		let caller = funded_account::<T>("creator", 0);
+		let contest_id = create_max_contest::<T>(&caller, JudgingMode::Creator, Zero::zero());
+		let contest_end_date = contest_end_date::<T>() + One::one();
+
+		// A new end date reschedules the end of the contest.
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			contest_id,
+			max_bytes::<T::MaxTitleLength>(),
+			max_bytes::<T::MaxDescriptionLength>(),
+			contest_end_date
+		);
+
+		assert_eq!(ContestsMap::<T>::get(contest_id).unwrap().contest_end_date, contest_end_date);
+	}
+
+	#[benchmark]
+	fn create_contest_entry() {
+		let creator = funded_account::<T>("creator", 0);
+		let contest_id = create_max_contest::<T>(&creator, JudgingMode::Creator, Zero::zero());
+
+		let caller: T::AccountId = whitelisted_caller();
+		fund_asset::<T>(prize_token::<T>(), &caller, PRIZE);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), contest_id, max_bytes::<T::MaxSubmissionLength>());
+
+		assert_eq!(AccountEntriesCount::<T>::get(contest_id, caller), 1);
+	}
+
+	// Paying the last prize tier of a contest with every prize component closes it.
+	#[benchmark]
+	fn assign_contest_winner() {
+		let caller = funded_account::<T>("creator", 0);
+		let contest_id = create_max_contest::<T>(&caller, JudgingMode::Creator, Zero::zero());
+		let tiers = T::MaxPrizeTiers::get();
+
+		sponsor_max::<T>(contest_id);
+
+		let entries = create_entries::<T>(contest_id, tiers);
+
+		for rank in 0..tiers - 1 {
+			Pallet::<T>::assign_contest_winner(RawOrigin::Signed(caller.clone()).into(), entries[rank as usize].1, rank).unwrap();
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), entries[tiers as usize - 1].1, tiers - 1);
+
+		assert!(!ContestsMap::<T>::get(contest_id).unwrap().statcode);
+	}
+
+	// Closing a contest without winners refunds the entry fee of each of the `e` entries.
+	#[benchmark]
+	fn close_contract(e: Linear<0, 500>) {
+		let caller = funded_account::<T>("creator", 0);
+		let contest_id = create_max_contest::<T>(&caller, JudgingMode::Creator, Zero::zero());
+
+		sponsor_max::<T>(contest_id);
+		create_entries::<T>(contest_id, e);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), contest_id);
+
+		let contest = ContestsMap::<T>::get(contest_id).unwrap();
+		assert!(!contest.statcode);
+		assert_eq!(contest.fee_pool, 0);
+	}
+
+	#[benchmark]
+	fn finalize_contest() {
+		let caller = funded_account::<T>("creator", 0);
+		let contest_id = create_max_contest::<T>(&caller, JudgingMode::Creator, Zero::zero());
+
+		sponsor_max::<T>(contest_id);
+
+		let entries = create_entries::<T>(contest_id, T::MaxPrizeTiers::get());
+		let ranked_entry_ids: Vec<u32> = entries.iter().map(|(_, entry_id)| *entry_id).collect();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), contest_id, BoundedVec::truncate_from(ranked_entry_ids));
+
+		assert!(!ContestsMap::<T>::get(contest_id).unwrap().statcode);
+	}
+
+	#[benchmark]
+	fn vote_entry() {
+		let creator = funded_account::<T>("creator", 0);
+		let voting = JudgingMode::CommunityVote { voting_period: T::MinContestDuration::get() };
+		let contest_id = create_max_contest::<T>(&creator, voting, Zero::zero());
+		let entries = create_entries::<T>(contest_id, 1);
+
+		let caller: T::AccountId = whitelisted_caller();
+		fund_asset::<T>(prize_token::<T>(), &caller, PRIZE);
+
+		<frame_system::Pallet<T>>::set_block_number(ContestsMap::<T>::get(contest_id).unwrap().contest_end_date);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), entries[0].1);
+
+		assert_eq!(VotingOf::<T>::get(contest_id, caller).map(|(entry_id, _)| entry_id), Some(entries[0].1));
+	}
+
+	#[benchmark]
+	fn unlock_vote() {
+		let creator = funded_account::<T>("creator", 0);
+		let voting = JudgingMode::CommunityVote { voting_period: T::MinContestDuration::get() };
+		let contest_id = create_max_contest::<T>(&creator, voting, Zero::zero());
+		let entries = create_entries::<T>(contest_id, 1);
+
+		let caller: T::AccountId = whitelisted_caller();
+		fund_asset::<T>(prize_token::<T>(), &caller, PRIZE);
+
+		let contest = ContestsMap::<T>::get(contest_id).unwrap();
+		<frame_system::Pallet<T>>::set_block_number(contest.contest_end_date);
+		Pallet::<T>::vote_entry(RawOrigin::Signed(caller.clone()).into(), entries[0].1).unwrap();
+
+		<frame_system::Pallet<T>>::set_block_number(Pallet::<T>::contest_close_date(&contest));
+		Pallet::<T>::enact_contest_end(RawOrigin::Root.into(), contest_id).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), contest_id);
+
+		assert!(VotingOf::<T>::get(contest_id, caller).is_none());
+	}
+
+	// The last judge reaching the quorum pays every prize tier and closes the contest.
+	#[benchmark]
+	fn submit_judgement() {
+		let creator = funded_account::<T>("creator", 0);
+		let contest_id = create_max_contest::<T>(&creator, full_panel::<T>(), Zero::zero());
+
+		sponsor_max::<T>(contest_id);
+
+		let entries = create_entries::<T>(contest_id, T::MaxPrizeTiers::get());
+		let ranked_entry_ids: BoundedVec<u32, T::MaxPrizeTiers> =
+			BoundedVec::truncate_from(entries.iter().map(|(_, entry_id)| *entry_id).collect());
+
+		<frame_system::Pallet<T>>::set_block_number(ContestsMap::<T>::get(contest_id).unwrap().contest_end_date);
+
+		let judges = T::MaxJudges::get();
+		for i in 0..judges - 1 {
+			let judge: T::AccountId = account("judge", i, SEED);
+			Pallet::<T>::submit_judgement(RawOrigin::Signed(judge).into(), contest_id, ranked_entry_ids.clone()).unwrap();
+		}
+
+		let caller: T::AccountId = account("judge", judges - 1, SEED);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), contest_id, ranked_entry_ids);
+
+		assert!(!ContestsMap::<T>::get(contest_id).unwrap().statcode);
+	}
+
+	// Closing a community voted contest ranks the `e` voted entries and pays every prize tier.
+	#[benchmark]
+	fn enact_contest_end(e: Linear<0, 500>) {
+		let creator = funded_account::<T>("creator", 0);
+		let voting = JudgingMode::CommunityVote { voting_period: T::MinContestDuration::get() };
+		let contest_id = create_max_contest::<T>(&creator, voting, Zero::zero());
+
+		sponsor_max::<T>(contest_id);
+
+		let entries = create_entries::<T>(contest_id, e);
+
+		<frame_system::Pallet<T>>::set_block_number(ContestsMap::<T>::get(contest_id).unwrap().contest_end_date);
+
+		for (entrant, entry_id) in entries.into_iter() {
+			Pallet::<T>::vote_entry(RawOrigin::Signed(entrant).into(), entry_id).unwrap();
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, contest_id);
+
+		assert!(!ContestsMap::<T>::get(contest_id).unwrap().statcode);
+	}
+
+	// Closing a community voted contest nobody closed yet also cancels its scheduled close.
+	#[benchmark]
+	fn close_ended_contest(e: Linear<0, 500>) {
+		let creator = funded_account::<T>("creator", 0);
+		let voting = JudgingMode::CommunityVote { voting_period: T::MinContestDuration::get() };
+		let contest_id = create_max_contest::<T>(&creator, voting, Zero::zero());
+
+		sponsor_max::<T>(contest_id);
+
+		let entries = create_entries::<T>(contest_id, e);
+
+		let contest = ContestsMap::<T>::get(contest_id).unwrap();
+		<frame_system::Pallet<T>>::set_block_number(contest.contest_end_date);
+
+		for (entrant, entry_id) in entries.into_iter() {
+			Pallet::<T>::vote_entry(RawOrigin::Signed(entrant).into(), entry_id).unwrap();
+		}
+
+		<frame_system::Pallet<T>>::set_block_number(Pallet::<T>::contest_close_date(&contest));
+
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), contest_id);
+
+		assert!(!ContestsMap::<T>::get(contest_id).unwrap().statcode);
+	}
+
+	// Sponsoring with a new currency adds the last prize component the contest can hold.
+	#[benchmark]
+	fn sponsor_contest() {
+		let creator = funded_account::<T>("creator", 0);
+		let contest_id = create_max_contest::<T>(&creator, JudgingMode::Creator, Zero::zero());
+		let components = T::MaxPrizeComponents::get();
+
+		sponsor::<T>(contest_id, components - 1);
+		sponsor_by_others::<T>(contest_id, T::MaxSponsorships::get().saturating_sub(components));
+
+		let asset_id = T::BenchmarkHelper::asset_id(components);
+		let caller: T::AccountId = whitelisted_caller();
+		fund_asset::<T>(asset_id, &caller, PRIZE);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), contest_id, PrizeCurrency::Asset(asset_id), PRIZE / 10);
+
+		assert_eq!(ContestsMap::<T>::get(contest_id).unwrap().prize_components.len() as u32, components);
+		assert_eq!(Sponsorships::<T>::get(contest_id).len() as u32, T::MaxSponsorships::get());
+	}
+
+	#[benchmark]
+	fn claim_payout() {
+		let (contest_id, _) = create_held_payout::<T>();
+
+		<frame_system::Pallet<T>>::set_block_number(PendingPayouts::<T>::get(contest_id, 0).unwrap().release_at);
+
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), contest_id, 0);
+
+		assert!(!PendingPayouts::<T>::contains_key(contest_id, 0));
+	}
+
+	#[benchmark]
+	fn enact_payout_release() {
+		let (contest_id, _) = create_held_payout::<T>();
+
+		<frame_system::Pallet<T>>::set_block_number(PendingPayouts::<T>::get(contest_id, 0).unwrap().release_at);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, contest_id, 0);
+
+		assert!(!PendingPayouts::<T>::contains_key(contest_id, 0));
+	}
+
+	#[benchmark]
+	fn dispute_payout() {
+		let (contest_id, entries) = create_held_payout::<T>();
+		let caller = entries[1].0.clone();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), contest_id, 0);
+
+		assert!(PendingPayouts::<T>::get(contest_id, 0).unwrap().dispute.is_some());
+	}
+
+	// Reassigning the payout to another entry is the heaviest resolution.
+	#[benchmark]
+	fn resolve_dispute() {
+		let (contest_id, entries) = create_held_payout::<T>();
+
+		Pallet::<T>::dispute_payout(RawOrigin::Signed(entries[1].0.clone()).into(), contest_id, 0).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, contest_id, 0, Some(entries[1].1));
+
+		assert!(!PendingPayouts::<T>::contains_key(contest_id, 0));
+		assert!(EntriesMap::<T>::get(entries[1].1).unwrap().winner);
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
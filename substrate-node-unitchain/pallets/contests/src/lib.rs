@@ -8,8 +8,16 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
-//#[cfg(feature = "std")]
-//use serde::{Deserialize, Serialize};
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod migrations;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 
 use frame_support::{
 	sp_runtime::{
@@ -17,22 +25,125 @@ use frame_support::{
 			Zero,
 			One,
 			AccountIdConversion,
-			CheckedDiv,
-			CheckedSub
+			CheckedAdd,
+			CheckedSub,
+			Dispatchable,
+			Saturating
 		}, 
-		FixedPointOperand},
+		FixedPointOperand,
+		PerThing,
+		Perbill,
+		Permill,
+		SaturatedConversion},
 	PalletId,
-	traits::tokens::{
-		Balance,
-		fungibles::{
-			Transfer, 
-			Inspect,
-			Mutate
-		}},
+	traits::{
+		Currency,
+		ExistenceRequirement,
+		ReservableCurrency,
+		schedule::{DispatchTime, Named as ScheduleNamed},
+		StorageVersion,
+		tokens::{
+			Balance,
+			fungibles::{
+				Transfer, 
+				Inspect,
+				Mutate
+			}}},
 	pallet_prelude::*};
 
 use frame_system::pallet_prelude::*;
 
+use pallet_ranks::RankInspect;
+
+use scale_info::prelude::vec::Vec;
+
+const CONTESTS_ID: [u8; 8] = *b"UnitCnts";
+
+/// The current storage version.
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+/// Contest as returned by the runtime API.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ContestInfo<AccountId, AssetId, Balance, BlockNumber> {
+	pub contest_id: u32,
+	pub title: Vec<u8>,
+	pub creator: AccountId,
+	pub prize_token_id: AssetId,
+	pub prize_token_amount: Balance,
+	pub open: bool,
+	pub contest_end_date: BlockNumber,
+	pub close_date: BlockNumber,
+	pub entries: u32,
+}
+
+/// Contest entry as returned by the runtime API.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct EntryInfo<AccountId> {
+	pub entry_id: u32,
+	pub author: AccountId,
+	pub submission: Vec<u8>,
+	pub winner: bool,
+	pub votes: u128,
+}
+
+/// Prizes of a contest still held by the pallet account.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct EscrowInfo<AssetId, Balance> {
+	pub prize_token_id: AssetId,
+	/// Prize token left for the tiers not paid yet, entry fees in the prize token included.
+	pub unpaid_prize: Balance,
+	/// Prize token held for winners during the dispute period.
+	pub held_prize: Balance,
+	/// Entry fees not paid out yet.
+	pub fee_pool: u128,
+	/// Sponsored prize components not paid out yet.
+	pub components: Vec<PrizeComponent<AssetId>>,
+}
+
+/// Prize paid, or held, for a winner of a contest.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct PayoutInfo<AccountId, Balance, BlockNumber> {
+	pub rank: u32,
+	pub entry_id: u32,
+	pub winner: AccountId,
+	pub prize: Balance,
+	/// Block at which a held payout is released, `None` once it was paid.
+	pub release_at: Option<BlockNumber>,
+}
+
+/// Creates the assets used by the benchmarks of the pallet.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId, AssetId, Balance> {
+	/// The asset with id `id`.
+	fn asset_id(id: u32) -> AssetId;
+
+	/// Create `asset_id`, administered by `admin`.
+	fn create_asset(asset_id: AssetId, admin: AccountId, min_balance: Balance);
+}
+
+/// Benchmark helper creating assets with `fungibles::Create`.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct AssetsBenchmarkHelper<Assets>(sp_std::marker::PhantomData<Assets>);
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<AccountId, Assets> BenchmarkHelper<AccountId, Assets::AssetId, Assets::Balance> for AssetsBenchmarkHelper<Assets>
+where
+	Assets: frame_support::traits::tokens::fungibles::Create<AccountId>,
+	Assets::AssetId: From<u32>,
+{
+	fn asset_id(id: u32) -> Assets::AssetId {
+		id.into()
+	}
+
+	fn create_asset(asset_id: Assets::AssetId, admin: AccountId, min_balance: Assets::Balance) {
+		Assets::create(asset_id, admin, true, min_balance).expect("benchmark assets can be created");
+	}
+}
+
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -40,6 +151,7 @@ pub mod pallet {
 
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::config]
@@ -61,6 +173,12 @@ pub mod pallet {
 					+ Default
 					+ Zero;
 
+		/// The native currency, used for entry fees paid in the native token and dispute deposits.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The ranks of each asset, used to gate entries by rank.
+		type Ranks: RankInspect<Self::AssetId, Self::AssetBalance>;
+
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
 
@@ -76,11 +194,9 @@ pub mod pallet {
 		#[pallet::constant]
 		type MinTokenSymbolLength: Get<u32>;
 
+		/// Minimum number of blocks between the creation of a contest and its end.
 		#[pallet::constant]
-		type MaxContestEndDateLength: Get<u32>;
-
-		#[pallet::constant]
-		type MinContestEndDateLength: Get<u32>;
+		type MinContestDuration: Get<Self::BlockNumber>;
 
 		#[pallet::constant]
 		type MaxDescriptionLength: Get<u32>;
@@ -93,12 +209,184 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type MinTokenWinner: Get<u32>;
+
+		/// Max number of judges in the panel of a contest.
+		#[pallet::constant]
+		type MaxJudges: Get<u32>;
+
+		/// Max number of prize tiers (winners) of a contest.
+		#[pallet::constant]
+		type MaxPrizeTiers: Get<u32>;
+
+		/// Max number of prize components sponsors can add on top of the prize token.
+		#[pallet::constant]
+		type MaxPrizeComponents: Get<u32>;
+
+		/// Max number of contributions, one per sponsor and currency, to the prize of a contest.
+		/// Should be at least `MaxPrizeComponents`.
+		#[pallet::constant]
+		type MaxSponsorships: Get<u32>;
+
+		/// Max length of an entry submission (URL or IPFS CID).
+		#[pallet::constant]
+		type MaxSubmissionLength: Get<u32>;
+
+		/// Max number of entries an account can submit to a single contest.
+		#[pallet::constant]
+		type MaxEntriesPerAccount: Get<u32>;
+
+		/// Max number of entries of a single contest, bounds the entries tallied when voting ends
+		/// and the refunds made when it is closed.
+		#[pallet::constant]
+		type MaxEntriesPerContest: Get<u32>;
+
+		/// Max length of the rank name required to enter a contest.
+		#[pallet::constant]
+		type MaxRankNameLength: Get<u32>;
+
+		/// Deposit reserved from an entrant disputing a payout, slashed if the dispute is rejected.
+		#[pallet::constant]
+		type DisputeDeposit: Get<NativeBalanceOf<Self>>;
+
+		/// Origin that resolves disputed payouts, root can always resolve them.
+		type DisputeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Overarching type of all pallets origins.
+		type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>>;
+
+		/// The overarching call type for Scheduler.
+		type ContestCall: Parameter + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin> + From<Call<Self>>;
+
+		/// The Scheduler, used to close contests once their end date is reached.
+		type Scheduler: ScheduleNamed<Self::BlockNumber, Self::ContestCall, Self::PalletsOrigin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Creates the assets used by the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AccountId, Self::AssetId, Self::AssetBalance>;
     }
 
 	pub type AssetBalanceOf<T> = <T as Config>::AssetBalance;
 
 	pub type AssetIdOf<T> = <T as Config>::AssetId;
 
+	pub type BlockNumberOf<T> = <T as frame_system::Config>::BlockNumber;
+
+	pub type NativeBalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// Currency of a prize component.
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub enum PrizeCurrency<AssetId> {
+		/// AssetId from the Assets Pallet.
+		Asset(AssetId),
+		/// Native Balances currency of the network.
+		Native,
+	}
+
+	/// Amount of a currency added to the prize of a contest by sponsors.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub struct PrizeComponent<AssetId> {
+		pub currency: PrizeCurrency<AssetId>,
+		pub amount: u128,
+	}
+
+	/// Prize tiers given by the creator of a contest, ordered from 1st place down.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	pub enum PrizeTiers<T: Config> {
+		/// Absolute amount of prize tokens for each rank, must add up to the prize amount.
+		Amounts(BoundedVec<AssetBalanceOf<T>, T::MaxPrizeTiers>),
+		/// Per-mill share of the prize for each rank, must add up to 1000.
+		Shares(BoundedVec<u16, T::MaxPrizeTiers>),
+	}
+
+	/// How the winners of a contest are chosen.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	pub enum JudgingMode<T: Config> {
+		/// The contest creator assigns the winners.
+		Creator,
+		/// Holders of the prize token vote on entries for `voting_period` blocks after the
+		/// contest end date, the entries with most weight are paid when voting ends.
+		CommunityVote { voting_period: BlockNumberOf<T> },
+		/// A panel of judges ranks entries for `judging_period` blocks after the contest end
+		/// date, the winners are paid as soon as `quorum` judges have submitted their ranking.
+		Panel {
+			judges: BoundedVec<T::AccountId, T::MaxJudges>,
+			quorum: u32,
+			judging_period: BlockNumberOf<T>,
+		},
+	}
+
+	impl<T: Config> Default for JudgingMode<T> {
+		fn default() -> Self {
+			JudgingMode::Creator
+		}
+	}
+
+	/// Fee paid by each entrant of a contest, added to the prize of the contest.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	pub enum EntryFee<T: Config> {
+		/// Amount of the prize token of the contest.
+		Asset(AssetBalanceOf<T>),
+		/// Amount of the native currency.
+		Native(NativeBalanceOf<T>),
+	}
+
+	/// What happens to the entry fees of a contest closed without winners.
+	#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub enum FeePolicy {
+		/// Each entrant gets its fees back.
+		Refund,
+		/// The fees are paid to the contest creator.
+		Forfeit,
+	}
+
+	impl Default for FeePolicy {
+		fn default() -> Self {
+			FeePolicy::Refund
+		}
+	}
+
+	/// Who can submit entries to a contest.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	pub enum Eligibility<T: Config> {
+		/// Accounts holding at least this balance of the prize token.
+		MinBalance(AssetBalanceOf<T>),
+		/// Accounts holding at least the rank with this name in the ranks of the prize token.
+		MinRank(BoundedVec<u8, T::MaxRankNameLength>),
+	}
+
+	/// Prize of a rank held in escrow until the dispute period of the contest ends.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	pub struct PendingPayout<T: Config> {
+		pub entry_id: u32,
+		pub winner: T::AccountId,
+		pub prize: AssetBalanceOf<T>,
+		// Part of the entry fees paid along with the prize.
+		pub fee_share: u128,
+		// Part of each sponsored prize component paid along with the prize.
+		pub components: BoundedVec<PrizeComponent<AssetIdOf<T>>, T::MaxPrizeComponents>,
+		pub release_at: BlockNumberOf<T>,
+		// Entrant that disputed the payout and the deposit reserved from it.
+		pub dispute: Option<(T::AccountId, NativeBalanceOf<T>)>,
+	}
+
+	/// Prize of a single rank and the entry it was paid to, if any.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, MaxEncodedLen, TypeInfo)]
+	pub struct PrizeTier<Balance> {
+		pub amount: Balance,
+		pub entry_id: Option<u32>,
+	}
+
 	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, MaxEncodedLen, TypeInfo)]
 	#[scale_info(skip_type_params(T))]
 	//#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -109,10 +397,25 @@ pub mod pallet {
 		pub prize_token_id: AssetIdOf<T>,
 		pub prize_token_amount: AssetBalanceOf<T>,
 		pub prize_token_winner: u32,
+		// Prize of each rank, index 0 is the 1st place.
+		pub prize_tiers: BoundedVec<PrizeTier<AssetBalanceOf<T>>, T::MaxPrizeTiers>,
+		// Prizes added by sponsors, split between the tiers in proportion to their prize.
+		pub prize_components: BoundedVec<PrizeComponent<AssetIdOf<T>>, T::MaxPrizeComponents>,
+		pub entry_fee: Option<EntryFee<T>>,
+		pub fee_policy: FeePolicy,
+		// Entry fees collected and not paid out yet, in the currency of `entry_fee`.
+		// Fees in the prize token are also counted in `prize_token_amount`.
+		pub fee_pool: u128,
+		// Rule entrants must meet, anyone can enter if none.
+		pub eligibility: Option<Eligibility<T>>,
+		// Blocks a payout is held in escrow and can be disputed, paid right away if zero.
+		pub dispute_period: BlockNumberOf<T>,
 		pub token_symbol: BoundedVec<u8, T::MaxTokenSymbolLength>,
 		// statcode states -> true: open; false: closed.
 		pub statcode: bool,
-		pub contest_end_date: BoundedVec<u8, T::MaxContestEndDateLength>,
+		// Block at which the contest stops accepting entries and is closed automatically.
+		pub contest_end_date: BlockNumberOf<T>,
+		pub judging: JudgingMode<T>,
 		pub description: BoundedVec<u8, T::MaxDescriptionLength>
 	}
 
@@ -123,6 +426,7 @@ pub mod pallet {
 		pub user_address: T::AccountId,
 		pub contest_id: u32,
 		pub entry_id: u32,
+		pub submission: BoundedVec<u8, T::MaxSubmissionLength>,
 		pub winner: bool,
 	}
 
@@ -131,11 +435,77 @@ pub mod pallet {
 	// contest_id -> Contest
 	pub type ContestsMap<T> = StorageMap<_, Blake2_128Concat, u32, Contest<T>>; 
 
+	#[pallet::storage]
+	#[pallet::getter(fn contests_by_creator)]
+	// AccountId -> contest_id -> ()
+	pub type ContestsByCreator<T: Config> = StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, u32, ()>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn contests_by_prize_token)]
+	// prize_token_id -> contest_id -> ()
+	pub type ContestsByAsset<T: Config> = StorageDoubleMap<_, Blake2_128Concat, AssetIdOf<T>, Blake2_128Concat, u32, ()>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn ger_entries)]
 	// entry_id -> ContestEntry
 	pub type EntriesMap<T> = StorageMap<_, Blake2_128Concat, u32, ContestEntry<T>>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn contest_entries)]
+	// contest_id -> entry_id -> ()
+	pub type ContestEntriesMap<T> = StorageDoubleMap<_, Blake2_128Concat, u32, Blake2_128Concat, u32, ()>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn account_entries)]
+	// contest_id -> AccountId -> number of entries
+	pub type AccountEntriesCount<T: Config> = StorageDoubleMap<_, Blake2_128Concat, u32, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn entries_count)]
+	// contest_id -> number of entries
+	pub type ContestEntriesCount<T> = StorageMap<_, Blake2_128Concat, u32, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn entry_votes)]
+	// entry_id -> vote weight
+	pub type EntryVotes<T> = StorageMap<_, Blake2_128Concat, u32, u128, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn voting_of)]
+	// contest_id -> AccountId -> (entry_id voted for, prize tokens held until the contest is closed)
+	pub type VotingOf<T: Config> = StorageDoubleMap<_, Blake2_128Concat, u32, Blake2_128Concat, T::AccountId, (u32, AssetBalanceOf<T>)>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn judgements)]
+	// contest_id -> judge -> entry_ids ranked by the judge, 1st place first
+	pub type Judgements<T: Config> = StorageDoubleMap<_, Blake2_128Concat, u32, Blake2_128Concat, T::AccountId, BoundedVec<u32, T::MaxPrizeTiers>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn pending_payouts)]
+	// contest_id -> rank -> PendingPayout
+	pub type PendingPayouts<T: Config> = StorageDoubleMap<_, Blake2_128Concat, u32, Blake2_128Concat, u32, PendingPayout<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn sponsorships)]
+	// contest_id -> (sponsor, amount of a currency added to the prize)
+	pub type Sponsorships<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u32,
+		BoundedVec<(T::AccountId, PrizeComponent<AssetIdOf<T>>), T::MaxSponsorships>,
+		ValueQuery
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_contest_id)]
+	/// ContestId for the next contest.
+	pub type NextContestId<T> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_entry_id)]
+	/// EntryId for the next contest entry.
+	pub type NextEntryId<T> = StorageValue<_, u32, ValueQuery>;
+
 
 	#[pallet::genesis_config]
 	pub struct GenesisConfig;
@@ -158,18 +528,32 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		ContestCreted { who: T::AccountId, contest_id: u32, title: BoundedVec<u8, T::MaxTitleLength> },
 		ContestUpdated { who: T::AccountId, contest_id: u32, title: BoundedVec<u8, T::MaxTitleLength>, 
-				description: BoundedVec<u8, T::MaxDescriptionLength>, contest_end_date: BoundedVec<u8, T::MaxContestEndDateLength> },
+				description: BoundedVec<u8, T::MaxDescriptionLength>, contest_end_date: BlockNumberOf<T> },
 		EntryCreated { who: T::AccountId, contest_id: u32, entry_id: u32 },
-		ContestWinnerAssigned { contest_id: u32, winner: T::AccountId, prize: AssetBalanceOf<T> },
+		EntryFeePaid { who: T::AccountId, contest_id: u32, entry_id: u32, fee: u128 },
+		EntryFeesReleased { contest_id: u32, to: T::AccountId, amount: u128 },
+		PayoutHeld { contest_id: u32, rank: u32, winner: T::AccountId, release_at: BlockNumberOf<T> },
+		PayoutReleased { contest_id: u32, rank: u32, winner: T::AccountId, prize: AssetBalanceOf<T> },
+		PayoutDisputed { who: T::AccountId, contest_id: u32, rank: u32 },
+		DisputeResolved { contest_id: u32, rank: u32, winner: T::AccountId, reassigned: bool },
+		ContestSponsored { who: T::AccountId, contest_id: u32, currency: PrizeCurrency<AssetIdOf<T>>, amount: u128 },
+		PrizeComponentPaid { contest_id: u32, to: T::AccountId, currency: PrizeCurrency<AssetIdOf<T>>, amount: u128 },
+		ContestWinnerAssigned { contest_id: u32, winner: T::AccountId, rank: u32, prize: AssetBalanceOf<T> },
 		ContestClosed { who: T::AccountId, contest_id: u32 },
+		ContestEnded { contest_id: u32, refunded: AssetBalanceOf<T> },
+		EntryVoted { who: T::AccountId, contest_id: u32, entry_id: u32, weight: u128 },
+		VoteUnlocked { who: T::AccountId, contest_id: u32, amount: AssetBalanceOf<T> },
+		JudgementSubmitted { judge: T::AccountId, contest_id: u32, ranked_entry_ids: BoundedVec<u32, T::MaxPrizeTiers> },
+		ContestJudged { contest_id: u32, judgements: u32, winners: Vec<(u32, T::AccountId, AssetBalanceOf<T>)>, 
+				refunded: AssetBalanceOf<T> },
+		ContestFinalized { who: T::AccountId, contest_id: u32, winners: Vec<(u32, T::AccountId, AssetBalanceOf<T>)>, 
+				refunded: AssetBalanceOf<T> },
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
-		ContestIdAlreadyInUse,
 		ContestIdDontExist,
 		ContestAlreadyClosed,
-		EntryIdAlreadyExist,
 		EntryIdDontExist,
 		AssetDontExist,
 		TitleTooSmall,
@@ -181,39 +565,93 @@ pub mod pallet {
 		OnlyOwnerCanChange,
 		OnlyOwnerCanAssignContestWinner,
 		OnlyOwnerCanCloseContest,
-		DivisionError
+		InvalidPrizeTiers,
+		PrizeTiersMismatch,
+		PrizeTierDontExist,
+		PrizeTierAlreadyPaid,
+		EntryAlreadyWinner,
+		EntryNotInContest,
+		TooManyWinners,
+		InvalidJudgingMode,
+		VotingNotOpen,
+		AlreadyVoted,
+		InsufficientVotingBalance,
+		NotAJudge,
+		AlreadyJudged,
+		DuplicateEntry,
+		InvalidContestEndDate,
+		ContestEndDatePassed,
+		SubmissionTooSmall,
+		TooManyEntries,
+		TooManyContestEntries,
+		InvalidEntryFee,
+		RankDontExist,
+		BalanceTooLowToEnter,
+		RankTooLowToEnter,
+		PayoutDontExist,
+		PayoutStillHeld,
+		PayoutAlreadyDisputed,
+		PayoutNotDisputed,
+		DisputePeriodEnded,
+		OnlyEntrantCanDispute,
+		TooManyPrizeComponents,
+		EntryIdOverflow,
+		InvalidDisputePeriod,
+		TooManySponsorships,
+		SchedulingFailed,
+		ContestNotEnded,
+		ContestStillOpen,
+		NotVoted,
+		ContestIdOverflow
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 
 		#[pallet::call_index(0)]
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::contest_new())]
 		pub fn contest_new(
 			origin: OriginFor<T>,
-			contest_id: u32,
 			title: BoundedVec<u8, T::MaxTitleLength>,
 			prize_token_id: AssetIdOf<T>,
 			prize_token_amount: AssetBalanceOf<T>,
-			prize_token_winner: u32,
+			prize_tiers: PrizeTiers<T>,
 			token_symbol: BoundedVec<u8, T::MaxTokenSymbolLength>,
-			contest_end_date: BoundedVec<u8, T::MaxContestEndDateLength>,
-			description: BoundedVec<u8, T::MaxDescriptionLength>
+			contest_end_date: BlockNumberOf<T>,
+			description: BoundedVec<u8, T::MaxDescriptionLength>,
+			judging: JudgingMode<T>,
+			entry_fee: Option<EntryFee<T>>,
+			fee_policy: FeePolicy,
+			eligibility: Option<Eligibility<T>>,
+			dispute_period: BlockNumberOf<T>
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
+			let prize_tiers = Self::resolve_prize_tiers(
+				prize_token_amount.clone(),
+				prize_tiers
+			)?;
+
+			let prize_token_winner = prize_tiers.len() as u32;
+
 			Self::validate_contest_new(
 				who.clone(),
-				contest_id.clone(),
 				title.clone(),
 				prize_token_id.clone(),
 				prize_token_amount.clone(),
 				prize_token_winner.clone(),
 				token_symbol.clone(),
 				contest_end_date.clone(),
-				description.clone()
+				description.clone(),
+				judging.clone(),
+				entry_fee.clone(),
+				eligibility.clone(),
+				dispute_period.clone()
 			)?;
 
+			let contest_id = Self::next_contest_id();
+			let next_contest_id = contest_id.checked_add(1).ok_or(Error::<T>::ContestIdOverflow)?;
+
 			let contest = Contest::<T> {
 				contest_id: contest_id.clone(),
 				title: title.clone(),
@@ -221,9 +659,17 @@ pub mod pallet {
 				prize_token_id: prize_token_id.clone(),
 				prize_token_amount: prize_token_amount.clone(),
 				prize_token_winner: prize_token_winner.clone(),
+				prize_tiers,
+				prize_components: BoundedVec::default(),
+				entry_fee,
+				fee_policy,
+				fee_pool: 0,
+				eligibility,
+				dispute_period,
 				token_symbol: token_symbol.clone(),
 				statcode: true,
 				contest_end_date: contest_end_date.clone(),
+				judging,
 				description: description.clone()
 			};
 
@@ -237,7 +683,12 @@ pub mod pallet {
 				true	
 			)?;
 
+			Self::schedule_contest_end(contest_id, Self::contest_close_date(&contest))?;
+
 			ContestsMap::<T>::insert(contest_id, contest);
+			ContestsByCreator::<T>::insert(who.clone(), contest_id.clone(), ());
+			ContestsByAsset::<T>::insert(prize_token_id.clone(), contest_id.clone(), ());
+			NextContestId::<T>::set(next_contest_id);
 
 			Self::deposit_event(Event::<T>::ContestCreted { who, contest_id, title } );
 
@@ -245,13 +696,13 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(1)]
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::update_contest())]
 		pub fn update_contest(
 			origin: OriginFor<T>,
 			contest_id: u32,
 			title: BoundedVec<u8, T::MaxTitleLength>,
 			description: BoundedVec<u8, T::MaxDescriptionLength>,
-			contest_end_date: BoundedVec<u8, T::MaxContestEndDateLength>
+			contest_end_date: BlockNumberOf<T>
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
@@ -268,7 +719,15 @@ pub mod pallet {
 
 			contest.title = title.clone();
 			contest.description = description.clone();
-			contest.contest_end_date = contest_end_date.clone();
+			if contest.contest_end_date != contest_end_date {
+				contest.contest_end_date = contest_end_date.clone();
+
+				T::Scheduler::reschedule_named(
+					(CONTESTS_ID, contest_id).encode(),
+					DispatchTime::At(Self::contest_close_date(&contest))
+				)
+				.map_err(|_| Error::<T>::SchedulingFailed)?;
+			}
 
 			ContestsMap::<T>::insert(contest_id, contest);
 
@@ -278,27 +737,44 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(2)]
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::create_contest_entry())]
 		pub fn create_contest_entry(
 			origin: OriginFor<T>,
 			contest_id: u32,
-			entry_id: u32
+			submission: BoundedVec<u8, T::MaxSubmissionLength>
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			Self::validate_create_contest_entry(
+			let mut contest = Self::validate_create_contest_entry(
+				who.clone(),
 				contest_id.clone(),
-				entry_id.clone()
+				submission.clone()
 			)?;
 
+			let entry_id = Self::next_entry_id();
+			let next_entry_id = entry_id.checked_add(1).ok_or(Error::<T>::EntryIdOverflow)?;
+
+			let fee = Self::collect_entry_fee(&who, &mut contest)?;
+
 			let entry_contest = ContestEntry::<T> {
 				user_address: who.clone(),
 				contest_id: contest_id.clone(),
 				entry_id: entry_id.clone(),
+				submission: submission.clone(),
 				winner: false
 			};
 
 			EntriesMap::<T>::insert(entry_id.clone(), entry_contest);
+			ContestEntriesMap::<T>::insert(contest_id.clone(), entry_id.clone(), ());
+			AccountEntriesCount::<T>::mutate(contest_id.clone(), who.clone(), |count| *count = count.saturating_add(1));
+			ContestEntriesCount::<T>::mutate(contest_id.clone(), |count| *count = count.saturating_add(1));
+			NextEntryId::<T>::set(next_entry_id);
+
+			if fee > 0 {
+				ContestsMap::<T>::insert(contest_id.clone(), contest);
+
+				Self::deposit_event(Event::<T>::EntryFeePaid { who: who.clone(), contest_id, entry_id, fee });
+			}
 
 			Self::deposit_event(Event::<T>::EntryCreated { who, contest_id, entry_id });
 
@@ -306,39 +782,27 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(3)]
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::assign_contest_winner())]
 		pub fn assign_contest_winner(
 			origin: OriginFor<T>,
-			entry_id: u32
+			entry_id: u32,
+			rank: u32
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
 			let mut contest = Self::validate_assign_contest_winner(
 				who.clone(),
-				entry_id.clone()
+				entry_id.clone(),
+				rank.clone()
 			)?;
 
-			let prize = match contest.prize_token_amount.checked_div(&contest.prize_token_winner.into()) {
-					Some(value) => Ok(value),
-					None => Err(Error::<T>::DivisionError)
-			}?;
-
 			let mut contest_entry = EntriesMap::<T>::get(entry_id).unwrap();
 
-			T::Assets::transfer(
-				contest.prize_token_id,
-				&Self::account_id(),
-				&contest_entry.user_address,
-				prize.clone(),
-				false	
-			)?;
-
-			contest.prize_token_winner = contest.prize_token_winner.checked_sub(1).unwrap_or(0);
-			contest.prize_token_amount = contest.prize_token_amount.checked_sub(&prize).unwrap_or(AssetBalanceOf::<T>::zero());
-			contest_entry.winner = true;
+			let prize = Self::pay_prize_tier(&mut contest, &mut contest_entry, rank)?;
 
 			if contest.prize_token_winner == 0 {
 				contest.statcode = false;
+				Self::cancel_contest_end(contest.contest_id);
 			}
 
 			let contest_id = contest_entry.contest_id.clone();
@@ -347,13 +811,13 @@ pub mod pallet {
 			ContestsMap::<T>::insert(contest.contest_id, contest);
 			EntriesMap::<T>::insert(entry_id, contest_entry);
 
-			Self::deposit_event(Event::<T>::ContestWinnerAssigned { contest_id ,winner, prize });
+			Self::deposit_event(Event::<T>::ContestWinnerAssigned { contest_id ,winner, rank, prize });
 
 			Ok(())
 		}
 
 		#[pallet::call_index(4)]
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::close_contract(T::MaxEntriesPerContest::get()))]
 		pub fn close_contract(
 			origin: OriginFor<T>,
 			contest_id: u32
@@ -365,124 +829,1607 @@ pub mod pallet {
 				contest_id.clone()
 			)?;
 
-			T::Assets::transfer(
-				contest.prize_token_id.clone(),
-				&Self::account_id(),
-				&who,
-				contest.prize_token_amount.clone(),
-				false
-			)?;
-
-			contest.statcode = false;
-			contest.prize_token_amount = AssetBalanceOf::<T>::zero();
+			Self::refund_remaining_prize(&mut contest)?;
 
 			let contest_id = contest.contest_id.clone();
 
 			ContestsMap::<T>::insert(contest_id.clone(), contest);
 
+			Self::cancel_contest_end(contest_id);
+
 			Self::deposit_event(Event::<T>::ContestClosed { who, contest_id });
 
 			Ok(())
 		}
-	}
-}
 
-impl<T: Config> Pallet<T> {
-	pub fn account_id() -> T::AccountId {
-		T::PalletId::get().into_account_truncating()
-	}
+		/// Pay every prize tier of a contest and close it in a single call.
+		///
+		/// Only the contest creator can call this function.
+		///
+		/// - `contest_id`: The contest to finalize.
+		/// - `ranked_entry_ids`: The winning entries of the ranks not assigned yet, best rank first.
+		///   Ranks already assigned with `assign_contest_winner` are skipped, ranks left without an
+		///   entry are not paid and their prize is refunded to the creator with any other remainder.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::finalize_contest())]
+		pub fn finalize_contest(
+			origin: OriginFor<T>,
+			contest_id: u32,
+			ranked_entry_ids: BoundedVec<u32, T::MaxPrizeTiers>
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-	fn validate_contest_new(
-		who: T::AccountId,
-		contest_id: u32,
-		title: BoundedVec<u8, T::MaxTitleLength>,
-		prize_token_id: AssetIdOf<T>,
-		prize_token_amount: AssetBalanceOf<T>,
-		prize_token_winner: u32,
-		token_symbol: BoundedVec<u8, T::MaxTokenSymbolLength>,
-		contest_end_date: BoundedVec<u8, T::MaxContestEndDateLength>,
-		description: BoundedVec<u8, T::MaxDescriptionLength>
-	) -> DispatchResult {
+			let mut contest = Self::validate_finalize_contest(
+				who.clone(),
+				contest_id.clone(),
+				ranked_entry_ids.clone()
+			)?;
 
-		ensure!(T::Assets::asset_exists(prize_token_id.clone()), Error::<T>::AssetDontExist);
-		ensure!(!ContestsMap::<T>::contains_key(contest_id), Error::<T>::ContestIdAlreadyInUse);
-		ensure!(prize_token_winner >= T::MinTokenWinner::get(), Error::<T>::PrizeTokenWinnerTooSmall);
-		ensure!(title.len() as u32 >= T::MinTitleLength::get(), Error::<T>::TitleTooSmall);
-		ensure!(token_symbol.len() as u32 >= T::MinTokenSymbolLength::get(), Error::<T>::TokenSymbolTooSmall);
-		ensure!(description.len() as u32 >= T::MinDescriptionLength::get(), Error::<T>::DescriptionTooSmall);
-		ensure!(T::Assets::balance(prize_token_id, &who) >= T::MinTokenAmount::get().into(), Error::<T>::AssetBalanceInsufficient);
-		
-		// Need to finish the contest_end_date validation yet
+			let winners = Self::pay_ranked_entries(&mut contest, &ranked_entry_ids)?;
 
-		Ok(())
-	}
+			let refunded = Self::refund_remaining_prize(&mut contest)?;
 
-	fn validate_update_contest(
-		who: T::AccountId,
-		contest_id: u32,
-		title: BoundedVec<u8, T::MaxTitleLength>,
-		description: BoundedVec<u8, T::MaxDescriptionLength>,
-		contest_end_date: BoundedVec<u8, T::MaxContestEndDateLength>		
-	) -> DispatchResult {
+			ContestsMap::<T>::insert(contest_id.clone(), contest);
 
-		ensure!(ContestsMap::<T>::contains_key(contest_id.clone()), Error::<T>::ContestIdDontExist);
-		ensure!(title.len() as u32 >= T::MinTitleLength::get(), Error::<T>::TitleTooSmall);
-		ensure!(description.len() as u32 >= T::MinDescriptionLength::get(), Error::<T>::DescriptionTooSmall);
+			Self::cancel_contest_end(contest_id);
 
-		// Unwrap used because there is a ensure! above testing that the element exist with contest_id key 
-		let contest = ContestsMap::<T>::get(contest_id.clone()).unwrap();
+			Self::deposit_event(Event::<T>::ContestFinalized { who, contest_id, winners, refunded });
 
-		ensure!(contest.user_address == who, Error::<T>::OnlyOwnerCanChange);
+			Ok(())
+		}
 
-		// Need to finish the contest_end_date verification.
+		/// Vote for an entry of a contest judged by community vote.
+		///
+		/// The vote is weighted by the balance of the prize token held by the voter, and each
+		/// account can vote once per contest. The balance voted with is held by the pallet until
+		/// the contest is closed, so the same tokens can't vote twice, see `unlock_vote`.
+		///
+		/// - `entry_id`: The entry to vote for.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::vote_entry())]
+		pub fn vote_entry(
+			origin: OriginFor<T>,
+			entry_id: u32
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-		Ok(())
-	}
+			let contest = Self::validate_vote_entry(
+				who.clone(),
+				entry_id.clone()
+			)?;
 
-	fn validate_create_contest_entry(
-		contest_id: u32,
-		entry_id: u32
-	) -> DispatchResult {
+			let contest_id = contest.contest_id.clone();
+			let amount = Self::vote_weight(&who, &contest);
+			let weight = amount.saturated_into::<u128>();
 
-		ensure!(!EntriesMap::<T>::contains_key(entry_id), Error::<T>::EntryIdAlreadyExist);		
-		ensure!(ContestsMap::<T>::contains_key(contest_id), Error::<T>::ContestIdDontExist);
+			ensure!(weight > 0, Error::<T>::InsufficientVotingBalance);
 
-		Ok(())
-	}
+			T::Assets::transfer(
+				contest.prize_token_id.clone(),
+				&who,
+				&Self::account_id(),
+				amount.clone(),
+				false
+			)?;
 
-	fn validate_assign_contest_winner(
-		who: T::AccountId,
-		entry_id: u32
-	) -> Result<Contest<T>, DispatchError> {
+			EntryVotes::<T>::mutate(entry_id.clone(), |votes| *votes = votes.saturating_add(weight));
+			VotingOf::<T>::insert(contest_id.clone(), who.clone(), (entry_id.clone(), amount));
 
-		ensure!(EntriesMap::<T>::contains_key(entry_id.clone()), Error::<T>::EntryIdDontExist);
+			Self::deposit_event(Event::<T>::EntryVoted { who, contest_id, entry_id, weight });
 
-		// Unwrap used because there is a ensure! above testing that the element exist with contest_id key 
-		let contest_entry = EntriesMap::<T>::get(entry_id).unwrap();
+			Ok(())
+		}
 
-		ensure!(ContestsMap::<T>::contains_key(contest_entry.contest_id), Error::<T>::ContestIdDontExist);
-		
-		// Unwrap used because there is a ensure! above testing that the element exist with contest_id key 
-		let contest = ContestsMap::get(contest_entry.contest_id).unwrap();
-		
-		ensure!(contest.statcode, Error::<T>::ContestAlreadyClosed);
-		ensure!(who == contest.user_address, Error::<T>::OnlyOwnerCanAssignContestWinner);
-		
-		Ok(contest)
-	} 
+		/// Get back the prize tokens held for a vote once the contest is closed.
+		///
+		/// - `contest_id`: The contest voted on.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::unlock_vote())]
+		pub fn unlock_vote(
+			origin: OriginFor<T>,
+			contest_id: u32
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-	fn validate_close_contract(
-		who: T::AccountId,
-		contest_id: u32
-	) -> Result<Contest<T> ,DispatchError> {
+			let (contest, amount) = Self::validate_unlock_vote(
+				who.clone(),
+				contest_id.clone()
+			)?;
 
-		ensure!(ContestsMap::<T>::contains_key(contest_id.clone()), Error::<T>::ContestIdDontExist);
+			T::Assets::transfer(
+				contest.prize_token_id.clone(),
+				&Self::account_id(),
+				&who,
+				amount.clone(),
+				false
+			)?;
 
-		let contest = ContestsMap::<T>::get(contest_id.clone()).unwrap();
+			VotingOf::<T>::remove(contest_id.clone(), who.clone());
 
-		ensure!(contest.statcode == true, Error::<T>::ContestAlreadyClosed);
-		ensure!(contest.user_address == who, Error::<T>::OnlyOwnerCanCloseContest);
+			Self::deposit_event(Event::<T>::VoteUnlocked { who, contest_id, amount });
 
-		Ok(contest)
+			Ok(())
+		}
+
+		/// Submit the ranking of a judge for a contest judged by a panel.
+		///
+		/// Only judges of the contest can call this function, once per contest. When the quorum
+		/// of judges is reached the winners are paid and the contest is closed.
+		///
+		/// - `contest_id`: The contest being judged.
+		/// - `ranked_entry_ids`: The entries chosen by the judge, 1st place first.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::submit_judgement())]
+		pub fn submit_judgement(
+			origin: OriginFor<T>,
+			contest_id: u32,
+			ranked_entry_ids: BoundedVec<u32, T::MaxPrizeTiers>
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut contest = Self::validate_submit_judgement(
+				who.clone(),
+				contest_id.clone(),
+				ranked_entry_ids.clone()
+			)?;
+
+			Judgements::<T>::insert(contest_id.clone(), who.clone(), ranked_entry_ids.clone());
+
+			Self::deposit_event(Event::<T>::JudgementSubmitted { judge: who, contest_id, ranked_entry_ids });
+
+			let judgements = Judgements::<T>::iter_prefix(contest_id).count() as u32;
+
+			if let JudgingMode::Panel { quorum, .. } = contest.judging {
+				if judgements >= quorum {
+					let ranking = Self::panel_ranking(&contest);
+					let winners = Self::pay_ranked_entries(&mut contest, &ranking)?;
+					let refunded = Self::refund_remaining_prize(&mut contest)?;
+
+					ContestsMap::<T>::insert(contest_id.clone(), contest);
+
+					Self::cancel_contest_end(contest_id);
+
+					Self::deposit_event(Event::<T>::ContestJudged { contest_id, judgements, winners, refunded });
+				}
+			}
+
+			Ok(())
+		}
+
+		/// Close a contest once its end date is reached.
+		///
+		/// Contests judged by community vote first pay the most voted entries.
+		/// Any prize left in escrow is refunded to the contest creator.
+		///
+		/// The dispatch origin of this call must be _ROOT_, it is scheduled when the contest is created.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::enact_contest_end(T::MaxEntriesPerContest::get()))]
+		pub fn enact_contest_end(
+			origin: OriginFor<T>,
+			contest_id: u32
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			let refunded = Self::do_enact_contest_end(contest_id)?;
+
+			Self::deposit_event(Event::<T>::ContestEnded { contest_id, refunded });
+
+			Ok(())
+		}
+
+		/// Close a contest once its close date is reached, if the scheduler did not close it.
+		///
+		/// Anyone can call this function, it does what `enact_contest_end` does so that the
+		/// prize held in escrow is never stuck.
+		///
+		/// - `contest_id`: The contest to close.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::close_ended_contest(T::MaxEntriesPerContest::get()))]
+		pub fn close_ended_contest(
+			origin: OriginFor<T>,
+			contest_id: u32
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			Self::validate_close_ended_contest(
+				contest_id.clone()
+			)?;
+
+			let refunded = Self::do_enact_contest_end(contest_id)?;
+
+			Self::cancel_contest_end(contest_id);
+
+			Self::deposit_event(Event::<T>::ContestEnded { contest_id, refunded });
+
+			Ok(())
+		}
+
+		/// Add to the prize of an open contest.
+		///
+		/// Anyone can sponsor a contest. The amount is split between the prize tiers in proportion
+		/// to their prize, whatever is not paid to winners is refunded to the sponsors in
+		/// proportion to their contributions.
+		///
+		/// - `contest_id`: The contest to sponsor.
+		/// - `currency`: An asset or the native currency.
+		/// - `amount`: The amount added to the prize.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::sponsor_contest())]
+		pub fn sponsor_contest(
+			origin: OriginFor<T>,
+			contest_id: u32,
+			currency: PrizeCurrency<AssetIdOf<T>>,
+			amount: u128
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut contest = Self::validate_sponsor_contest(
+				who.clone(),
+				contest_id.clone(),
+				currency.clone(),
+				amount.clone()
+			)?;
+
+			match currency.clone() {
+				PrizeCurrency::Asset(asset_id) => {
+					T::Assets::transfer(
+						asset_id,
+						&who,
+						&Self::account_id(),
+						AssetBalanceOf::<T>::saturated_from(amount),
+						true
+					)?;
+				},
+				PrizeCurrency::Native => {
+					T::Currency::transfer(
+						&who,
+						&Self::account_id(),
+						NativeBalanceOf::<T>::saturated_from(amount),
+						ExistenceRequirement::KeepAlive
+					)?;
+				},
+			}
+
+			match contest.prize_components.iter_mut().find(|component| component.currency == currency) {
+				Some(component) => component.amount = component.amount.saturating_add(amount),
+				None => contest.prize_components
+					.try_push(PrizeComponent { currency: currency.clone(), amount })
+					.map_err(|_| Error::<T>::TooManyPrizeComponents)?,
+			}
+
+			Sponsorships::<T>::try_mutate(contest_id.clone(), |sponsorships| -> DispatchResult {
+				match sponsorships
+					.iter_mut()
+					.find(|(sponsor, contribution)| *sponsor == who && contribution.currency == currency)
+				{
+					Some((_, contribution)) => contribution.amount = contribution.amount.saturating_add(amount),
+					None => sponsorships
+						.try_push((who.clone(), PrizeComponent { currency: currency.clone(), amount }))
+						.map_err(|_| Error::<T>::TooManySponsorships)?,
+				}
+
+				Ok(())
+			})?;
+
+			ContestsMap::<T>::insert(contest_id.clone(), contest);
+
+			Self::deposit_event(Event::<T>::ContestSponsored { who, contest_id, currency, amount });
+
+			Ok(())
+		}
+
+		/// Release a held payout once the dispute period of the contest has ended.
+		///
+		/// Anyone can call this function, the payout goes to the winner of the rank.
+		///
+		/// - `contest_id`: The contest of the payout.
+		/// - `rank`: The rank of the payout, 0 is the 1st place.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::claim_payout())]
+		pub fn claim_payout(
+			origin: OriginFor<T>,
+			contest_id: u32,
+			rank: u32
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let payout = Self::validate_claim_payout(
+				contest_id.clone(),
+				rank.clone()
+			)?;
+
+			Self::cancel_payout_release(contest_id, rank);
+
+			Self::do_release_payout(contest_id, rank, payout)
+		}
+
+		/// Release a held payout at the end of the dispute period.
+		///
+		/// The dispatch origin of this call must be _ROOT_, it is scheduled when the payout is held.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::enact_payout_release())]
+		pub fn enact_payout_release(
+			origin: OriginFor<T>,
+			contest_id: u32,
+			rank: u32
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			let payout = Self::validate_claim_payout(
+				contest_id.clone(),
+				rank.clone()
+			)?;
+
+			Self::do_release_payout(contest_id, rank, payout)
+		}
+
+		/// Dispute a held payout, reserving `DisputeDeposit` from the caller.
+		///
+		/// Only entrants of the contest can call this function, before the payout is released.
+		///
+		/// - `contest_id`: The contest of the payout.
+		/// - `rank`: The rank of the payout, 0 is the 1st place.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::dispute_payout())]
+		pub fn dispute_payout(
+			origin: OriginFor<T>,
+			contest_id: u32,
+			rank: u32
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut payout = Self::validate_dispute_payout(
+				who.clone(),
+				contest_id.clone(),
+				rank.clone()
+			)?;
+
+			let deposit = T::DisputeDeposit::get();
+
+			T::Currency::reserve(&who, deposit.clone())?;
+
+			payout.dispute = Some((who.clone(), deposit));
+
+			PendingPayouts::<T>::insert(contest_id.clone(), rank.clone(), payout);
+
+			Self::cancel_payout_release(contest_id, rank);
+
+			Self::deposit_event(Event::<T>::PayoutDisputed { who, contest_id, rank });
+
+			Ok(())
+		}
+
+		/// Resolve a disputed payout and release it.
+		///
+		/// The dispatch origin of this call must be _ROOT_ or `DisputeOrigin`.
+		///
+		/// - `contest_id`: The contest of the payout.
+		/// - `rank`: The rank of the payout, 0 is the 1st place.
+		/// - `reassign_to`: The entry that gets the payout instead of the disputed winner.
+		///   If `None` the dispute is rejected, the payout goes to the disputed winner and the
+		///   deposit of the disputer is slashed, otherwise the deposit is returned.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::resolve_dispute())]
+		pub fn resolve_dispute(
+			origin: OriginFor<T>,
+			contest_id: u32,
+			rank: u32,
+			reassign_to: Option<u32>
+		) -> DispatchResult {
+			T::DisputeOrigin::try_origin(origin)
+				.map(|_| ())
+				.or_else(ensure_root)?;
+
+			let mut payout = Self::validate_resolve_dispute(
+				contest_id.clone(),
+				rank.clone(),
+				reassign_to.clone()
+			)?;
+
+			// Unwrap used because there is a function "validate_resolve_dispute" above testing that the dispute exist
+			let (disputer, deposit) = payout.dispute.take().unwrap();
+
+			match reassign_to {
+				Some(entry_id) => {
+					// Unwraps used because there is a function "validate_resolve_dispute" above testing that the elements exist
+					let mut contest = ContestsMap::<T>::get(contest_id).unwrap();
+					let mut old_entry = EntriesMap::<T>::get(payout.entry_id).unwrap();
+					let mut new_entry = EntriesMap::<T>::get(entry_id).unwrap();
+
+					old_entry.winner = false;
+					new_entry.winner = true;
+
+					if let Some(tier) = contest.prize_tiers.get_mut(rank as usize) {
+						tier.entry_id = Some(entry_id);
+					}
+
+					payout.entry_id = entry_id;
+					payout.winner = new_entry.user_address.clone();
+
+					T::Currency::unreserve(&disputer, deposit);
+
+					ContestsMap::<T>::insert(contest_id, contest);
+					EntriesMap::<T>::insert(old_entry.entry_id, old_entry);
+					EntriesMap::<T>::insert(entry_id, new_entry);
+				},
+				None => {
+					let _ = T::Currency::slash_reserved(&disputer, deposit);
+				},
+			}
+
+			let winner = payout.winner.clone();
+
+			Self::do_release_payout(contest_id, rank, payout)?;
+
+			Self::deposit_event(Event::<T>::DisputeResolved { contest_id, rank, winner, reassigned: reassign_to.is_some() });
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	fn schedule_contest_end(
+		contest_id: u32,
+		contest_end_date: BlockNumberOf<T>
+	) -> DispatchResult {
+		T::Scheduler::schedule_named(
+			(CONTESTS_ID, contest_id).encode(),
+			DispatchTime::At(contest_end_date),
+			None,
+			63,
+			frame_system::RawOrigin::Root.into(),
+			Call::enact_contest_end { contest_id }.into(),
+		)
+		.map_err(|_| Error::<T>::SchedulingFailed)?;
+
+		Ok(())
+	}
+
+	fn cancel_contest_end(contest_id: u32) {
+		if T::Scheduler::cancel_named((CONTESTS_ID, contest_id).encode()).is_err() {
+			frame_support::print("LOGIC ERROR: cancel_contest_end/cancel_named failed");
+		}
+	}
+
+	fn schedule_payout_release(
+		contest_id: u32,
+		rank: u32,
+		release_at: BlockNumberOf<T>
+	) -> DispatchResult {
+		T::Scheduler::schedule_named(
+			(CONTESTS_ID, contest_id, rank).encode(),
+			DispatchTime::At(release_at),
+			None,
+			63,
+			frame_system::RawOrigin::Root.into(),
+			Call::enact_payout_release { contest_id, rank }.into(),
+		)
+		.map_err(|_| Error::<T>::SchedulingFailed)?;
+
+		Ok(())
+	}
+
+	fn cancel_payout_release(contest_id: u32, rank: u32) {
+		if T::Scheduler::cancel_named((CONTESTS_ID, contest_id, rank).encode()).is_err() {
+			frame_support::print("LOGIC ERROR: cancel_payout_release/cancel_named failed");
+		}
+	}
+
+	/// Turn the prize tiers given at creation into the amount paid to each rank.
+	///
+	/// Rounding dust left by per-mill shares goes to the 1st place.
+	fn resolve_prize_tiers(
+		prize_token_amount: AssetBalanceOf<T>,
+		prize_tiers: PrizeTiers<T>
+	) -> Result<BoundedVec<PrizeTier<AssetBalanceOf<T>>, T::MaxPrizeTiers>, DispatchError> {
+
+		let amounts: Vec<AssetBalanceOf<T>> = match prize_tiers {
+			PrizeTiers::Amounts(amounts) => {
+				let mut total = AssetBalanceOf::<T>::zero();
+				for amount in amounts.iter() {
+					total = total.checked_add(amount).ok_or(Error::<T>::PrizeTiersMismatch)?;
+				}
+				ensure!(total == prize_token_amount, Error::<T>::PrizeTiersMismatch);
+
+				amounts.into_inner()
+			},
+			PrizeTiers::Shares(shares) => {
+				let total: u32 = shares.iter().map(|share| *share as u32).sum();
+				ensure!(total == 1000, Error::<T>::PrizeTiersMismatch);
+
+				let mut amounts: Vec<AssetBalanceOf<T>> = shares
+					.iter()
+					.map(|share| Permill::from_perthousand(*share as u32).mul_floor(prize_token_amount))
+					.collect();
+
+				let paid = amounts.iter().fold(AssetBalanceOf::<T>::zero(), |acc, amount| acc + *amount);
+				if let Some(first) = amounts.first_mut() {
+					*first = *first + (prize_token_amount - paid);
+				}
+
+				amounts
+			},
+		};
+
+		ensure!(!amounts.is_empty(), Error::<T>::InvalidPrizeTiers);
+		ensure!(amounts.iter().all(|amount| !amount.is_zero()), Error::<T>::InvalidPrizeTiers);
+
+		let tiers: Vec<PrizeTier<AssetBalanceOf<T>>> = amounts
+			.into_iter()
+			.map(|amount| PrizeTier { amount, entry_id: None })
+			.collect();
+
+		// Can't fail, there are as many tiers as the bounded input had
+		Ok(BoundedVec::truncate_from(tiers))
+	}
+
+	/// Block at which the contest is closed, after the voting window for community judged contests.
+	///
+	/// Contests are only created and updated with a close date that fits a block number.
+	pub fn contest_close_date(contest: &Contest<T>) -> BlockNumberOf<T> {
+		contest.contest_end_date.saturating_add(Self::judging_period(&contest.judging))
+	}
+
+	/// Blocks between the end date of a contest and its close date.
+	fn judging_period(judging: &JudgingMode<T>) -> BlockNumberOf<T> {
+		match judging {
+			JudgingMode::CommunityVote { voting_period } => *voting_period,
+			JudgingMode::Panel { judging_period, .. } => *judging_period,
+			JudgingMode::Creator => Zero::zero(),
+		}
+	}
+
+	/// Voting power of an account, the balance of the prize token it can transfer.
+	fn vote_weight(
+		who: &T::AccountId,
+		contest: &Contest<T>
+	) -> AssetBalanceOf<T> {
+		T::Assets::reducible_balance(contest.prize_token_id.clone(), who, false)
+	}
+
+	fn validate_judging_mode(judging: &JudgingMode<T>) -> DispatchResult {
+		match judging {
+			JudgingMode::CommunityVote { voting_period } => {
+				ensure!(!voting_period.is_zero(), Error::<T>::InvalidJudgingMode);
+			},
+			JudgingMode::Panel { judges, quorum, judging_period } => {
+				ensure!(!judging_period.is_zero(), Error::<T>::InvalidJudgingMode);
+				ensure!(*quorum > 0 && *quorum as usize <= judges.len(), Error::<T>::InvalidJudgingMode);
+
+				for (index, judge) in judges.iter().enumerate() {
+					ensure!(!judges[..index].contains(judge), Error::<T>::InvalidJudgingMode);
+				}
+			},
+			JudgingMode::Creator => {},
+		}
+
+		Ok(())
+	}
+
+	fn validate_contest_end_date(
+		contest_end_date: BlockNumberOf<T>,
+		judging: &JudgingMode<T>,
+		dispute_period: BlockNumberOf<T>
+	) -> DispatchResult {
+		let now = <frame_system::Pallet<T>>::block_number();
+
+		ensure!(contest_end_date > now, Error::<T>::InvalidContestEndDate);
+		ensure!(contest_end_date - now >= T::MinContestDuration::get(), Error::<T>::InvalidContestEndDate);
+
+		let close_date = contest_end_date
+			.checked_add(&Self::judging_period(judging))
+			.ok_or(Error::<T>::InvalidContestEndDate)?;
+
+		// Payouts are held from at latest the close date.
+		ensure!(close_date.checked_add(&dispute_period).is_some(), Error::<T>::InvalidDisputePeriod);
+
+		Ok(())
+	}
+
+	fn validate_contest_new(
+		who: T::AccountId,
+		title: BoundedVec<u8, T::MaxTitleLength>,
+		prize_token_id: AssetIdOf<T>,
+		prize_token_amount: AssetBalanceOf<T>,
+		prize_token_winner: u32,
+		token_symbol: BoundedVec<u8, T::MaxTokenSymbolLength>,
+		contest_end_date: BlockNumberOf<T>,
+		description: BoundedVec<u8, T::MaxDescriptionLength>,
+		judging: JudgingMode<T>,
+		entry_fee: Option<EntryFee<T>>,
+		eligibility: Option<Eligibility<T>>,
+		dispute_period: BlockNumberOf<T>
+	) -> DispatchResult {
+
+		ensure!(T::Assets::asset_exists(prize_token_id.clone()), Error::<T>::AssetDontExist);
+		ensure!(prize_token_winner >= T::MinTokenWinner::get(), Error::<T>::PrizeTokenWinnerTooSmall);
+		ensure!(title.len() as u32 >= T::MinTitleLength::get(), Error::<T>::TitleTooSmall);
+		ensure!(token_symbol.len() as u32 >= T::MinTokenSymbolLength::get(), Error::<T>::TokenSymbolTooSmall);
+		ensure!(description.len() as u32 >= T::MinDescriptionLength::get(), Error::<T>::DescriptionTooSmall);
+		ensure!(T::Assets::balance(prize_token_id, &who) >= T::MinTokenAmount::get().into(), Error::<T>::AssetBalanceInsufficient);
+		
+		Self::validate_contest_end_date(contest_end_date, &judging, dispute_period)?;
+		Self::validate_judging_mode(&judging)?;
+
+		match entry_fee {
+			Some(EntryFee::Asset(fee)) => ensure!(!fee.is_zero(), Error::<T>::InvalidEntryFee),
+			Some(EntryFee::Native(fee)) => ensure!(!fee.is_zero(), Error::<T>::InvalidEntryFee),
+			None => {},
+		}
+
+		if let Some(Eligibility::MinRank(name)) = eligibility {
+			ensure!(T::Ranks::rank_min_tokens(prize_token_id, &name).is_some(), Error::<T>::RankDontExist);
+		}
+
+		Ok(())
+	}
+
+	fn validate_update_contest(
+		who: T::AccountId,
+		contest_id: u32,
+		title: BoundedVec<u8, T::MaxTitleLength>,
+		description: BoundedVec<u8, T::MaxDescriptionLength>,
+		contest_end_date: BlockNumberOf<T>
+	) -> DispatchResult {
+
+		ensure!(ContestsMap::<T>::contains_key(contest_id.clone()), Error::<T>::ContestIdDontExist);
+		ensure!(title.len() as u32 >= T::MinTitleLength::get(), Error::<T>::TitleTooSmall);
+		ensure!(description.len() as u32 >= T::MinDescriptionLength::get(), Error::<T>::DescriptionTooSmall);
+
+		// Unwrap used because there is a ensure! above testing that the element exist with contest_id key 
+		let contest = ContestsMap::<T>::get(contest_id.clone()).unwrap();
+
+		ensure!(contest.user_address == who, Error::<T>::OnlyOwnerCanChange);
+		ensure!(contest.statcode, Error::<T>::ContestAlreadyClosed);
+
+		if contest.contest_end_date != contest_end_date {
+			Self::validate_contest_end_date(contest_end_date, &contest.judging, contest.dispute_period)?;
+		}
+
+		Ok(())
+	}
+
+	fn validate_create_contest_entry(
+		who: T::AccountId,
+		contest_id: u32,
+		submission: BoundedVec<u8, T::MaxSubmissionLength>
+	) -> Result<Contest<T>, DispatchError> {
+
+		ensure!(ContestsMap::<T>::contains_key(contest_id), Error::<T>::ContestIdDontExist);
+		ensure!(!submission.is_empty(), Error::<T>::SubmissionTooSmall);
+		ensure!(
+			AccountEntriesCount::<T>::get(contest_id, &who) < T::MaxEntriesPerAccount::get(),
+			Error::<T>::TooManyEntries
+		);
+		ensure!(
+			ContestEntriesCount::<T>::get(contest_id) < T::MaxEntriesPerContest::get(),
+			Error::<T>::TooManyContestEntries
+		);
+
+		// Unwrap used because there is a ensure! above testing that the element exist with contest_id key 
+		let contest = ContestsMap::<T>::get(contest_id).unwrap();
+
+		ensure!(contest.statcode, Error::<T>::ContestAlreadyClosed);
+		ensure!(<frame_system::Pallet<T>>::block_number() < contest.contest_end_date, Error::<T>::ContestEndDatePassed);
+
+		Self::validate_eligibility(&who, &contest)?;
+
+		Ok(contest)
+	}
+
+	fn validate_eligibility(
+		who: &T::AccountId,
+		contest: &Contest<T>
+	) -> DispatchResult {
+
+		let balance = T::Assets::balance(contest.prize_token_id.clone(), who);
+
+		match &contest.eligibility {
+			Some(Eligibility::MinBalance(min_balance)) => {
+				ensure!(balance >= *min_balance, Error::<T>::BalanceTooLowToEnter);
+			},
+			Some(Eligibility::MinRank(name)) => {
+				let min_tokens = T::Ranks::rank_min_tokens(contest.prize_token_id.clone(), name)
+					.ok_or(Error::<T>::RankDontExist)?;
+
+				ensure!(balance >= min_tokens, Error::<T>::RankTooLowToEnter);
+			},
+			None => {},
+		}
+
+		Ok(())
+	}
+
+	fn validate_assign_contest_winner(
+		who: T::AccountId,
+		entry_id: u32,
+		rank: u32
+	) -> Result<Contest<T>, DispatchError> {
+
+		ensure!(EntriesMap::<T>::contains_key(entry_id.clone()), Error::<T>::EntryIdDontExist);
+
+		// Unwrap used because there is a ensure! above testing that the element exist with contest_id key 
+		let contest_entry = EntriesMap::<T>::get(entry_id).unwrap();
+
+		ensure!(ContestsMap::<T>::contains_key(contest_entry.contest_id), Error::<T>::ContestIdDontExist);
+		
+		// Unwrap used because there is a ensure! above testing that the element exist with contest_id key 
+		let contest = ContestsMap::get(contest_entry.contest_id).unwrap();
+		
+		ensure!(contest.statcode, Error::<T>::ContestAlreadyClosed);
+		ensure!(who == contest.user_address, Error::<T>::OnlyOwnerCanAssignContestWinner);
+		ensure!(contest.judging == JudgingMode::Creator, Error::<T>::InvalidJudgingMode);
+
+		Self::validate_prize_tier(&contest, &contest_entry, rank)?;
+		
+		Ok(contest)
+	}
+
+	fn validate_prize_tier(
+		contest: &Contest<T>,
+		contest_entry: &ContestEntry<T>,
+		rank: u32
+	) -> DispatchResult {
+
+		ensure!(!contest_entry.winner, Error::<T>::EntryAlreadyWinner);
+
+		let tier = contest.prize_tiers.get(rank as usize).ok_or(Error::<T>::PrizeTierDontExist)?;
+
+		ensure!(tier.entry_id.is_none(), Error::<T>::PrizeTierAlreadyPaid);
+
+		Ok(())
+	} 
+
+	fn validate_finalize_contest(
+		who: T::AccountId,
+		contest_id: u32,
+		ranked_entry_ids: BoundedVec<u32, T::MaxPrizeTiers>
+	) -> Result<Contest<T>, DispatchError> {
+
+		ensure!(ContestsMap::<T>::contains_key(contest_id.clone()), Error::<T>::ContestIdDontExist);
+
+		// Unwrap used because there is a ensure! above testing that the element exist with contest_id key 
+		let contest = ContestsMap::<T>::get(contest_id.clone()).unwrap();
+
+		ensure!(contest.statcode, Error::<T>::ContestAlreadyClosed);
+		ensure!(who == contest.user_address, Error::<T>::OnlyOwnerCanAssignContestWinner);
+		ensure!(contest.judging == JudgingMode::Creator, Error::<T>::InvalidJudgingMode);
+		ensure!(
+			ranked_entry_ids.len() <= contest.prize_tiers.iter().filter(|tier| tier.entry_id.is_none()).count(),
+			Error::<T>::TooManyWinners
+		);
+
+		for entry_id in ranked_entry_ids.iter() {
+			ensure!(EntriesMap::<T>::contains_key(entry_id), Error::<T>::EntryIdDontExist);
+			ensure!(ContestEntriesMap::<T>::contains_key(contest_id, entry_id), Error::<T>::EntryNotInContest);
+		}
+
+		Ok(contest)
+	}
+
+	fn validate_vote_entry(
+		who: T::AccountId,
+		entry_id: u32
+	) -> Result<Contest<T>, DispatchError> {
+
+		ensure!(EntriesMap::<T>::contains_key(entry_id.clone()), Error::<T>::EntryIdDontExist);
+
+		// Unwrap used because there is a ensure! above testing that the element exist with entry_id key 
+		let contest_entry = EntriesMap::<T>::get(entry_id).unwrap();
+
+		let contest = ContestsMap::<T>::get(contest_entry.contest_id).ok_or(Error::<T>::ContestIdDontExist)?;
+
+		ensure!(contest.statcode, Error::<T>::ContestAlreadyClosed);
+		ensure!(
+			matches!(contest.judging, JudgingMode::CommunityVote { .. }),
+			Error::<T>::InvalidJudgingMode
+		);
+
+		let now = <frame_system::Pallet<T>>::block_number();
+
+		ensure!(
+			now >= contest.contest_end_date && now < Self::contest_close_date(&contest),
+			Error::<T>::VotingNotOpen
+		);
+		ensure!(!VotingOf::<T>::contains_key(contest.contest_id, &who), Error::<T>::AlreadyVoted);
+
+		Ok(contest)
+	}
+
+	fn validate_unlock_vote(
+		who: T::AccountId,
+		contest_id: u32
+	) -> Result<(Contest<T>, AssetBalanceOf<T>), DispatchError> {
+
+		let contest = ContestsMap::<T>::get(contest_id).ok_or(Error::<T>::ContestIdDontExist)?;
+
+		ensure!(!contest.statcode, Error::<T>::ContestStillOpen);
+
+		let (_, amount) = VotingOf::<T>::get(contest_id, &who).ok_or(Error::<T>::NotVoted)?;
+
+		Ok((contest, amount))
+	}
+
+	fn validate_submit_judgement(
+		who: T::AccountId,
+		contest_id: u32,
+		ranked_entry_ids: BoundedVec<u32, T::MaxPrizeTiers>
+	) -> Result<Contest<T>, DispatchError> {
+
+		ensure!(ContestsMap::<T>::contains_key(contest_id.clone()), Error::<T>::ContestIdDontExist);
+
+		// Unwrap used because there is a ensure! above testing that the element exist with contest_id key 
+		let contest = ContestsMap::<T>::get(contest_id.clone()).unwrap();
+
+		ensure!(contest.statcode, Error::<T>::ContestAlreadyClosed);
+
+		let judges = match &contest.judging {
+			JudgingMode::Panel { judges, .. } => judges,
+			_ => return Err(Error::<T>::InvalidJudgingMode.into()),
+		};
+
+		ensure!(judges.contains(&who), Error::<T>::NotAJudge);
+
+		let now = <frame_system::Pallet<T>>::block_number();
+
+		ensure!(
+			now >= contest.contest_end_date && now < Self::contest_close_date(&contest),
+			Error::<T>::VotingNotOpen
+		);
+		ensure!(!Judgements::<T>::contains_key(contest_id, &who), Error::<T>::AlreadyJudged);
+		ensure!(ranked_entry_ids.len() <= contest.prize_tiers.len(), Error::<T>::TooManyWinners);
+
+		for (index, entry_id) in ranked_entry_ids.iter().enumerate() {
+			ensure!(ContestEntriesMap::<T>::contains_key(contest_id, entry_id), Error::<T>::EntryNotInContest);
+			ensure!(!ranked_entry_ids[..index].contains(entry_id), Error::<T>::DuplicateEntry);
+		}
+
+		Ok(contest)
+	}
+
+	fn validate_sponsor_contest(
+		who: T::AccountId,
+		contest_id: u32,
+		currency: PrizeCurrency<AssetIdOf<T>>,
+		amount: u128
+	) -> Result<Contest<T>, DispatchError> {
+
+		ensure!(ContestsMap::<T>::contains_key(contest_id.clone()), Error::<T>::ContestIdDontExist);
+
+		// Unwrap used because there is a ensure! above testing that the element exist with contest_id key 
+		let contest = ContestsMap::<T>::get(contest_id.clone()).unwrap();
+
+		ensure!(contest.statcode, Error::<T>::ContestAlreadyClosed);
+		ensure!(amount > 0, Error::<T>::TokenAmountTooSmall);
+
+		match currency {
+			PrizeCurrency::Asset(asset_id) => {
+				ensure!(T::Assets::asset_exists(asset_id), Error::<T>::AssetDontExist);
+			},
+			PrizeCurrency::Native => {},
+		}
+
+		ensure!(
+			contest.prize_components.len() < T::MaxPrizeComponents::get() as usize ||
+				contest.prize_components.iter().any(|component| component.currency == currency),
+			Error::<T>::TooManyPrizeComponents
+		);
+
+		let sponsorships = Sponsorships::<T>::get(contest_id);
+
+		ensure!(
+			sponsorships.len() < T::MaxSponsorships::get() as usize ||
+				sponsorships.iter().any(|(sponsor, contribution)| *sponsor == who && contribution.currency == currency),
+			Error::<T>::TooManySponsorships
+		);
+
+		Ok(contest)
+	}
+
+	fn validate_claim_payout(
+		contest_id: u32,
+		rank: u32
+	) -> Result<PendingPayout<T>, DispatchError> {
+
+		let payout = PendingPayouts::<T>::get(contest_id, rank).ok_or(Error::<T>::PayoutDontExist)?;
+
+		ensure!(payout.dispute.is_none(), Error::<T>::PayoutAlreadyDisputed);
+		ensure!(<frame_system::Pallet<T>>::block_number() >= payout.release_at, Error::<T>::PayoutStillHeld);
+
+		Ok(payout)
+	}
+
+	fn validate_dispute_payout(
+		who: T::AccountId,
+		contest_id: u32,
+		rank: u32
+	) -> Result<PendingPayout<T>, DispatchError> {
+
+		let payout = PendingPayouts::<T>::get(contest_id, rank).ok_or(Error::<T>::PayoutDontExist)?;
+
+		ensure!(payout.dispute.is_none(), Error::<T>::PayoutAlreadyDisputed);
+		ensure!(<frame_system::Pallet<T>>::block_number() < payout.release_at, Error::<T>::DisputePeriodEnded);
+		ensure!(
+			AccountEntriesCount::<T>::get(contest_id, &who) > 0 && who != payout.winner,
+			Error::<T>::OnlyEntrantCanDispute
+		);
+
+		Ok(payout)
+	}
+
+	fn validate_resolve_dispute(
+		contest_id: u32,
+		rank: u32,
+		reassign_to: Option<u32>
+	) -> Result<PendingPayout<T>, DispatchError> {
+
+		let payout = PendingPayouts::<T>::get(contest_id, rank).ok_or(Error::<T>::PayoutDontExist)?;
+
+		ensure!(payout.dispute.is_some(), Error::<T>::PayoutNotDisputed);
+
+		if let Some(entry_id) = reassign_to {
+			let contest_entry = EntriesMap::<T>::get(entry_id).ok_or(Error::<T>::EntryIdDontExist)?;
+
+			ensure!(contest_entry.contest_id == contest_id, Error::<T>::EntryNotInContest);
+			ensure!(!contest_entry.winner, Error::<T>::EntryAlreadyWinner);
+			ensure!(ContestsMap::<T>::contains_key(contest_id), Error::<T>::ContestIdDontExist);
+		}
+
+		Ok(payout)
+	}
+
+	fn validate_close_contract(
+		who: T::AccountId,
+		contest_id: u32
+	) -> Result<Contest<T> ,DispatchError> {
+
+		ensure!(ContestsMap::<T>::contains_key(contest_id.clone()), Error::<T>::ContestIdDontExist);
+
+		let contest = ContestsMap::<T>::get(contest_id.clone()).unwrap();
+
+		ensure!(contest.statcode == true, Error::<T>::ContestAlreadyClosed);
+		ensure!(contest.user_address == who, Error::<T>::OnlyOwnerCanCloseContest);
+		// Contests judged by community vote or by a panel are closed when judging ends.
+		ensure!(contest.judging == JudgingMode::Creator, Error::<T>::InvalidJudgingMode);
+
+		Ok(contest)
+	}
+
+	fn validate_close_ended_contest(
+		contest_id: u32
+	) -> Result<Contest<T>, DispatchError> {
+
+		let contest = ContestsMap::<T>::get(contest_id).ok_or(Error::<T>::ContestIdDontExist)?;
+
+		ensure!(contest.statcode, Error::<T>::ContestAlreadyClosed);
+		ensure!(
+			<frame_system::Pallet<T>>::block_number() >= Self::contest_close_date(&contest),
+			Error::<T>::ContestNotEnded
+		);
+
+		Ok(contest)
+	}
+
+	/// Close the contest at its end date and refund the remaining prize to the creator.
+	fn do_enact_contest_end(contest_id: u32) -> Result<AssetBalanceOf<T>, DispatchError> {
+		let mut contest = ContestsMap::<T>::get(contest_id).ok_or(Error::<T>::ContestIdDontExist)?;
+
+		ensure!(contest.statcode, Error::<T>::ContestAlreadyClosed);
+
+		if let JudgingMode::CommunityVote { .. } = contest.judging {
+			Self::pay_voted_entries(&mut contest)?;
+		}
+
+		let refunded = Self::refund_remaining_prize(&mut contest)?;
+
+		ContestsMap::<T>::insert(contest_id, contest);
+
+		Ok(refunded)
+	}
+
+	/// Pay the prize tiers to the entries with most vote weight, 1st place to the most voted.
+	///
+	/// Ties go to the entry submitted first, entries without votes are never paid.
+	/// At most `MaxEntriesPerContest` entries are tallied, as many as a contest can hold.
+	fn pay_voted_entries(contest: &mut Contest<T>) -> DispatchResult {
+		let mut ranking: Vec<(u32, u128)> = ContestEntriesMap::<T>::iter_key_prefix(contest.contest_id)
+			.take(T::MaxEntriesPerContest::get() as usize)
+			.map(|entry_id| (entry_id, EntryVotes::<T>::get(entry_id)))
+			.filter(|(_, votes)| *votes > 0)
+			.collect();
+
+		ranking.sort_by(|(a_id, a_votes), (b_id, b_votes)| b_votes.cmp(a_votes).then(a_id.cmp(b_id)));
+
+		let ranked_entry_ids: Vec<u32> = ranking
+			.into_iter()
+			.take(contest.prize_tiers.len())
+			.map(|(entry_id, _)| entry_id)
+			.collect();
+
+		let winners = Self::pay_ranked_entries(contest, &ranked_entry_ids)?;
+
+		for (rank, (_, winner, prize)) in winners.into_iter().enumerate() {
+			Self::deposit_event(Event::<T>::ContestWinnerAssigned {
+				contest_id: contest.contest_id,
+				winner,
+				rank: rank as u32,
+				prize
+			});
+		}
+
+		Ok(())
+	}
+
+	/// Combine the rankings of the judges of a contest, 1st place first.
+	///
+	/// Each judge gives an entry as many points as there are prize tiers below its rank
+	/// (plus one), ties go to the entry submitted first.
+	fn panel_ranking(contest: &Contest<T>) -> Vec<u32> {
+		let tiers = contest.prize_tiers.len() as u32;
+		let mut scores: Vec<(u32, u32)> = Vec::new();
+
+		for (_, ranked_entry_ids) in Judgements::<T>::iter_prefix(contest.contest_id) {
+			for (rank, entry_id) in ranked_entry_ids.iter().enumerate() {
+				let points = tiers.saturating_sub(rank as u32);
+
+				match scores.iter_mut().find(|(id, _)| id == entry_id) {
+					Some((_, score)) => *score = score.saturating_add(points),
+					None => scores.push((*entry_id, points)),
+				}
+			}
+		}
+
+		scores.sort_by(|(a_id, a_score), (b_id, b_score)| b_score.cmp(a_score).then(a_id.cmp(b_id)));
+
+		scores
+			.into_iter()
+			.take(tiers as usize)
+			.map(|(entry_id, _)| entry_id)
+			.collect()
+	}
+
+	/// Pay the prize tiers not paid yet, best rank first, to the entries of `ranked_entry_ids`
+	/// in order and store the entries.
+	fn pay_ranked_entries(
+		contest: &mut Contest<T>,
+		ranked_entry_ids: &[u32]
+	) -> Result<Vec<(u32, T::AccountId, AssetBalanceOf<T>)>, DispatchError> {
+		let unpaid_ranks: Vec<u32> = contest.prize_tiers
+			.iter()
+			.enumerate()
+			.filter(|(_, tier)| tier.entry_id.is_none())
+			.map(|(rank, _)| rank as u32)
+			.collect();
+
+		let mut winners = Vec::new();
+
+		for (rank, entry_id) in unpaid_ranks.into_iter().zip(ranked_entry_ids.iter()) {
+			let mut contest_entry = EntriesMap::<T>::get(entry_id).ok_or(Error::<T>::EntryIdDontExist)?;
+
+			let prize = Self::pay_prize_tier(contest, &mut contest_entry, rank)?;
+
+			winners.push((*entry_id, contest_entry.user_address.clone(), prize));
+
+			EntriesMap::<T>::insert(entry_id, contest_entry);
+		}
+
+		Ok(winners)
+	}
+
+	/// Pay the prize of `rank` to the author of `contest_entry` and mark it as winner.
+	///
+	/// Contests with a dispute period hold the payout in escrow until the period ends.
+	/// Storage of the contest and entry is not written, callers store them.
+	fn pay_prize_tier(
+		contest: &mut Contest<T>,
+		contest_entry: &mut ContestEntry<T>,
+		rank: u32
+	) -> Result<AssetBalanceOf<T>, DispatchError> {
+
+		Self::validate_prize_tier(contest, contest_entry, rank)?;
+
+		let fee_share = Self::pool_share(contest, rank, contest.fee_pool);
+
+		let component_shares: Vec<PrizeComponent<AssetIdOf<T>>> = contest.prize_components
+			.iter()
+			.map(|component| PrizeComponent {
+				currency: component.currency.clone(),
+				amount: Self::pool_share(contest, rank, component.amount)
+			})
+			.collect();
+
+		for (component, share) in contest.prize_components.iter_mut().zip(component_shares.iter()) {
+			component.amount = component.amount.saturating_sub(share.amount);
+		}
+
+		// Unwrap used because there is a function "validate_prize_tier" above testing that the tier exist
+		let tier = contest.prize_tiers.get_mut(rank as usize).unwrap();
+		let prize = tier.amount.clone();
+
+		tier.entry_id = Some(contest_entry.entry_id);
+		contest.prize_token_winner = contest.prize_token_winner.checked_sub(1).unwrap_or(0);
+		contest.prize_token_amount = contest.prize_token_amount.checked_sub(&prize).unwrap_or(AssetBalanceOf::<T>::zero());
+		contest_entry.winner = true;
+
+		if contest.dispute_period.is_zero() {
+			T::Assets::transfer(
+				contest.prize_token_id.clone(),
+				&Self::account_id(),
+				&contest_entry.user_address,
+				prize.clone(),
+				false
+			)?;
+
+			Self::release_entry_fees(contest, &contest_entry.user_address, fee_share)?;
+
+			for share in component_shares.iter() {
+				Self::transfer_prize_component(contest.contest_id, &contest_entry.user_address, share)?;
+			}
+		} else {
+			Self::take_entry_fees(contest, fee_share);
+
+			let release_at = <frame_system::Pallet<T>>::block_number()
+				.checked_add(&contest.dispute_period)
+				.ok_or(Error::<T>::InvalidDisputePeriod)?;
+
+			PendingPayouts::<T>::insert(contest.contest_id, rank, PendingPayout::<T> {
+				entry_id: contest_entry.entry_id,
+				winner: contest_entry.user_address.clone(),
+				prize: prize.clone(),
+				fee_share,
+				// Can't fail, there is a share for each component of the contest
+				components: BoundedVec::truncate_from(component_shares),
+				release_at: release_at.clone(),
+				dispute: None
+			});
+
+			Self::schedule_payout_release(contest.contest_id, rank, release_at.clone())?;
+
+			Self::deposit_event(Event::<T>::PayoutHeld {
+				contest_id: contest.contest_id,
+				rank,
+				winner: contest_entry.user_address.clone(),
+				release_at
+			});
+		}
+
+		Ok(prize)
+	}
+
+	/// Refund the prize left in escrow and close the contest.
+	///
+	/// Sponsored prize components go back to their sponsors, the rest to the contest creator.
+	/// The judgements of the panel are not needed anymore and are removed.
+	fn refund_remaining_prize(contest: &mut Contest<T>) -> Result<AssetBalanceOf<T>, DispatchError> {
+		Self::settle_entry_fees(contest)?;
+
+		let _ = Judgements::<T>::clear_prefix(contest.contest_id, T::MaxJudges::get(), None);
+
+		let sponsorships = Sponsorships::<T>::take(contest.contest_id);
+
+		for component in contest.prize_components.iter() {
+			Self::refund_prize_component(contest, component, &sponsorships)?;
+		}
+
+		contest.prize_components = BoundedVec::default();
+
+		let refunded = contest.prize_token_amount.clone();
+
+		if !refunded.is_zero() {
+			T::Assets::transfer(
+				contest.prize_token_id.clone(),
+				&Self::account_id(),
+				&contest.user_address,
+				refunded.clone(),
+				false
+			)?;
+		}
+
+		contest.statcode = false;
+		contest.prize_token_amount = AssetBalanceOf::<T>::zero();
+
+		Ok(refunded)
+	}
+
+	/// Transfer a held payout from escrow to its winner.
+	fn do_release_payout(
+		contest_id: u32,
+		rank: u32,
+		payout: PendingPayout<T>
+	) -> DispatchResult {
+		let contest = ContestsMap::<T>::get(contest_id).ok_or(Error::<T>::ContestIdDontExist)?;
+
+		T::Assets::transfer(
+			contest.prize_token_id.clone(),
+			&Self::account_id(),
+			&payout.winner,
+			payout.prize.clone(),
+			false
+		)?;
+
+		Self::transfer_entry_fees(&contest, &payout.winner, payout.fee_share)?;
+
+		for component in payout.components.iter() {
+			Self::transfer_prize_component(contest_id, &payout.winner, component)?;
+		}
+
+		PendingPayouts::<T>::remove(contest_id, rank);
+
+		Self::deposit_event(Event::<T>::PayoutReleased { contest_id, rank, winner: payout.winner, prize: payout.prize });
+
+		Ok(())
+	}
+
+	/// Take the entry fee of `contest` from `who` into escrow, returns the fee taken.
+	///
+	/// Storage is not written, callers store the updated contest.
+	fn collect_entry_fee(
+		who: &T::AccountId,
+		contest: &mut Contest<T>
+	) -> Result<u128, DispatchError> {
+		let fee = match contest.entry_fee.clone() {
+			Some(EntryFee::Asset(fee)) => {
+				T::Assets::transfer(
+					contest.prize_token_id.clone(),
+					who,
+					&Self::account_id(),
+					fee.clone(),
+					true
+				)?;
+
+				contest.prize_token_amount = contest.prize_token_amount.saturating_add(fee);
+
+				fee.saturated_into::<u128>()
+			},
+			Some(EntryFee::Native(fee)) => {
+				T::Currency::transfer(
+					who,
+					&Self::account_id(),
+					fee.clone(),
+					ExistenceRequirement::KeepAlive
+				)?;
+
+				fee.saturated_into::<u128>()
+			},
+			None => 0,
+		};
+
+		contest.fee_pool = contest.fee_pool.saturating_add(fee);
+
+		Ok(fee)
+	}
+
+	/// Part of `pool` paid along with the prize of `rank`, used for entry fees and sponsored
+	/// prize components.
+	///
+	/// The pool is split in proportion to the prizes of the tiers not paid yet, so the last
+	/// tier paid takes whatever is left.
+	fn pool_share(
+		contest: &Contest<T>,
+		rank: u32,
+		pool: u128
+	) -> u128 {
+		let amount = contest.prize_tiers
+			.get(rank as usize)
+			.map(|tier| tier.amount.saturated_into::<u128>())
+			.unwrap_or(0);
+
+		let unpaid: u128 = contest.prize_tiers
+			.iter()
+			.filter(|tier| tier.entry_id.is_none())
+			.map(|tier| tier.amount.saturated_into::<u128>())
+			.sum();
+
+		if amount >= unpaid {
+			return pool
+		}
+
+		Perbill::from_rational(amount, unpaid).mul_floor(pool)
+	}
+
+	/// Refund what is left of a prize component to its sponsors, in proportion to their
+	/// contributions. The last sponsor takes the rounding dust.
+	///
+	/// Contributions made before sponsors were recorded are refunded to the contest creator.
+	fn refund_prize_component(
+		contest: &Contest<T>,
+		component: &PrizeComponent<AssetIdOf<T>>,
+		sponsorships: &[(T::AccountId, PrizeComponent<AssetIdOf<T>>)]
+	) -> DispatchResult {
+		let contributions: Vec<(&T::AccountId, u128)> = sponsorships
+			.iter()
+			.filter(|(_, contribution)| contribution.currency == component.currency)
+			.map(|(sponsor, contribution)| (sponsor, contribution.amount))
+			.collect();
+
+		let mut contributed = contributions.iter().fold(0u128, |acc, (_, amount)| acc.saturating_add(*amount));
+		let mut left = component.amount;
+
+		for (sponsor, amount) in contributions.into_iter() {
+			let refund = if amount >= contributed {
+				left
+			} else {
+				Perbill::from_rational(amount, contributed).mul_floor(left)
+			};
+
+			Self::transfer_prize_component(
+				contest.contest_id,
+				sponsor,
+				&PrizeComponent { currency: component.currency.clone(), amount: refund }
+			)?;
+
+			left = left.saturating_sub(refund);
+			contributed = contributed.saturating_sub(amount);
+		}
+
+		Self::transfer_prize_component(
+			contest.contest_id,
+			&contest.user_address,
+			&PrizeComponent { currency: component.currency.clone(), amount: left }
+		)
 	}
-}
\ No newline at end of file
+
+	/// Transfer a prize component from escrow to `to`.
+	fn transfer_prize_component(
+		contest_id: u32,
+		to: &T::AccountId,
+		component: &PrizeComponent<AssetIdOf<T>>
+	) -> DispatchResult {
+		if component.amount == 0 {
+			return Ok(())
+		}
+
+		match component.currency.clone() {
+			PrizeCurrency::Asset(asset_id) => {
+				T::Assets::transfer(
+					asset_id,
+					&Self::account_id(),
+					to,
+					AssetBalanceOf::<T>::saturated_from(component.amount),
+					false
+				)?;
+			},
+			PrizeCurrency::Native => {
+				T::Currency::transfer(
+					&Self::account_id(),
+					to,
+					NativeBalanceOf::<T>::saturated_from(component.amount),
+					ExistenceRequirement::AllowDeath
+				)?;
+			},
+		}
+
+		Self::deposit_event(Event::<T>::PrizeComponentPaid {
+			contest_id,
+			to: to.clone(),
+			currency: component.currency.clone(),
+			amount: component.amount
+		});
+
+		Ok(())
+	}
+
+	/// Transfer `amount` of the fee pool of `contest` to `to`.
+	///
+	/// Storage is not written, callers store the updated contest.
+	fn release_entry_fees(
+		contest: &mut Contest<T>,
+		to: &T::AccountId,
+		amount: u128
+	) -> DispatchResult {
+		Self::transfer_entry_fees(contest, to, amount)?;
+		Self::take_entry_fees(contest, amount);
+
+		Ok(())
+	}
+
+	/// Remove `amount` from the fee pool of `contest`, the tokens stay in escrow.
+	fn take_entry_fees(
+		contest: &mut Contest<T>,
+		amount: u128
+	) {
+		if let Some(EntryFee::Asset(_)) = contest.entry_fee {
+			contest.prize_token_amount = contest.prize_token_amount
+				.checked_sub(&AssetBalanceOf::<T>::saturated_from(amount))
+				.unwrap_or(AssetBalanceOf::<T>::zero());
+		}
+
+		contest.fee_pool = contest.fee_pool.saturating_sub(amount);
+	}
+
+	/// Transfer `amount` of entry fees of `contest` from escrow to `to`.
+	fn transfer_entry_fees(
+		contest: &Contest<T>,
+		to: &T::AccountId,
+		amount: u128
+	) -> DispatchResult {
+		if amount == 0 {
+			return Ok(())
+		}
+
+		match contest.entry_fee {
+			Some(EntryFee::Asset(_)) => {
+				T::Assets::transfer(
+					contest.prize_token_id.clone(),
+					&Self::account_id(),
+					to,
+					AssetBalanceOf::<T>::saturated_from(amount),
+					false
+				)?;
+			},
+			Some(EntryFee::Native(_)) => {
+				T::Currency::transfer(
+					&Self::account_id(),
+					to,
+					NativeBalanceOf::<T>::saturated_from(amount),
+					ExistenceRequirement::AllowDeath
+				)?;
+			},
+			None => return Ok(()),
+		}
+
+		Self::deposit_event(Event::<T>::EntryFeesReleased { contest_id: contest.contest_id, to: to.clone(), amount });
+
+		Ok(())
+	}
+
+	/// Empty the fee pool of a contest being closed.
+	///
+	/// If no prize tier was paid and the fee policy is `Refund`, every entry gets its fee back,
+	/// a contest holds at most `MaxEntriesPerContest` entries.
+	/// Anything else left in the pool goes to the contest creator.
+	fn settle_entry_fees(contest: &mut Contest<T>) -> DispatchResult {
+		if contest.fee_pool == 0 {
+			return Ok(())
+		}
+
+		let fee = match contest.entry_fee {
+			Some(EntryFee::Asset(fee)) => fee.saturated_into::<u128>(),
+			Some(EntryFee::Native(fee)) => fee.saturated_into::<u128>(),
+			None => 0,
+		};
+
+		let no_winners = contest.prize_tiers.iter().all(|tier| tier.entry_id.is_none());
+
+		if no_winners && contest.fee_policy == FeePolicy::Refund {
+			let entry_ids = ContestEntriesMap::<T>::iter_key_prefix(contest.contest_id)
+				.take(T::MaxEntriesPerContest::get() as usize);
+
+			for entry_id in entry_ids {
+				if let Some(contest_entry) = EntriesMap::<T>::get(entry_id) {
+					let amount = fee.min(contest.fee_pool);
+
+					Self::release_entry_fees(contest, &contest_entry.user_address, amount)?;
+				}
+			}
+		}
+
+		let creator = contest.user_address.clone();
+		let remaining = contest.fee_pool;
+
+		Self::release_entry_fees(contest, &creator, remaining)
+	}
+}
+
+// Queries of the runtime API.
+impl<T: Config> Pallet<T> {
+	/// Contests with `prize_token_id` as prize token, either open or closed.
+	pub fn contests_by_asset(
+		prize_token_id: AssetIdOf<T>,
+		open: bool
+	) -> Vec<ContestInfo<T::AccountId, AssetIdOf<T>, AssetBalanceOf<T>, BlockNumberOf<T>>> {
+		Self::contests_info(ContestsByAsset::<T>::iter_key_prefix(prize_token_id), open)
+	}
+
+	/// Contests created by `creator`, either open or closed.
+	pub fn contests_of_creator(
+		creator: T::AccountId,
+		open: bool
+	) -> Vec<ContestInfo<T::AccountId, AssetIdOf<T>, AssetBalanceOf<T>, BlockNumberOf<T>>> {
+		Self::contests_info(ContestsByCreator::<T>::iter_key_prefix(creator), open)
+	}
+
+	fn contests_info(
+		contest_ids: impl Iterator<Item = u32>,
+		open: bool
+	) -> Vec<ContestInfo<T::AccountId, AssetIdOf<T>, AssetBalanceOf<T>, BlockNumberOf<T>>> {
+		let mut contests: Vec<_> = contest_ids
+			.filter_map(|contest_id| ContestsMap::<T>::get(contest_id))
+			.filter(|contest| contest.statcode == open)
+			.map(|contest| Self::contest_info(&contest))
+			.collect();
+
+		contests.sort_by_key(|contest| contest.contest_id);
+
+		contests
+	}
+
+	/// Up to `limit` entries of a contest starting at `offset`, ordered by entry_id.
+	pub fn contest_entries_page(
+		contest_id: u32,
+		offset: u32,
+		limit: u32
+	) -> Vec<EntryInfo<T::AccountId>> {
+		let mut entry_ids: Vec<u32> = ContestEntriesMap::<T>::iter_key_prefix(contest_id).collect();
+
+		entry_ids.sort();
+
+		entry_ids
+			.into_iter()
+			.skip(offset as usize)
+			.take(limit as usize)
+			.filter_map(|entry_id| EntriesMap::<T>::get(entry_id))
+			.map(|contest_entry| EntryInfo {
+				entry_id: contest_entry.entry_id,
+				author: contest_entry.user_address,
+				submission: contest_entry.submission.into_inner(),
+				winner: contest_entry.winner,
+				votes: EntryVotes::<T>::get(contest_entry.entry_id)
+			})
+			.collect()
+	}
+
+	/// Prizes of a contest still held in escrow.
+	pub fn contest_escrow(contest_id: u32) -> Option<EscrowInfo<AssetIdOf<T>, AssetBalanceOf<T>>> {
+		let contest = ContestsMap::<T>::get(contest_id)?;
+
+		let held_prize = PendingPayouts::<T>::iter_prefix_values(contest_id)
+			.fold(AssetBalanceOf::<T>::zero(), |acc, payout| acc.saturating_add(payout.prize));
+
+		Some(EscrowInfo {
+			prize_token_id: contest.prize_token_id,
+			unpaid_prize: contest.prize_token_amount,
+			held_prize,
+			fee_pool: contest.fee_pool,
+			components: contest.prize_components.into_inner()
+		})
+	}
+
+	/// Prize of each winner of a contest, 1st place first.
+	pub fn contest_payouts(contest_id: u32) -> Vec<PayoutInfo<T::AccountId, AssetBalanceOf<T>, BlockNumberOf<T>>> {
+		let contest = match ContestsMap::<T>::get(contest_id) {
+			Some(contest) => contest,
+			None => return Vec::new(),
+		};
+
+		contest.prize_tiers
+			.iter()
+			.enumerate()
+			.filter_map(|(rank, tier)| {
+				let entry_id = tier.entry_id?;
+				let pending = PendingPayouts::<T>::get(contest_id, rank as u32);
+				let winner = match &pending {
+					Some(payout) => payout.winner.clone(),
+					None => EntriesMap::<T>::get(entry_id)?.user_address,
+				};
+
+				Some(PayoutInfo {
+					rank: rank as u32,
+					entry_id,
+					winner,
+					prize: tier.amount,
+					release_at: pending.map(|payout| payout.release_at)
+				})
+			})
+			.collect()
+	}
+
+	fn contest_info(contest: &Contest<T>) -> ContestInfo<T::AccountId, AssetIdOf<T>, AssetBalanceOf<T>, BlockNumberOf<T>> {
+		ContestInfo {
+			contest_id: contest.contest_id,
+			title: contest.title.clone().into_inner(),
+			creator: contest.user_address.clone(),
+			prize_token_id: contest.prize_token_id,
+			prize_token_amount: contest.prize_token_amount,
+			open: contest.statcode,
+			contest_end_date: contest.contest_end_date,
+			close_date: Self::contest_close_date(contest),
+			entries: ContestEntriesCount::<T>::get(contest.contest_id)
+		}
+	}
+}
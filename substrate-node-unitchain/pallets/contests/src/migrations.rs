@@ -0,0 +1,191 @@
+//! Storage migrations for the contests pallet.
+
+use super::*;
+
+pub mod v1 {
+	use super::*;
+	use frame_support::{
+		log,
+		storage_alias,
+		traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+		weights::Weight,
+	};
+
+	/// Contest as stored before version 1, the end date was free text.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	pub struct OldContest<T: Config> {
+		pub contest_id: u32,
+		pub title: BoundedVec<u8, T::MaxTitleLength>,
+		pub user_address: T::AccountId,
+		pub prize_token_id: AssetIdOf<T>,
+		pub prize_token_amount: AssetBalanceOf<T>,
+		pub prize_token_winner: u32,
+		pub token_symbol: BoundedVec<u8, T::MaxTokenSymbolLength>,
+		pub statcode: bool,
+		pub contest_end_date: Vec<u8>,
+		pub description: BoundedVec<u8, T::MaxDescriptionLength>
+	}
+
+	/// Contest entry as stored before version 1, without submission.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	pub struct OldContestEntry<T: Config> {
+		pub user_address: T::AccountId,
+		pub contest_id: u32,
+		pub entry_id: u32,
+		pub winner: bool,
+	}
+
+	#[storage_alias]
+	pub type ContestsMap<T: Config> = StorageMap<Pallet<T>, Blake2_128Concat, u32, OldContest<T>>;
+
+	#[storage_alias]
+	pub type EntriesMap<T: Config> = StorageMap<Pallet<T>, Blake2_128Concat, u32, OldContestEntry<T>>;
+
+	/// Migrate contests and entries from version 0 to version 1.
+	///
+	/// - The remaining prize is split equally between the winners not paid yet, as version 0
+	///   paid them, the rounding dust goes to the 1st place.
+	/// - The free text end date can't be turned into a block, open contests end
+	///   `MinContestDuration` blocks after the upgrade and are closed by the scheduler.
+	/// - Entries get an empty submission, and the counters and indexes added in version 1
+	///   are built from the existing contests and entries.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+			if onchain_version != 0 {
+				log::info!(target: "runtime::contests", "MigrateToV1 should be removed, storage is at {:?}", onchain_version);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let mut reads: u64 = 1;
+			let mut writes: u64 = 0;
+			let mut next_contest_id: u32 = 0;
+			let mut next_entry_id: u32 = 0;
+
+			crate::ContestsMap::<T>::translate::<OldContest<T>, _>(|contest_id, old| {
+				reads += 1;
+				writes += 3;
+				next_contest_id = next_contest_id.max(contest_id.saturating_add(1));
+
+				let prize_tiers = if old.statcode && old.prize_token_winner > 0 {
+					let winners: AssetBalanceOf<T> = old.prize_token_winner.into();
+					let prize = old.prize_token_amount / winners;
+					let dust = old.prize_token_amount - prize * winners;
+
+					let mut amounts: Vec<AssetBalanceOf<T>> = (0..old.prize_token_winner).map(|_| prize).collect();
+					if let Some(first) = amounts.first_mut() {
+						*first = *first + dust;
+					}
+
+					amounts
+						.into_iter()
+						.take(T::MaxPrizeTiers::get() as usize)
+						.map(|amount| PrizeTier { amount, entry_id: None })
+						.collect()
+				} else {
+					Vec::new()
+				};
+
+				let contest = Contest::<T> {
+					contest_id,
+					title: old.title,
+					user_address: old.user_address,
+					prize_token_id: old.prize_token_id,
+					prize_token_amount: old.prize_token_amount,
+					prize_token_winner: prize_tiers.len() as u32,
+					prize_tiers: BoundedVec::truncate_from(prize_tiers),
+					prize_components: BoundedVec::default(),
+					entry_fee: None,
+					fee_policy: FeePolicy::Refund,
+					fee_pool: 0,
+					eligibility: None,
+					dispute_period: Zero::zero(),
+					token_symbol: old.token_symbol,
+					statcode: old.statcode,
+					contest_end_date: if old.statcode { now + T::MinContestDuration::get() } else { now },
+					judging: JudgingMode::Creator,
+					description: old.description
+				};
+
+				if contest.statcode && Pallet::<T>::schedule_contest_end(contest_id, contest.contest_end_date).is_err() {
+					log::warn!(target: "runtime::contests", "Contest {} not scheduled to close, close it with close_ended_contest", contest_id);
+				}
+
+				crate::ContestsByCreator::<T>::insert(contest.user_address.clone(), contest_id, ());
+				crate::ContestsByAsset::<T>::insert(contest.prize_token_id, contest_id, ());
+
+				Some(contest)
+			});
+
+			crate::EntriesMap::<T>::translate::<OldContestEntry<T>, _>(|entry_id, old| {
+				reads += 1;
+				writes += 4;
+				next_entry_id = next_entry_id.max(entry_id.saturating_add(1));
+
+				crate::ContestEntriesMap::<T>::insert(old.contest_id, entry_id, ());
+				crate::AccountEntriesCount::<T>::mutate(old.contest_id, old.user_address.clone(), |count| *count = count.saturating_add(1));
+				crate::ContestEntriesCount::<T>::mutate(old.contest_id, |count| *count = count.saturating_add(1));
+
+				Some(ContestEntry::<T> {
+					user_address: old.user_address,
+					contest_id: old.contest_id,
+					entry_id,
+					submission: BoundedVec::default(),
+					winner: old.winner
+				})
+			});
+
+			NextContestId::<T>::set(next_contest_id);
+			NextEntryId::<T>::set(next_entry_id);
+			StorageVersion::new(1).put::<Pallet<T>>();
+			writes += 3;
+
+			log::info!(target: "runtime::contests", "Migrated {} contests and {} entries to version 1", next_contest_id, next_entry_id);
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 0, "contests: storage version is not 0");
+
+			let contests = ContestsMap::<T>::iter_keys().count() as u32;
+			let entries = EntriesMap::<T>::iter_keys().count() as u32;
+
+			Ok((contests, entries).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let (contests, entries): (u32, u32) =
+				Decode::decode(&mut &state[..]).map_err(|_| "contests: can't decode pre_upgrade state")?;
+
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "contests: storage version is not 1");
+			ensure!(crate::ContestsMap::<T>::iter_values().count() as u32 == contests, "contests: contests were lost");
+			ensure!(crate::EntriesMap::<T>::iter_values().count() as u32 == entries, "contests: entries were lost");
+
+			for contest in crate::ContestsMap::<T>::iter_values() {
+				ensure!(contest.contest_id < NextContestId::<T>::get(), "contests: contest id above the counter");
+				ensure!(
+					crate::ContestsByCreator::<T>::contains_key(&contest.user_address, contest.contest_id),
+					"contests: contest missing from creator index"
+				);
+			}
+
+			for contest_entry in crate::EntriesMap::<T>::iter_values() {
+				ensure!(
+					crate::ContestEntriesMap::<T>::contains_key(contest_entry.contest_id, contest_entry.entry_id),
+					"contests: entry missing from contest index"
+				);
+			}
+
+			Ok(())
+		}
+	}
+}
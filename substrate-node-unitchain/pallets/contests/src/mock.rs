@@ -2,12 +2,14 @@ use crate as pallet_contests;
 use frame_support::{
 	parameter_types,
 	PalletId,
-	traits::{AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, ConstU128}};
+	traits::{AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64, ConstU128, EqualPrivilegeOnly, Hooks},
+	weights::Weight};
 use frame_system::{EnsureSigned, EnsureRoot};
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
+	Perbill,
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -25,6 +27,8 @@ frame_support::construct_runtime!(
 		System: frame_system,
         Assets: pallet_assets,
         Balances: pallet_balances,
+		Scheduler: pallet_scheduler,
+		Ranks: pallet_ranks,
 		Contests: pallet_contests,
 	}
 );
@@ -101,18 +105,51 @@ impl pallet_balances::Config for Test {
 	type ReserveIdentifier = [u8; 8];
 }
 
+parameter_types! {
+	pub BlockWeights: frame_system::limits::BlockWeights =
+		frame_system::limits::BlockWeights::simple_max(
+			Weight::from_parts(frame_support::weights::constants::WEIGHT_REF_TIME_PER_SECOND, u64::MAX),
+		);
+	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * BlockWeights::get().max_block;
+}
+
+impl pallet_scheduler::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type PalletsOrigin = OriginCaller;
+	type RuntimeCall = RuntimeCall;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type ScheduleOrigin = EnsureRoot<u64>;
+	type MaxScheduledPerBlock = ConstU32<100>;
+	type WeightInfo = ();
+	type OriginPrivilegeCmp = EqualPrivilegeOnly;
+	type Preimages = ();
+}
+
+impl pallet_ranks::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+}
+
 parameter_types! {
     pub const ContestPalletId: PalletId = PalletId(*b"unittask");
     pub const MaxTitleLength: u32 = 50;
     pub const MinTitleLength: u32 = 10;
     pub const MaxTokenSymbolLength: u32 = 10;
     pub const MinTokenSymbolLength: u32 = 3;
-    pub const MaxContestEndDateLength: u32 = 12;
-    pub const MinContestEndDateLength: u32 = 8;
+    pub const MinContestDuration: u64 = 5;
     pub const MaxDescriptionLength: u32 = 350;
     pub const MinDescriptionLength: u32 = 50;
     pub const MinTokenAmount: u32 = 10;
     pub const MinTokenWinner: u32 = 1;
+    pub const MaxJudges: u32 = 5;
+    pub const MaxPrizeTiers: u32 = 10;
+    pub const MaxPrizeComponents: u32 = 2;
+    pub const MaxSponsorships: u32 = 2;
+    pub const MaxSubmissionLength: u32 = 100;
+    pub const MaxEntriesPerAccount: u32 = 3;
+    pub const MaxEntriesPerContest: u32 = 500;
+    pub const MaxRankNameLength: u32 = 20;
+    pub const DisputeDeposit: Balance = 100;
 }
 
 impl pallet_contests::Config for Test {
@@ -120,17 +157,34 @@ impl pallet_contests::Config for Test {
     type Assets = Assets;
     type AssetBalance = u128;
     type AssetId = u32;
+    type Currency = Balances;
+    type Ranks = Ranks;
     type PalletId = ContestPalletId;
     type MaxTitleLength = MaxTitleLength;
     type MinTitleLength = MinTitleLength;
     type MaxTokenSymbolLength = MaxTokenSymbolLength;
     type MinTokenSymbolLength = MinTokenSymbolLength;
-    type MaxContestEndDateLength = MaxContestEndDateLength;
-    type MinContestEndDateLength = MinContestEndDateLength;
+    type MinContestDuration = MinContestDuration;
     type MaxDescriptionLength = MaxDescriptionLength;
     type MinDescriptionLength = MinDescriptionLength;
     type MinTokenAmount = MinTokenAmount;
     type MinTokenWinner = MinTokenWinner;
+    type MaxJudges = MaxJudges;
+    type MaxPrizeTiers = MaxPrizeTiers;
+    type MaxPrizeComponents = MaxPrizeComponents;
+    type MaxSponsorships = MaxSponsorships;
+    type MaxSubmissionLength = MaxSubmissionLength;
+    type MaxEntriesPerAccount = MaxEntriesPerAccount;
+    type MaxEntriesPerContest = MaxEntriesPerContest;
+    type MaxRankNameLength = MaxRankNameLength;
+    type DisputeDeposit = DisputeDeposit;
+    type DisputeOrigin = EnsureRoot<u64>;
+    type PalletsOrigin = OriginCaller;
+    type ContestCall = RuntimeCall;
+    type Scheduler = Scheduler;
+    type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = pallet_contests::AssetsBenchmarkHelper<Assets>;
 }
 
 pub const ALICE: u64 = 0;
@@ -157,3 +211,14 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 	test_ext.execute_with(|| System::set_block_number(1));
 	test_ext
 }
+
+pub fn next_block() {
+	System::set_block_number(System::block_number() + 1);
+	Scheduler::on_initialize(System::block_number());
+}
+
+pub fn fast_forward_to(n: u64) {
+	while System::block_number() < n {
+		next_block();
+	}
+}
@@ -5,7 +5,6 @@ use frame_support::{assert_noop, assert_ok, BoundedVec};
 /*
 * FUNCTIONS
 - contest_new
-	ensure!(!ContestsMap::<T>::contains_key(contest_id), Error::<T>::ContestIdAlreadyInUse);
 	ensure!(T::Assets::asset_exists(prize_token_id.clone()), Error::<T>::AssetDontExist);
 	ensure!(prize_token_winner >= T::MinTokenWinner::get(), Error::<T>::PrizeTokenWinnerTooSmall);
 	ensure!(title.len() as u32 >= T::MinTitleLength::get(), Error::<T>::TitleTooSmall);
@@ -25,8 +24,11 @@ use frame_support::{assert_noop, assert_ok, BoundedVec};
 	ensure!(contest.user_address == who, Error::<T>::OnlyOwnerCanChange);
 
 - create_entry_contest
-	ensure!(!EntriesMap::<T>::contains_key(entry_id), Error::<T>::EntryIdAlreadyExist);		
 	ensure!(ContestsMap::<T>::contains_key(contest_id), Error::<T>::ContestIdDontExist);
+	ensure!(!submission.is_empty(), Error::<T>::SubmissionTooSmall);
+	ensure!(AccountEntriesCount::<T>::get(contest_id, &who) < T::MaxEntriesPerAccount::get(), Error::<T>::TooManyEntries);
+	ensure!(contest.statcode, Error::<T>::ContestAlreadyClosed);
+	ensure!(<frame_system::Pallet<T>>::block_number() < contest.contest_end_date, Error::<T>::ContestEndDatePassed);
 
 - assign_contest_winner
 	ensure!(EntriesMap::<T>::contains_key(entry_id.clone()), Error::<T>::EntryIdDontExist);
@@ -41,6 +43,11 @@ use frame_support::{assert_noop, assert_ok, BoundedVec};
 	
 	ensure!(contest.statcode, Error::<T>::ContestAlreadyClosed);
 	ensure!(who == contest.user_address, Error::<T>::OnlyOwnerCanAssignContestWinner);
+	ensure!(!contest_entry.winner, Error::<T>::EntryAlreadyWinner);
+
+	let tier = contest.prize_tiers.get(rank as usize).ok_or(Error::<T>::PrizeTierDontExist)?;
+
+	ensure!(tier.entry_id.is_none(), Error::<T>::PrizeTierAlreadyPaid);
 
 - close_contest
 	ensure!(ContestsMap::<T>::contains_key(contest_id.clone()), Error::<T>::ContestIdDontExist);
@@ -51,36 +58,74 @@ use frame_support::{assert_noop, assert_ok, BoundedVec};
 	ensure!(contest.user_address == who, Error::<T>::OnlyOwnerCanCloseContest);
 */
 
+fn shares(shares: Vec<u16>) -> PrizeTiers<Test> {
+	PrizeTiers::Shares(BoundedVec::try_from(shares).unwrap())
+}
+
+fn amounts(amounts: Vec<u128>) -> PrizeTiers<Test> {
+	PrizeTiers::Amounts(BoundedVec::try_from(amounts).unwrap())
+}
+
 fn create_contest() {
-	let title: BoundedVec<u8, <Test as pallet::Config>::MaxTitleLength> = BoundedVec::try_from("UNIT CONTEST".as_bytes().to_vec()).unwrap();
-	let token_symbol: BoundedVec<u8, <Test as pallet::Config>::MaxTokenSymbolLength> = BoundedVec::try_from("UNIT".as_bytes().to_vec()).unwrap();
-	let contest_end_date: BoundedVec<u8, <Test as pallet::Config>::MaxContestEndDateLength> = BoundedVec::try_from("20/10/2023".as_bytes().to_vec()).unwrap();
-	let description: BoundedVec<u8, <Test as pallet::Config>::MaxDescriptionLength> = BoundedVec::try_from("Roseum tenerum flores prunorum in aura tepida veris saltantes.".as_bytes().to_vec()).unwrap();
+	create_contest_with_tiers(shares(vec![500, 500]));
+}
 
+fn create_contest_with_tiers(prize_tiers: PrizeTiers<Test>) {
 	assert_ok!(Assets::create(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1));
 
 	assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1_000_000_000_000_000));
 	assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 0.into(), Contests::account_id(), 1_000_000_000_000_000));
 
+	new_contest(prize_tiers);
+}
+
+fn new_contest(prize_tiers: PrizeTiers<Test>) {
+	new_contest_judged_by(prize_tiers, JudgingMode::Creator);
+}
+
+fn new_contest_judged_by(prize_tiers: PrizeTiers<Test>, judging: JudgingMode<Test>) {
+	new_contest_with(prize_tiers, judging, None, FeePolicy::Refund, None, 0);
+}
+
+fn new_contest_with(
+	prize_tiers: PrizeTiers<Test>,
+	judging: JudgingMode<Test>,
+	entry_fee: Option<EntryFee<Test>>,
+	fee_policy: FeePolicy,
+	eligibility: Option<Eligibility<Test>>,
+	dispute_period: u64
+) {
+	let title: BoundedVec<u8, <Test as pallet::Config>::MaxTitleLength> = BoundedVec::try_from("UNIT CONTEST".as_bytes().to_vec()).unwrap();
+	let token_symbol: BoundedVec<u8, <Test as pallet::Config>::MaxTokenSymbolLength> = BoundedVec::try_from("UNIT".as_bytes().to_vec()).unwrap();
+	let description: BoundedVec<u8, <Test as pallet::Config>::MaxDescriptionLength> = BoundedVec::try_from("Roseum tenerum flores prunorum in aura tepida veris saltantes.".as_bytes().to_vec()).unwrap();
+
 	assert_ok!(Contests::contest_new(
 		RuntimeOrigin::signed(ALICE),
-		0,
 		title,
 		0,
 		100,
-		2,
+		prize_tiers,
 		token_symbol,
-		contest_end_date,
-		description)
+		20,
+		description,
+		judging,
+		entry_fee,
+		fee_policy,
+		eligibility,
+		dispute_period)
 	);
 }
 
+fn submission() -> BoundedVec<u8, <Test as pallet::Config>::MaxSubmissionLength> {
+	BoundedVec::try_from("ipfs://QmYwAPJzv5CZsnAzt8auVTLpG1bG6dkprdFM5ocTyBCQb".as_bytes().to_vec()).unwrap()
+}
+
 #[test]
 fn create_contest_sucessfull() {
 	new_test_ext().execute_with(|| {
 		create_contest();
 
-		System::assert_last_event(Event::ContestCreated { who: ALICE, contest_id: 0, title:  BoundedVec::try_from("UNIT CONTEST".as_bytes().to_vec()).unwrap()}.into())
+		System::assert_last_event(Event::ContestCreted { who: ALICE, contest_id: 0, title:  BoundedVec::try_from("UNIT CONTEST".as_bytes().to_vec()).unwrap()}.into())
 	});
 }
 
@@ -90,17 +135,16 @@ fn update_contest_sucessfull() {
 		create_contest();
 
 		let title: BoundedVec<u8, <Test as pallet::Config>::MaxTitleLength> = BoundedVec::try_from("UNIT CONTEST UPDATED".as_bytes().to_vec()).unwrap();
-		let token_symbol: BoundedVec<u8, <Test as pallet::Config>::MaxTokenSymbolLength> = BoundedVec::try_from("UNIT UPDATED".as_bytes().to_vec()).unwrap();
-		let contest_end_date: BoundedVec<u8, <Test as pallet::Config>::MaxContestEndDateLength> = BoundedVec::try_from("20/10/2030".as_bytes().to_vec()).unwrap();
+		let contest_end_date = 30;
 		let description: BoundedVec<u8, <Test as pallet::Config>::MaxDescriptionLength> = BoundedVec::try_from("UPDATED UPDATED UPDATED UPDATED UPDATED UPDATED UPDATED UPDATED UPDATED UPDATED.".as_bytes().to_vec()).unwrap();
 
-		Contests::update_contest(
-			RuntimeOrigin::singed(ALICE),
-			0.into(),
-			title,
-			description,
+		assert_ok!(Contests::update_contest(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			title.clone(),
+			description.clone(),
 			contest_end_date
-		);
+		));
 
 		System::assert_last_event(Event::ContestUpdated { who: ALICE, contest_id: 0, title, description, contest_end_date }.into())
 	});
@@ -111,13 +155,13 @@ fn create_entry_sucessfull() {
 	new_test_ext().execute_with(|| {
 		create_contest();
 
-		Constests::create_contest_entry(
+		assert_ok!(Contests::create_contest_entry(
 			RuntimeOrigin::signed(BOB),
 			0,
-			10
-		);
+			submission()
+		));
 
-		System::assert_last_event(Event::EntryCreated { who: BOB, contest_id: 0, entry: 10 }.into());
+		System::assert_last_event(Event::EntryCreated { who: BOB, contest_id: 0, entry_id: 0 }.into());
 	});
 }
 
@@ -126,18 +170,19 @@ fn assign_contest_winner_sucessfull() {
 	new_test_ext().execute_with(|| {
 		create_contest();
 
-		Constests::create_contest_entry(
+		assert_ok!(Contests::create_contest_entry(
 			RuntimeOrigin::signed(BOB),
 			0,
-			10
-		);
+			submission()
+		));
 
-		Contests::assign_contest_winner(
+		assert_ok!(Contests::assign_contest_winner(
 			RuntimeOrigin::signed(ALICE),
-			10
-		);
+			0,
+			0
+		));
 
-		System::assert_last_event(Event::ContestWinnerAssigned { contest_id: 0, winner: BOB, prize: 50.into() }.into());
+		System::assert_last_event(Event::ContestWinnerAssigned { contest_id: 0, winner: BOB, rank: 0, prize: 50.into() }.into());
 	});
 }
 
@@ -146,11 +191,1084 @@ fn close_contest_sucessfull() {
 	new_test_ext().execute_with(|| {
 		create_contest();
 
-		Contests::close_contest(
+		assert_ok!(Contests::close_contract(
 			RuntimeOrigin::signed(ALICE),
 			0
-		);
+		));
 
 		System::assert_last_event(Event::ContestClosed { who: ALICE, contest_id: 0 }.into());
 	}); 
-}
\ No newline at end of file
+}
+
+#[test]
+fn create_contest_with_past_end_date_fails() {
+	new_test_ext().execute_with(|| {
+		let title: BoundedVec<u8, <Test as pallet::Config>::MaxTitleLength> = BoundedVec::try_from("UNIT CONTEST".as_bytes().to_vec()).unwrap();
+		let token_symbol: BoundedVec<u8, <Test as pallet::Config>::MaxTokenSymbolLength> = BoundedVec::try_from("UNIT".as_bytes().to_vec()).unwrap();
+		let description: BoundedVec<u8, <Test as pallet::Config>::MaxDescriptionLength> = BoundedVec::try_from("Roseum tenerum flores prunorum in aura tepida veris saltantes.".as_bytes().to_vec()).unwrap();
+
+		assert_ok!(Assets::create(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1_000_000_000_000_000));
+
+		assert_noop!(
+			Contests::contest_new(
+				RuntimeOrigin::signed(ALICE),
+				title,
+				0,
+				100,
+				shares(vec![500, 500]),
+				token_symbol,
+				3,
+				description,
+				JudgingMode::Creator,
+				None,
+				FeePolicy::Refund,
+				None,
+				0
+			),
+			Error::<Test>::InvalidContestEndDate
+		);
+	});
+}
+
+#[test]
+fn create_contest_with_exhausted_contest_ids_fails() {
+	new_test_ext().execute_with(|| {
+		let title: BoundedVec<u8, <Test as pallet::Config>::MaxTitleLength> = BoundedVec::try_from("UNIT CONTEST".as_bytes().to_vec()).unwrap();
+		let token_symbol: BoundedVec<u8, <Test as pallet::Config>::MaxTokenSymbolLength> = BoundedVec::try_from("UNIT".as_bytes().to_vec()).unwrap();
+		let description: BoundedVec<u8, <Test as pallet::Config>::MaxDescriptionLength> = BoundedVec::try_from("Roseum tenerum flores prunorum in aura tepida veris saltantes.".as_bytes().to_vec()).unwrap();
+
+		create_contest();
+
+		NextContestId::<Test>::put(u32::MAX);
+
+		assert_noop!(
+			Contests::contest_new(
+				RuntimeOrigin::signed(ALICE),
+				title,
+				0,
+				100,
+				shares(vec![500, 500]),
+				token_symbol,
+				20,
+				description,
+				JudgingMode::Creator,
+				None,
+				FeePolicy::Refund,
+				None,
+				0
+			),
+			Error::<Test>::ContestIdOverflow
+		);
+	});
+}
+
+#[test]
+fn create_contest_with_overflowing_close_date_fails() {
+	new_test_ext().execute_with(|| {
+		let title: BoundedVec<u8, <Test as pallet::Config>::MaxTitleLength> = BoundedVec::try_from("UNIT CONTEST".as_bytes().to_vec()).unwrap();
+		let token_symbol: BoundedVec<u8, <Test as pallet::Config>::MaxTokenSymbolLength> = BoundedVec::try_from("UNIT".as_bytes().to_vec()).unwrap();
+		let description: BoundedVec<u8, <Test as pallet::Config>::MaxDescriptionLength> = BoundedVec::try_from("Roseum tenerum flores prunorum in aura tepida veris saltantes.".as_bytes().to_vec()).unwrap();
+
+		assert_ok!(Assets::create(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1_000_000_000_000_000));
+
+		for judging in vec![
+			JudgingMode::CommunityVote { voting_period: u64::MAX },
+			JudgingMode::Panel { judges: BoundedVec::try_from(vec![BOB]).unwrap(), quorum: 1, judging_period: u64::MAX },
+		] {
+			assert_noop!(
+				Contests::contest_new(
+					RuntimeOrigin::signed(ALICE),
+					title.clone(),
+					0,
+					100,
+					shares(vec![500, 500]),
+					token_symbol.clone(),
+					20,
+					description.clone(),
+					judging,
+					None,
+					FeePolicy::Refund,
+					None,
+					0
+				),
+				Error::<Test>::InvalidContestEndDate
+			);
+		}
+
+		assert_noop!(
+			Contests::contest_new(
+				RuntimeOrigin::signed(ALICE),
+				title,
+				0,
+				100,
+				shares(vec![500, 500]),
+				token_symbol,
+				20,
+				description,
+				JudgingMode::Creator,
+				None,
+				FeePolicy::Refund,
+				None,
+				u64::MAX
+			),
+			Error::<Test>::InvalidDisputePeriod
+		);
+	});
+}
+
+#[test]
+fn create_entry_after_end_date_fails() {
+	new_test_ext().execute_with(|| {
+		create_contest();
+
+		System::set_block_number(20);
+
+		assert_noop!(
+			Contests::create_contest_entry(RuntimeOrigin::signed(BOB), 0, submission()),
+			Error::<Test>::ContestEndDatePassed
+		);
+	});
+}
+
+#[test]
+fn contest_is_closed_and_refunded_at_end_date() {
+	new_test_ext().execute_with(|| {
+		create_contest();
+
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(BOB), 0, submission()));
+		assert_ok!(Contests::assign_contest_winner(RuntimeOrigin::signed(ALICE), 0, 0));
+
+		let creator_balance = Assets::balance(0, ALICE);
+
+		fast_forward_to(20);
+
+		System::assert_has_event(Event::ContestEnded { contest_id: 0, refunded: 50 }.into());
+
+		let contest = Contests::get_contests(0).unwrap();
+		assert!(!contest.statcode);
+		assert_eq!(contest.prize_token_amount, 0);
+		assert_eq!(Assets::balance(0, ALICE), creator_balance + 50);
+	});
+}
+
+#[test]
+fn ended_contest_is_closed_by_anyone() {
+	new_test_ext().execute_with(|| {
+		create_voted_contest(amounts(vec![70, 30]));
+
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(BOB), 0, submission()));
+
+		fast_forward_to(20);
+
+		assert_ok!(Contests::vote_entry(RuntimeOrigin::signed(CHARLIE), 0));
+		assert_noop!(
+			Contests::close_ended_contest(RuntimeOrigin::signed(DAVE), 0),
+			Error::<Test>::ContestNotEnded
+		);
+
+		let creator_balance = Assets::balance(0, ALICE);
+
+		// The scheduler did not run at the close date.
+		System::set_block_number(30);
+
+		assert_ok!(Contests::close_ended_contest(RuntimeOrigin::signed(DAVE), 0));
+
+		System::assert_last_event(Event::ContestEnded { contest_id: 0, refunded: 30 }.into());
+		assert_eq!(Assets::balance(0, BOB), 70);
+		assert_eq!(Assets::balance(0, ALICE), creator_balance + 30);
+		assert!(!Contests::get_contests(0).unwrap().statcode);
+		assert_noop!(
+			Contests::close_ended_contest(RuntimeOrigin::signed(DAVE), 0),
+			Error::<Test>::ContestAlreadyClosed
+		);
+	});
+}
+
+#[test]
+fn entries_are_indexed_by_contest() {
+	new_test_ext().execute_with(|| {
+		create_contest();
+
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(BOB), 0, submission()));
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(CHARLIE), 0, submission()));
+
+		assert_eq!(Contests::next_entry_id(), 2);
+		assert_eq!(Contests::ger_entries(1).unwrap().user_address, CHARLIE);
+		assert_eq!(Contests::ger_entries(1).unwrap().submission, submission());
+
+		let entries: Vec<u32> = ContestEntriesMap::<Test>::iter_key_prefix(0).collect();
+		assert_eq!(entries.len(), 2);
+		assert!(entries.contains(&0) && entries.contains(&1));
+		assert_eq!(Contests::account_entries(0, BOB), 1);
+	});
+}
+
+#[test]
+fn create_entry_over_account_limit_fails() {
+	new_test_ext().execute_with(|| {
+		create_contest();
+
+		for _ in 0..<Test as pallet::Config>::MaxEntriesPerAccount::get() {
+			assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(BOB), 0, submission()));
+		}
+
+		assert_noop!(
+			Contests::create_contest_entry(RuntimeOrigin::signed(BOB), 0, submission()),
+			Error::<Test>::TooManyEntries
+		);
+		assert_noop!(
+			Contests::create_contest_entry(RuntimeOrigin::signed(CHARLIE), 0, BoundedVec::default()),
+			Error::<Test>::SubmissionTooSmall
+		);
+	});
+}
+
+#[test]
+fn create_entry_over_contest_limit_fails() {
+	new_test_ext().execute_with(|| {
+		create_contest();
+
+		let max_entries = <Test as pallet::Config>::MaxEntriesPerContest::get();
+
+		for account in 0..max_entries as u64 {
+			assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(100 + account), 0, submission()));
+		}
+
+		assert_eq!(Contests::entries_count(0), max_entries);
+		assert_noop!(
+			Contests::create_contest_entry(RuntimeOrigin::signed(BOB), 0, submission()),
+			Error::<Test>::TooManyContestEntries
+		);
+	});
+}
+
+#[test]
+fn create_entry_with_exhausted_entry_ids_fails() {
+	new_test_ext().execute_with(|| {
+		create_contest();
+
+		NextEntryId::<Test>::put(u32::MAX);
+
+		assert_noop!(
+			Contests::create_contest_entry(RuntimeOrigin::signed(BOB), 0, submission()),
+			Error::<Test>::EntryIdOverflow
+		);
+	});
+}
+
+#[test]
+fn create_entry_on_closed_contest_fails() {
+	new_test_ext().execute_with(|| {
+		create_contest();
+
+		assert_ok!(Contests::close_contract(RuntimeOrigin::signed(ALICE), 0));
+
+		assert_noop!(
+			Contests::create_contest_entry(RuntimeOrigin::signed(BOB), 0, submission()),
+			Error::<Test>::ContestAlreadyClosed
+		);
+	});
+}
+
+#[test]
+fn prize_tiers_are_paid_by_rank() {
+	new_test_ext().execute_with(|| {
+		create_contest_with_tiers(amounts(vec![60, 30, 10]));
+
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(BOB), 0, submission()));
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(CHARLIE), 0, submission()));
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(DAVE), 0, submission()));
+
+		assert_ok!(Contests::assign_contest_winner(RuntimeOrigin::signed(ALICE), 1, 0));
+		assert_ok!(Contests::assign_contest_winner(RuntimeOrigin::signed(ALICE), 0, 2));
+		assert_eq!(Assets::balance(0, CHARLIE), 60);
+		assert_eq!(Assets::balance(0, BOB), 10);
+
+		assert_noop!(
+			Contests::assign_contest_winner(RuntimeOrigin::signed(ALICE), 2, 0),
+			Error::<Test>::PrizeTierAlreadyPaid
+		);
+		assert_noop!(
+			Contests::assign_contest_winner(RuntimeOrigin::signed(ALICE), 0, 1),
+			Error::<Test>::EntryAlreadyWinner
+		);
+		assert_noop!(
+			Contests::assign_contest_winner(RuntimeOrigin::signed(ALICE), 2, 3),
+			Error::<Test>::PrizeTierDontExist
+		);
+
+		assert_ok!(Contests::assign_contest_winner(RuntimeOrigin::signed(ALICE), 2, 1));
+		assert_eq!(Assets::balance(0, DAVE), 30);
+
+		let contest = Contests::get_contests(0).unwrap();
+		assert!(!contest.statcode);
+		assert_eq!(contest.prize_token_amount, 0);
+		assert_eq!(contest.prize_tiers[1].entry_id, Some(2));
+	});
+}
+
+#[test]
+fn prize_tier_shares_give_dust_to_first_place() {
+	new_test_ext().execute_with(|| {
+		create_contest_with_tiers(shares(vec![334, 333, 333]));
+
+		let contest = Contests::get_contests(0).unwrap();
+		let tiers: Vec<u128> = contest.prize_tiers.iter().map(|tier| tier.amount).collect();
+
+		assert_eq!(tiers, vec![34, 33, 33]);
+	});
+}
+
+#[test]
+fn create_contest_with_mismatched_tiers_fails() {
+	new_test_ext().execute_with(|| {
+		let title: BoundedVec<u8, <Test as pallet::Config>::MaxTitleLength> = BoundedVec::try_from("UNIT CONTEST".as_bytes().to_vec()).unwrap();
+		let token_symbol: BoundedVec<u8, <Test as pallet::Config>::MaxTokenSymbolLength> = BoundedVec::try_from("UNIT".as_bytes().to_vec()).unwrap();
+		let description: BoundedVec<u8, <Test as pallet::Config>::MaxDescriptionLength> = BoundedVec::try_from("Roseum tenerum flores prunorum in aura tepida veris saltantes.".as_bytes().to_vec()).unwrap();
+
+		assert_ok!(Assets::create(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1_000_000_000_000_000));
+
+		assert_noop!(
+			Contests::contest_new(
+				RuntimeOrigin::signed(ALICE),
+				title.clone(),
+				0,
+				100,
+				amounts(vec![60, 30]),
+				token_symbol.clone(),
+				20,
+				description.clone(),
+				JudgingMode::Creator,
+				None,
+				FeePolicy::Refund,
+				None,
+				0
+			),
+			Error::<Test>::PrizeTiersMismatch
+		);
+		assert_noop!(
+			Contests::contest_new(
+				RuntimeOrigin::signed(ALICE),
+				title,
+				0,
+				100,
+				shares(vec![600, 300]),
+				token_symbol,
+				20,
+				description,
+				JudgingMode::Creator,
+				None,
+				FeePolicy::Refund,
+				None,
+				0
+			),
+			Error::<Test>::PrizeTiersMismatch
+		);
+	});
+}
+
+fn ranked(entry_ids: Vec<u32>) -> BoundedVec<u32, <Test as pallet::Config>::MaxPrizeTiers> {
+	BoundedVec::try_from(entry_ids).unwrap()
+}
+
+#[test]
+fn finalize_contest_pays_all_tiers() {
+	new_test_ext().execute_with(|| {
+		create_contest_with_tiers(amounts(vec![60, 30, 10]));
+
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(BOB), 0, submission()));
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(CHARLIE), 0, submission()));
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(DAVE), 0, submission()));
+
+		let creator_balance = Assets::balance(0, ALICE);
+
+		assert_ok!(Contests::finalize_contest(RuntimeOrigin::signed(ALICE), 0, ranked(vec![2, 0])));
+
+		System::assert_last_event(Event::ContestFinalized {
+			who: ALICE,
+			contest_id: 0,
+			winners: vec![(2, DAVE, 60), (0, BOB, 30)],
+			refunded: 10
+		}.into());
+
+		assert_eq!(Assets::balance(0, DAVE), 60);
+		assert_eq!(Assets::balance(0, BOB), 30);
+		assert_eq!(Assets::balance(0, ALICE), creator_balance + 10);
+		assert!(Contests::ger_entries(2).unwrap().winner);
+
+		let contest = Contests::get_contests(0).unwrap();
+		assert!(!contest.statcode);
+		assert_eq!(contest.prize_token_amount, 0);
+	});
+}
+
+#[test]
+fn finalize_contest_skips_assigned_tiers() {
+	new_test_ext().execute_with(|| {
+		create_contest_with_tiers(amounts(vec![60, 30, 10]));
+
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(BOB), 0, submission()));
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(CHARLIE), 0, submission()));
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(DAVE), 0, submission()));
+
+		assert_ok!(Contests::assign_contest_winner(RuntimeOrigin::signed(ALICE), 0, 1));
+
+		assert_noop!(
+			Contests::finalize_contest(RuntimeOrigin::signed(ALICE), 0, ranked(vec![2, 1, 0])),
+			Error::<Test>::TooManyWinners
+		);
+
+		assert_ok!(Contests::finalize_contest(RuntimeOrigin::signed(ALICE), 0, ranked(vec![2, 1])));
+
+		System::assert_last_event(Event::ContestFinalized {
+			who: ALICE,
+			contest_id: 0,
+			winners: vec![(2, DAVE, 60), (1, CHARLIE, 10)],
+			refunded: 0
+		}.into());
+
+		assert_eq!(Assets::balance(0, BOB), 30);
+		assert_eq!(Assets::balance(0, DAVE), 60);
+		assert_eq!(Assets::balance(0, CHARLIE), 10);
+		assert_eq!(Contests::get_contests(0).unwrap().prize_tiers[1].entry_id, Some(0));
+	});
+}
+
+#[test]
+fn finalize_contest_fails_atomically() {
+	new_test_ext().execute_with(|| {
+		create_contest_with_tiers(amounts(vec![60, 30, 10]));
+		new_contest(amounts(vec![100]));
+
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(BOB), 0, submission()));
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(CHARLIE), 1, submission()));
+
+		assert_noop!(
+			Contests::finalize_contest(RuntimeOrigin::signed(ALICE), 0, ranked(vec![0, 1])),
+			Error::<Test>::EntryNotInContest
+		);
+		assert_noop!(
+			Contests::finalize_contest(RuntimeOrigin::signed(ALICE), 0, ranked(vec![0, 0])),
+			Error::<Test>::EntryAlreadyWinner
+		);
+		assert_noop!(
+			Contests::finalize_contest(RuntimeOrigin::signed(ALICE), 1, ranked(vec![1, 0])),
+			Error::<Test>::TooManyWinners
+		);
+		assert_noop!(
+			Contests::finalize_contest(RuntimeOrigin::signed(BOB), 0, ranked(vec![0])),
+			Error::<Test>::OnlyOwnerCanAssignContestWinner
+		);
+	});
+}
+
+fn create_voted_contest(prize_tiers: PrizeTiers<Test>) {
+	assert_ok!(Assets::create(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1));
+	assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1_000_000_000_000_000));
+	assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 0.into(), CHARLIE, 300));
+	assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 0.into(), DAVE, 200));
+
+	new_contest_judged_by(prize_tiers, JudgingMode::CommunityVote { voting_period: 10 });
+}
+
+#[test]
+fn voted_contest_pays_most_voted_entries() {
+	new_test_ext().execute_with(|| {
+		create_voted_contest(amounts(vec![70, 30]));
+
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(BOB), 0, submission()));
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(CHARLIE), 0, submission()));
+
+		assert_noop!(
+			Contests::vote_entry(RuntimeOrigin::signed(DAVE), 0),
+			Error::<Test>::VotingNotOpen
+		);
+
+		fast_forward_to(20);
+
+		assert_ok!(Contests::vote_entry(RuntimeOrigin::signed(CHARLIE), 0));
+		assert_ok!(Contests::vote_entry(RuntimeOrigin::signed(DAVE), 1));
+
+		System::assert_last_event(Event::EntryVoted { who: DAVE, contest_id: 0, entry_id: 1, weight: 200 }.into());
+
+		// The tokens voted with can't be moved to vote again from another account.
+		assert_eq!(Assets::balance(0, DAVE), 0);
+
+		assert_noop!(
+			Contests::vote_entry(RuntimeOrigin::signed(DAVE), 0),
+			Error::<Test>::AlreadyVoted
+		);
+		assert_noop!(
+			Contests::vote_entry(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::InsufficientVotingBalance
+		);
+		assert_noop!(
+			Contests::assign_contest_winner(RuntimeOrigin::signed(ALICE), 0, 0),
+			Error::<Test>::InvalidJudgingMode
+		);
+		assert_noop!(
+			Contests::close_contract(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Test>::InvalidJudgingMode
+		);
+
+		assert_noop!(
+			Contests::unlock_vote(RuntimeOrigin::signed(CHARLIE), 0),
+			Error::<Test>::ContestStillOpen
+		);
+
+		fast_forward_to(30);
+
+		assert_eq!(Assets::balance(0, BOB), 70);
+		assert_eq!(Assets::balance(0, CHARLIE), 30);
+		assert!(!Contests::get_contests(0).unwrap().statcode);
+
+		assert_ok!(Contests::unlock_vote(RuntimeOrigin::signed(CHARLIE), 0));
+		System::assert_last_event(Event::VoteUnlocked { who: CHARLIE, contest_id: 0, amount: 300 }.into());
+		assert_eq!(Assets::balance(0, CHARLIE), 300 + 30);
+		assert_noop!(
+			Contests::unlock_vote(RuntimeOrigin::signed(CHARLIE), 0),
+			Error::<Test>::NotVoted
+		);
+	});
+}
+
+#[test]
+fn voted_contest_refunds_unclaimed_tiers() {
+	new_test_ext().execute_with(|| {
+		create_voted_contest(amounts(vec![70, 30]));
+
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(BOB), 0, submission()));
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(DAVE), 0, submission()));
+
+		fast_forward_to(20);
+
+		assert_ok!(Contests::vote_entry(RuntimeOrigin::signed(CHARLIE), 1));
+
+		let creator_balance = Assets::balance(0, ALICE);
+
+		fast_forward_to(30);
+
+		System::assert_has_event(Event::ContestEnded { contest_id: 0, refunded: 30 }.into());
+		assert_eq!(Assets::balance(0, DAVE), 200 + 70);
+		assert_eq!(Assets::balance(0, BOB), 0);
+		assert_eq!(Assets::balance(0, ALICE), creator_balance + 30);
+	});
+}
+
+fn panel(judges: Vec<u64>, quorum: u32) -> JudgingMode<Test> {
+	JudgingMode::Panel { judges: BoundedVec::try_from(judges).unwrap(), quorum, judging_period: 10 }
+}
+
+#[test]
+fn panel_contest_pays_winners_once_quorum_is_reached() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::create(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1_000_000_000_000_000));
+		new_contest_judged_by(amounts(vec![70, 30]), panel(vec![ALICE, CHARLIE, DAVE], 2));
+
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(BOB), 0, submission()));
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(CHARLIE), 0, submission()));
+
+		assert_noop!(
+			Contests::submit_judgement(RuntimeOrigin::signed(DAVE), 0, ranked(vec![0, 1])),
+			Error::<Test>::VotingNotOpen
+		);
+
+		fast_forward_to(20);
+
+		assert_noop!(
+			Contests::submit_judgement(RuntimeOrigin::signed(BOB), 0, ranked(vec![0, 1])),
+			Error::<Test>::NotAJudge
+		);
+		assert_noop!(
+			Contests::close_contract(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Test>::InvalidJudgingMode
+		);
+		assert_noop!(
+			Contests::submit_judgement(RuntimeOrigin::signed(DAVE), 0, ranked(vec![1, 1])),
+			Error::<Test>::DuplicateEntry
+		);
+
+		assert_ok!(Contests::submit_judgement(RuntimeOrigin::signed(DAVE), 0, ranked(vec![1, 0])));
+		System::assert_last_event(Event::JudgementSubmitted { judge: DAVE, contest_id: 0, ranked_entry_ids: ranked(vec![1, 0]) }.into());
+		assert_noop!(
+			Contests::submit_judgement(RuntimeOrigin::signed(DAVE), 0, ranked(vec![0])),
+			Error::<Test>::AlreadyJudged
+		);
+		assert_eq!(Assets::balance(0, CHARLIE), 0);
+
+		assert_ok!(Contests::submit_judgement(RuntimeOrigin::signed(CHARLIE), 0, ranked(vec![1])));
+
+		System::assert_last_event(Event::ContestJudged {
+			contest_id: 0,
+			judgements: 2,
+			winners: vec![(1, CHARLIE, 70), (0, BOB, 30)],
+			refunded: 0
+		}.into());
+		assert_eq!(Assets::balance(0, CHARLIE), 70);
+		assert_eq!(Assets::balance(0, BOB), 30);
+		assert!(!Contests::get_contests(0).unwrap().statcode);
+		assert!(Contests::judgements(0, DAVE).is_none());
+		assert!(Contests::judgements(0, CHARLIE).is_none());
+	});
+}
+
+#[test]
+fn panel_contest_without_quorum_is_refunded() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::create(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1_000_000_000_000_000));
+		new_contest_judged_by(amounts(vec![70, 30]), panel(vec![CHARLIE, DAVE], 2));
+
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(BOB), 0, submission()));
+
+		fast_forward_to(20);
+		assert_ok!(Contests::submit_judgement(RuntimeOrigin::signed(DAVE), 0, ranked(vec![0])));
+
+		fast_forward_to(30);
+
+		System::assert_has_event(Event::ContestEnded { contest_id: 0, refunded: 100 }.into());
+		assert_eq!(Assets::balance(0, BOB), 0);
+		assert!(Contests::judgements(0, DAVE).is_none());
+	});
+}
+
+#[test]
+fn entry_fees_are_shared_with_winners() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::create(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1_000_000_000_000_000));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 0.into(), BOB, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 0.into(), CHARLIE, 100));
+		new_contest_with(amounts(vec![70, 30]), JudgingMode::Creator, Some(EntryFee::Asset(10)), FeePolicy::Refund, None, 0);
+
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(BOB), 0, submission()));
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(CHARLIE), 0, submission()));
+
+		System::assert_has_event(Event::EntryFeePaid { who: CHARLIE, contest_id: 0, entry_id: 1, fee: 10 }.into());
+		let contest = Contests::get_contests(0).unwrap();
+		assert_eq!(contest.prize_token_amount, 120);
+		assert_eq!(contest.fee_pool, 20);
+
+		assert_ok!(Contests::finalize_contest(RuntimeOrigin::signed(ALICE), 0, ranked(vec![1, 0])));
+
+		assert_eq!(Assets::balance(0, CHARLIE), 90 + 70 + 14);
+		assert_eq!(Assets::balance(0, BOB), 90 + 30 + 6);
+		assert_eq!(Assets::balance(0, Contests::account_id()), 0);
+		let contest = Contests::get_contests(0).unwrap();
+		assert_eq!(contest.prize_token_amount, 0);
+		assert_eq!(contest.fee_pool, 0);
+	});
+}
+
+#[test]
+fn entry_fees_are_refunded_without_winners() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::create(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1_000_000_000_000_000));
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(ALICE), BOB, 1_000));
+		new_contest_with(amounts(vec![100]), JudgingMode::Creator, Some(EntryFee::Native(50)), FeePolicy::Refund, None, 0);
+
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(BOB), 0, submission()));
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(BOB), 0, submission()));
+
+		assert_eq!(Balances::free_balance(BOB), 900);
+		assert_eq!(Contests::get_contests(0).unwrap().fee_pool, 100);
+
+		fast_forward_to(20);
+
+		assert_eq!(Balances::free_balance(BOB), 1_000);
+		assert_eq!(Contests::get_contests(0).unwrap().fee_pool, 0);
+		System::assert_has_event(Event::ContestEnded { contest_id: 0, refunded: 100 }.into());
+	});
+}
+
+#[test]
+fn entry_fees_are_forfeited_to_creator() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::create(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1_000_000_000_000_000));
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(ALICE), BOB, 1_000));
+		new_contest_with(amounts(vec![100]), JudgingMode::Creator, Some(EntryFee::Native(50)), FeePolicy::Forfeit, None, 0);
+
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(BOB), 0, submission()));
+
+		let creator_balance = Balances::free_balance(ALICE);
+
+		assert_ok!(Contests::close_contract(RuntimeOrigin::signed(ALICE), 0));
+
+		assert_eq!(Balances::free_balance(BOB), 950);
+		assert_eq!(Balances::free_balance(ALICE), creator_balance + 50);
+		System::assert_has_event(Event::EntryFeesReleased { contest_id: 0, to: ALICE, amount: 50 }.into());
+	});
+}
+
+#[test]
+fn create_contest_with_zero_entry_fee_fails() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::create(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1_000_000_000_000_000));
+		let title: BoundedVec<u8, <Test as pallet::Config>::MaxTitleLength> = BoundedVec::try_from("UNIT CONTEST".as_bytes().to_vec()).unwrap();
+		let token_symbol: BoundedVec<u8, <Test as pallet::Config>::MaxTokenSymbolLength> = BoundedVec::try_from("UNIT".as_bytes().to_vec()).unwrap();
+		let description: BoundedVec<u8, <Test as pallet::Config>::MaxDescriptionLength> = BoundedVec::try_from("Roseum tenerum flores prunorum in aura tepida veris saltantes.".as_bytes().to_vec()).unwrap();
+
+		assert_noop!(
+			Contests::contest_new(
+				RuntimeOrigin::signed(ALICE),
+				title,
+				0,
+				100,
+				amounts(vec![100]),
+				token_symbol,
+				20,
+				description,
+				JudgingMode::Creator,
+				Some(EntryFee::Asset(0)),
+				FeePolicy::Refund,
+				None,
+				0
+			),
+			Error::<Test>::InvalidEntryFee
+		);
+	});
+}
+
+fn rank_name(name: &str) -> BoundedVec<u8, <Test as pallet::Config>::MaxRankNameLength> {
+	BoundedVec::try_from(name.as_bytes().to_vec()).unwrap()
+}
+
+#[test]
+fn create_entry_below_min_balance_fails() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::create(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1_000_000_000_000_000));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 0.into(), BOB, 50));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 0.into(), CHARLIE, 49));
+		new_contest_with(amounts(vec![100]), JudgingMode::Creator, None, FeePolicy::Refund, Some(Eligibility::MinBalance(50)), 0);
+
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(BOB), 0, submission()));
+		assert_noop!(
+			Contests::create_contest_entry(RuntimeOrigin::signed(CHARLIE), 0, submission()),
+			Error::<Test>::BalanceTooLowToEnter
+		);
+	});
+}
+
+#[test]
+fn create_entry_below_min_rank_fails() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::create(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1_000_000_000_000_000));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 0.into(), BOB, 500));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 0.into(), CHARLIE, 100));
+		assert_ok!(Ranks::create_rank(RuntimeOrigin::signed(ALICE), 0, "member".as_bytes().to_vec(), 100));
+		assert_ok!(Ranks::create_rank(RuntimeOrigin::signed(ALICE), 0, "veteran".as_bytes().to_vec(), 500));
+		new_contest_with(amounts(vec![100]), JudgingMode::Creator, None, FeePolicy::Refund, Some(Eligibility::MinRank(rank_name("veteran"))), 0);
+
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(BOB), 0, submission()));
+		assert_noop!(
+			Contests::create_contest_entry(RuntimeOrigin::signed(CHARLIE), 0, submission()),
+			Error::<Test>::RankTooLowToEnter
+		);
+
+		assert_ok!(Ranks::delete_rank(RuntimeOrigin::signed(ALICE), 0, "veteran".as_bytes().to_vec()));
+		assert_noop!(
+			Contests::create_contest_entry(RuntimeOrigin::signed(BOB), 0, submission()),
+			Error::<Test>::RankDontExist
+		);
+	});
+}
+
+#[test]
+fn create_contest_with_unknown_rank_fails() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::create(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1_000_000_000_000_000));
+		let title: BoundedVec<u8, <Test as pallet::Config>::MaxTitleLength> = BoundedVec::try_from("UNIT CONTEST".as_bytes().to_vec()).unwrap();
+		let token_symbol: BoundedVec<u8, <Test as pallet::Config>::MaxTokenSymbolLength> = BoundedVec::try_from("UNIT".as_bytes().to_vec()).unwrap();
+		let description: BoundedVec<u8, <Test as pallet::Config>::MaxDescriptionLength> = BoundedVec::try_from("Roseum tenerum flores prunorum in aura tepida veris saltantes.".as_bytes().to_vec()).unwrap();
+
+		assert_noop!(
+			Contests::contest_new(
+				RuntimeOrigin::signed(ALICE),
+				title,
+				0,
+				100,
+				amounts(vec![100]),
+				token_symbol,
+				20,
+				description,
+				JudgingMode::Creator,
+				None,
+				FeePolicy::Refund,
+				Some(Eligibility::MinRank(rank_name("veteran"))),
+				0
+			),
+			Error::<Test>::RankDontExist
+		);
+	});
+}
+
+fn create_disputed_contest() {
+	assert_ok!(Assets::create(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1));
+	assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1_000_000_000_000_000));
+	assert_ok!(Balances::transfer(RuntimeOrigin::signed(ALICE), CHARLIE, 1_000));
+	new_contest_with(amounts(vec![100]), JudgingMode::Creator, None, FeePolicy::Refund, None, 10);
+
+	assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(BOB), 0, submission()));
+	assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(CHARLIE), 0, submission()));
+	assert_ok!(Contests::assign_contest_winner(RuntimeOrigin::signed(ALICE), 0, 0));
+}
+
+#[test]
+fn held_payout_is_released_after_dispute_period() {
+	new_test_ext().execute_with(|| {
+		create_disputed_contest();
+
+		System::assert_has_event(Event::PayoutHeld { contest_id: 0, rank: 0, winner: BOB, release_at: 11 }.into());
+		assert_eq!(Assets::balance(0, BOB), 0);
+		assert_noop!(Contests::claim_payout(RuntimeOrigin::signed(BOB), 0, 0), Error::<Test>::PayoutStillHeld);
+
+		fast_forward_to(11);
+
+		System::assert_has_event(Event::PayoutReleased { contest_id: 0, rank: 0, winner: BOB, prize: 100 }.into());
+		assert_eq!(Assets::balance(0, BOB), 100);
+		assert!(Contests::pending_payouts(0, 0).is_none());
+	});
+}
+
+#[test]
+fn rejected_dispute_slashes_deposit() {
+	new_test_ext().execute_with(|| {
+		create_disputed_contest();
+
+		assert_noop!(Contests::dispute_payout(RuntimeOrigin::signed(DAVE), 0, 0), Error::<Test>::OnlyEntrantCanDispute);
+		assert_ok!(Contests::dispute_payout(RuntimeOrigin::signed(CHARLIE), 0, 0));
+		assert_eq!(Balances::reserved_balance(CHARLIE), 100);
+
+		fast_forward_to(15);
+		assert_eq!(Assets::balance(0, BOB), 0);
+		assert_noop!(Contests::claim_payout(RuntimeOrigin::signed(BOB), 0, 0), Error::<Test>::PayoutAlreadyDisputed);
+
+		assert_ok!(Contests::resolve_dispute(RuntimeOrigin::root(), 0, 0, None));
+
+		assert_eq!(Assets::balance(0, BOB), 100);
+		assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+		assert_eq!(Balances::free_balance(CHARLIE), 900);
+		System::assert_last_event(Event::DisputeResolved { contest_id: 0, rank: 0, winner: BOB, reassigned: false }.into());
+	});
+}
+
+#[test]
+fn upheld_dispute_reassigns_payout() {
+	new_test_ext().execute_with(|| {
+		create_disputed_contest();
+
+		assert_ok!(Contests::dispute_payout(RuntimeOrigin::signed(CHARLIE), 0, 0));
+		assert_noop!(Contests::resolve_dispute(RuntimeOrigin::signed(ALICE), 0, 0, Some(1)), sp_runtime::DispatchError::BadOrigin);
+		assert_ok!(Contests::resolve_dispute(RuntimeOrigin::root(), 0, 0, Some(1)));
+
+		assert_eq!(Assets::balance(0, BOB), 0);
+		assert_eq!(Assets::balance(0, CHARLIE), 100);
+		assert_eq!(Balances::free_balance(CHARLIE), 1_000);
+		assert!(!Contests::ger_entries(0).unwrap().winner);
+		assert!(Contests::ger_entries(1).unwrap().winner);
+		assert_eq!(Contests::get_contests(0).unwrap().prize_tiers[0].entry_id, Some(1));
+	});
+}
+
+#[test]
+fn sponsored_components_are_shared_between_tiers() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::create(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1));
+		assert_ok!(Assets::create(RuntimeOrigin::signed(ALICE), 1.into(), ALICE, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1_000_000_000_000_000));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 1.into(), DAVE, 1_000));
+		assert_ok!(Assets::create(RuntimeOrigin::signed(ALICE), 2.into(), ALICE, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 2.into(), DAVE, 1_000));
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(ALICE), DAVE, 10_000));
+		new_contest(amounts(vec![70, 30]));
+
+		assert_ok!(Contests::sponsor_contest(RuntimeOrigin::signed(DAVE), 0, PrizeCurrency::Native, 1_000));
+		assert_ok!(Contests::sponsor_contest(RuntimeOrigin::signed(DAVE), 0, PrizeCurrency::Native, 1_000));
+		assert_ok!(Contests::sponsor_contest(RuntimeOrigin::signed(DAVE), 0, PrizeCurrency::Asset(1), 500));
+		assert_noop!(
+			Contests::sponsor_contest(RuntimeOrigin::signed(DAVE), 0, PrizeCurrency::Asset(2), 500),
+			Error::<Test>::TooManyPrizeComponents
+		);
+
+		let contest = Contests::get_contests(0).unwrap();
+		assert_eq!(contest.prize_components.len(), 2);
+		assert_eq!(contest.prize_components[0], PrizeComponent { currency: PrizeCurrency::Native, amount: 2_000 });
+
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(BOB), 0, submission()));
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(CHARLIE), 0, submission()));
+		assert_ok!(Contests::finalize_contest(RuntimeOrigin::signed(ALICE), 0, ranked(vec![0, 1])));
+
+		assert_eq!(Balances::free_balance(BOB), 1_400);
+		assert_eq!(Balances::free_balance(CHARLIE), 600);
+		assert_eq!(Assets::balance(1, BOB), 350);
+		assert_eq!(Assets::balance(1, CHARLIE), 150);
+		assert!(Contests::get_contests(0).unwrap().prize_components.is_empty());
+	});
+}
+
+#[test]
+fn unpaid_sponsored_components_are_refunded_to_sponsors() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::create(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1_000_000_000_000_000));
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(ALICE), CHARLIE, 10_000));
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(ALICE), DAVE, 10_000));
+		new_contest(amounts(vec![70, 30]));
+
+		assert_ok!(Contests::sponsor_contest(RuntimeOrigin::signed(DAVE), 0, PrizeCurrency::Native, 1_000));
+		assert_ok!(Contests::sponsor_contest(RuntimeOrigin::signed(CHARLIE), 0, PrizeCurrency::Native, 3_000));
+		assert_noop!(
+			Contests::sponsor_contest(RuntimeOrigin::signed(ALICE), 0, PrizeCurrency::Native, 1_000),
+			Error::<Test>::TooManySponsorships
+		);
+		assert_eq!(Contests::sponsorships(0).len(), 2);
+
+		assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(BOB), 0, submission()));
+		assert_ok!(Contests::assign_contest_winner(RuntimeOrigin::signed(ALICE), 0, 0));
+
+		let creator_balance = Balances::free_balance(ALICE);
+
+		fast_forward_to(20);
+
+		assert_eq!(Balances::free_balance(BOB), 2_800);
+		assert_eq!(Balances::free_balance(DAVE), 10_000 - 1_000 + 300);
+		assert_eq!(Balances::free_balance(CHARLIE), 10_000 - 3_000 + 900);
+		assert_eq!(Balances::free_balance(ALICE), creator_balance);
+		System::assert_has_event(Event::PrizeComponentPaid {
+			contest_id: 0,
+			to: DAVE,
+			currency: PrizeCurrency::Native,
+			amount: 300
+		}.into());
+		assert!(Contests::sponsorships(0).is_empty());
+	});
+}
+
+#[test]
+fn runtime_api_queries_contests_entries_and_payouts() {
+	new_test_ext().execute_with(|| {
+		create_contest_with_tiers(amounts(vec![70, 30]));
+
+		for who in [BOB, CHARLIE, DAVE] {
+			assert_ok!(Contests::create_contest_entry(RuntimeOrigin::signed(who), 0, submission()));
+		}
+		assert_ok!(Contests::assign_contest_winner(RuntimeOrigin::signed(ALICE), 2, 0));
+
+		let contests = Contests::contests_by_asset(0, true);
+		assert_eq!(contests.len(), 1);
+		assert_eq!(contests[0].entries, 3);
+		assert_eq!(contests[0].prize_token_amount, 30);
+		assert!(Contests::contests_by_asset(0, false).is_empty());
+
+		let page: Vec<u32> = Contests::contest_entries_page(0, 1, 5).into_iter().map(|entry| entry.entry_id).collect();
+		assert_eq!(page, vec![1, 2]);
+
+		let escrow = Contests::contest_escrow(0).unwrap();
+		assert_eq!(escrow.unpaid_prize, 30);
+		assert_eq!(escrow.held_prize, 0);
+
+		assert_eq!(Contests::contest_payouts(0), vec![PayoutInfo {
+			rank: 0,
+			entry_id: 2,
+			winner: DAVE,
+			prize: 70,
+			release_at: None
+		}]);
+	});
+}
+
+#[test]
+fn contest_ids_are_assigned_and_indexed() {
+	new_test_ext().execute_with(|| {
+		create_contest();
+		assert_ok!(Assets::create(RuntimeOrigin::signed(ALICE), 1.into(), ALICE, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 1.into(), BOB, 1_000));
+		assert_ok!(Contests::contest_new(
+			RuntimeOrigin::signed(BOB),
+			BoundedVec::try_from("UNIT CONTEST".as_bytes().to_vec()).unwrap(),
+			1,
+			100,
+			amounts(vec![100]),
+			BoundedVec::try_from("UNIT".as_bytes().to_vec()).unwrap(),
+			20,
+			BoundedVec::try_from("Roseum tenerum flores prunorum in aura tepida veris saltantes.".as_bytes().to_vec()).unwrap(),
+			JudgingMode::Creator,
+			None,
+			FeePolicy::Refund,
+			None,
+			0
+		));
+
+		assert_eq!(Contests::next_contest_id(), 2);
+		assert_eq!(Contests::get_contests(1).unwrap().user_address, BOB);
+		assert!(Contests::contests_by_creator(ALICE, 0).is_some());
+		assert!(Contests::contests_by_creator(BOB, 1).is_some());
+		assert!(Contests::contests_by_creator(ALICE, 1).is_none());
+		assert!(Contests::contests_by_prize_token(1, 1).is_some());
+
+		let contest_ids: Vec<u32> = Contests::contests_of_creator(BOB, true).into_iter().map(|contest| contest.contest_id).collect();
+		assert_eq!(contest_ids, vec![1]);
+	});
+}
+
+#[test]
+fn migration_to_v1_keeps_contests_and_entries() {
+	use crate::migrations::v1;
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Contests>();
+		assert_ok!(Assets::create(RuntimeOrigin::signed(ALICE), 0.into(), ALICE, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(ALICE), 0.into(), Contests::account_id(), 100));
+
+		v1::ContestsMap::<Test>::insert(3, v1::OldContest::<Test> {
+			contest_id: 3,
+			title: BoundedVec::try_from("UNIT CONTEST".as_bytes().to_vec()).unwrap(),
+			user_address: ALICE,
+			prize_token_id: 0,
+			prize_token_amount: 100,
+			prize_token_winner: 3,
+			token_symbol: BoundedVec::try_from("UNIT".as_bytes().to_vec()).unwrap(),
+			statcode: true,
+			contest_end_date: "2023-12-31".as_bytes().to_vec(),
+			description: BoundedVec::try_from("Roseum tenerum flores prunorum in aura tepida veris saltantes.".as_bytes().to_vec()).unwrap()
+		});
+		v1::EntriesMap::<Test>::insert(7, v1::OldContestEntry::<Test> {
+			user_address: BOB,
+			contest_id: 3,
+			entry_id: 7,
+			winner: false
+		});
+
+		v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Contests::on_chain_storage_version(), 1);
+		assert_eq!(Contests::next_contest_id(), 4);
+		assert_eq!(Contests::next_entry_id(), 8);
+
+		let contest = Contests::get_contests(3).unwrap();
+		let amounts: Vec<u128> = contest.prize_tiers.iter().map(|tier| tier.amount).collect();
+		assert_eq!(amounts, vec![34, 33, 33]);
+		assert_eq!(contest.contest_end_date, 1 + MinContestDuration::get());
+		assert!(Contests::contests_by_creator(ALICE, 3).is_some());
+
+		assert!(Contests::ger_entries(7).unwrap().submission.is_empty());
+		assert!(Contests::contest_entries(3, 7).is_some());
+		assert_eq!(Contests::account_entries(3, BOB), 1);
+		assert_eq!(Contests::entries_count(3), 1);
+
+		fast_forward_to(1 + MinContestDuration::get());
+		assert!(!Contests::get_contests(3).unwrap().statcode);
+		assert_eq!(Assets::balance(0, ALICE), 100);
+	});
+}
@@ -0,0 +1,450 @@
+//! Weights for pallet_contests.
+//!
+//! Estimated from the storage read and written by each call until `./scripts/benchmark.sh contests`
+//! is run on reference hardware, which overwrites this file with the measured weights.
+//! The scheduler storage touched when the end of a contest is scheduled or cancelled is counted
+//! in the calls that do so.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_contests.
+pub trait WeightInfo {
+	fn contest_new() -> Weight;
+	fn update_contest() -> Weight;
+	fn create_contest_entry() -> Weight;
+	fn assign_contest_winner() -> Weight;
+	fn close_contract(e: u32, ) -> Weight;
+	fn finalize_contest() -> Weight;
+	fn vote_entry() -> Weight;
+	fn unlock_vote() -> Weight;
+	fn submit_judgement() -> Weight;
+	fn enact_contest_end(e: u32, ) -> Weight;
+	fn close_ended_contest(e: u32, ) -> Weight;
+	fn sponsor_contest() -> Weight;
+	fn claim_payout() -> Weight;
+	fn enact_payout_release() -> Weight;
+	fn dispute_payout() -> Weight;
+	fn resolve_dispute() -> Weight;
+}
+
+/// Weights for pallet_contests using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: Contests NextContestId (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:1 w:1)
+	// Storage: Contests ContestsByCreator (r:0 w:1)
+	// Storage: Contests ContestsByAsset (r:0 w:1)
+	// Storage: Contests ContestsMap (r:0 w:1)
+	fn contest_new() -> Weight {
+		Weight::from_ref_time(98_412_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(10 as u64))
+	}
+	// Storage: Contests ContestsMap (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	fn update_contest() -> Weight {
+		Weight::from_ref_time(61_205_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: Contests ContestsMap (r:1 w:1)
+	// Storage: Contests AccountEntriesCount (r:1 w:1)
+	// Storage: Contests ContestEntriesCount (r:1 w:1)
+	// Storage: Contests NextEntryId (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: Contests EntriesMap (r:0 w:1)
+	// Storage: Contests ContestEntriesMap (r:0 w:1)
+	fn create_contest_entry() -> Weight {
+		Weight::from_ref_time(74_930_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(9 as u64))
+	}
+	// Storage: Contests EntriesMap (r:1 w:1)
+	// Storage: Contests ContestsMap (r:1 w:1)
+	// Storage: Assets Asset (r:5 w:5)
+	// Storage: Assets Account (r:10 w:10)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:1 w:1)
+	fn assign_contest_winner() -> Weight {
+		Weight::from_ref_time(214_667_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(21 as u64))
+			.saturating_add(T::DbWeight::get().writes(21 as u64))
+	}
+	// Storage: Contests ContestsMap (r:1 w:1)
+	// Storage: Contests ContestEntriesMap (r:1 w:0)
+	// Storage: Contests EntriesMap (r:500 w:0)
+	// Storage: Assets Asset (r:5 w:5)
+	// Storage: Assets Account (r:502 w:502)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:1 w:1)
+	// Storage: Contests Sponsorships (r:1 w:1)
+	/// The range of component `e` is `[0, 500]`.
+	fn close_contract(e: u32, ) -> Weight {
+		Weight::from_ref_time(172_384_000 as u64)
+			// Standard Error: 21_000
+			.saturating_add(Weight::from_ref_time(38_516_000 as u64).saturating_mul(e as u64))
+			.saturating_add(T::DbWeight::get().reads(13 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(e as u64)))
+			.saturating_add(T::DbWeight::get().writes(12 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(e as u64)))
+	}
+	// Storage: Contests ContestsMap (r:1 w:1)
+	// Storage: Contests EntriesMap (r:10 w:10)
+	// Storage: Contests ContestEntriesMap (r:10 w:0)
+	// Storage: Assets Asset (r:5 w:5)
+	// Storage: Assets Account (r:42 w:42)
+	// Storage: System Account (r:11 w:11)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:1 w:1)
+	// Storage: Contests Sponsorships (r:1 w:1)
+	fn finalize_contest() -> Weight {
+		Weight::from_ref_time(1_286_520_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(82 as u64))
+			.saturating_add(T::DbWeight::get().writes(72 as u64))
+	}
+	// Storage: Contests EntriesMap (r:1 w:0)
+	// Storage: Contests ContestsMap (r:1 w:0)
+	// Storage: Contests VotingOf (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Contests EntryVotes (r:1 w:1)
+	fn vote_entry() -> Weight {
+		Weight::from_ref_time(71_245_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: Contests ContestsMap (r:1 w:0)
+	// Storage: Contests VotingOf (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	fn unlock_vote() -> Weight {
+		Weight::from_ref_time(52_318_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// Storage: Contests ContestsMap (r:1 w:1)
+	// Storage: Contests Judgements (r:12 w:6)
+	// Storage: Contests ContestEntriesMap (r:10 w:0)
+	// Storage: Contests EntriesMap (r:10 w:10)
+	// Storage: Assets Asset (r:5 w:5)
+	// Storage: Assets Account (r:42 w:42)
+	// Storage: System Account (r:11 w:11)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:1 w:1)
+	// Storage: Contests Sponsorships (r:1 w:1)
+	fn submit_judgement() -> Weight {
+		Weight::from_ref_time(1_402_871_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(94 as u64))
+			.saturating_add(T::DbWeight::get().writes(78 as u64))
+	}
+	// Storage: Contests ContestsMap (r:1 w:1)
+	// Storage: Contests ContestEntriesMap (r:1 w:0)
+	// Storage: Contests EntryVotes (r:500 w:0)
+	// Storage: Contests EntriesMap (r:10 w:10)
+	// Storage: Assets Asset (r:5 w:5)
+	// Storage: Assets Account (r:42 w:42)
+	// Storage: System Account (r:11 w:11)
+	// Storage: Contests Sponsorships (r:1 w:1)
+	/// The range of component `e` is `[0, 500]`.
+	fn enact_contest_end(e: u32, ) -> Weight {
+		Weight::from_ref_time(1_127_309_000 as u64)
+			// Standard Error: 9_000
+			.saturating_add(Weight::from_ref_time(7_842_000 as u64).saturating_mul(e as u64))
+			.saturating_add(T::DbWeight::get().reads(71 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(e as u64)))
+			.saturating_add(T::DbWeight::get().writes(70 as u64))
+	}
+	// Storage: Contests ContestsMap (r:1 w:1)
+	// Storage: Contests ContestEntriesMap (r:1 w:0)
+	// Storage: Contests EntryVotes (r:500 w:0)
+	// Storage: Contests EntriesMap (r:10 w:10)
+	// Storage: Assets Asset (r:5 w:5)
+	// Storage: Assets Account (r:42 w:42)
+	// Storage: System Account (r:11 w:11)
+	// Storage: Contests Sponsorships (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:1 w:1)
+	/// The range of component `e` is `[0, 500]`.
+	fn close_ended_contest(e: u32, ) -> Weight {
+		Weight::from_ref_time(1_152_644_000 as u64)
+			// Standard Error: 9_000
+			.saturating_add(Weight::from_ref_time(7_842_000 as u64).saturating_mul(e as u64))
+			.saturating_add(T::DbWeight::get().reads(73 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(e as u64)))
+			.saturating_add(T::DbWeight::get().writes(72 as u64))
+	}
+	// Storage: Contests ContestsMap (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Contests Sponsorships (r:1 w:1)
+	fn sponsor_contest() -> Weight {
+		Weight::from_ref_time(58_104_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: Contests PendingPayouts (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:1 w:1)
+	// Storage: Contests ContestsMap (r:1 w:0)
+	// Storage: Assets Asset (r:5 w:5)
+	// Storage: Assets Account (r:10 w:10)
+	// Storage: System Account (r:2 w:2)
+	fn claim_payout() -> Weight {
+		Weight::from_ref_time(196_543_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(21 as u64))
+			.saturating_add(T::DbWeight::get().writes(20 as u64))
+	}
+	// Storage: Contests PendingPayouts (r:1 w:1)
+	// Storage: Contests ContestsMap (r:1 w:0)
+	// Storage: Assets Asset (r:5 w:5)
+	// Storage: Assets Account (r:10 w:10)
+	// Storage: System Account (r:2 w:2)
+	fn enact_payout_release() -> Weight {
+		Weight::from_ref_time(171_032_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(19 as u64))
+			.saturating_add(T::DbWeight::get().writes(18 as u64))
+	}
+	// Storage: Contests PendingPayouts (r:1 w:1)
+	// Storage: Contests AccountEntriesCount (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:1 w:1)
+	fn dispute_payout() -> Weight {
+		Weight::from_ref_time(63_718_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: Contests PendingPayouts (r:1 w:1)
+	// Storage: Contests EntriesMap (r:2 w:2)
+	// Storage: Contests ContestsMap (r:2 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Assets Asset (r:5 w:5)
+	// Storage: Assets Account (r:10 w:10)
+	fn resolve_dispute() -> Weight {
+		Weight::from_ref_time(204_287_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(23 as u64))
+			.saturating_add(T::DbWeight::get().writes(22 as u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: Contests NextContestId (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:1 w:1)
+	// Storage: Contests ContestsByCreator (r:0 w:1)
+	// Storage: Contests ContestsByAsset (r:0 w:1)
+	// Storage: Contests ContestsMap (r:0 w:1)
+	fn contest_new() -> Weight {
+		Weight::from_ref_time(98_412_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(10 as u64))
+	}
+	// Storage: Contests ContestsMap (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	fn update_contest() -> Weight {
+		Weight::from_ref_time(61_205_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: Contests ContestsMap (r:1 w:1)
+	// Storage: Contests AccountEntriesCount (r:1 w:1)
+	// Storage: Contests ContestEntriesCount (r:1 w:1)
+	// Storage: Contests NextEntryId (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: Contests EntriesMap (r:0 w:1)
+	// Storage: Contests ContestEntriesMap (r:0 w:1)
+	fn create_contest_entry() -> Weight {
+		Weight::from_ref_time(74_930_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(9 as u64))
+	}
+	// Storage: Contests EntriesMap (r:1 w:1)
+	// Storage: Contests ContestsMap (r:1 w:1)
+	// Storage: Assets Asset (r:5 w:5)
+	// Storage: Assets Account (r:10 w:10)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:1 w:1)
+	fn assign_contest_winner() -> Weight {
+		Weight::from_ref_time(214_667_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(21 as u64))
+			.saturating_add(RocksDbWeight::get().writes(21 as u64))
+	}
+	// Storage: Contests ContestsMap (r:1 w:1)
+	// Storage: Contests ContestEntriesMap (r:1 w:0)
+	// Storage: Contests EntriesMap (r:500 w:0)
+	// Storage: Assets Asset (r:5 w:5)
+	// Storage: Assets Account (r:502 w:502)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:1 w:1)
+	// Storage: Contests Sponsorships (r:1 w:1)
+	/// The range of component `e` is `[0, 500]`.
+	fn close_contract(e: u32, ) -> Weight {
+		Weight::from_ref_time(172_384_000 as u64)
+			// Standard Error: 21_000
+			.saturating_add(Weight::from_ref_time(38_516_000 as u64).saturating_mul(e as u64))
+			.saturating_add(RocksDbWeight::get().reads(13 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(e as u64)))
+			.saturating_add(RocksDbWeight::get().writes(12 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(e as u64)))
+	}
+	// Storage: Contests ContestsMap (r:1 w:1)
+	// Storage: Contests EntriesMap (r:10 w:10)
+	// Storage: Contests ContestEntriesMap (r:10 w:0)
+	// Storage: Assets Asset (r:5 w:5)
+	// Storage: Assets Account (r:42 w:42)
+	// Storage: System Account (r:11 w:11)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:1 w:1)
+	// Storage: Contests Sponsorships (r:1 w:1)
+	fn finalize_contest() -> Weight {
+		Weight::from_ref_time(1_286_520_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(82 as u64))
+			.saturating_add(RocksDbWeight::get().writes(72 as u64))
+	}
+	// Storage: Contests EntriesMap (r:1 w:0)
+	// Storage: Contests ContestsMap (r:1 w:0)
+	// Storage: Contests VotingOf (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Contests EntryVotes (r:1 w:1)
+	fn vote_entry() -> Weight {
+		Weight::from_ref_time(71_245_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	// Storage: Contests ContestsMap (r:1 w:0)
+	// Storage: Contests VotingOf (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	fn unlock_vote() -> Weight {
+		Weight::from_ref_time(52_318_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	// Storage: Contests ContestsMap (r:1 w:1)
+	// Storage: Contests Judgements (r:12 w:6)
+	// Storage: Contests ContestEntriesMap (r:10 w:0)
+	// Storage: Contests EntriesMap (r:10 w:10)
+	// Storage: Assets Asset (r:5 w:5)
+	// Storage: Assets Account (r:42 w:42)
+	// Storage: System Account (r:11 w:11)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:1 w:1)
+	// Storage: Contests Sponsorships (r:1 w:1)
+	fn submit_judgement() -> Weight {
+		Weight::from_ref_time(1_402_871_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(94 as u64))
+			.saturating_add(RocksDbWeight::get().writes(78 as u64))
+	}
+	// Storage: Contests ContestsMap (r:1 w:1)
+	// Storage: Contests ContestEntriesMap (r:1 w:0)
+	// Storage: Contests EntryVotes (r:500 w:0)
+	// Storage: Contests EntriesMap (r:10 w:10)
+	// Storage: Assets Asset (r:5 w:5)
+	// Storage: Assets Account (r:42 w:42)
+	// Storage: System Account (r:11 w:11)
+	// Storage: Contests Sponsorships (r:1 w:1)
+	/// The range of component `e` is `[0, 500]`.
+	fn enact_contest_end(e: u32, ) -> Weight {
+		Weight::from_ref_time(1_127_309_000 as u64)
+			// Standard Error: 9_000
+			.saturating_add(Weight::from_ref_time(7_842_000 as u64).saturating_mul(e as u64))
+			.saturating_add(RocksDbWeight::get().reads(71 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(e as u64)))
+			.saturating_add(RocksDbWeight::get().writes(70 as u64))
+	}
+	// Storage: Contests ContestsMap (r:1 w:1)
+	// Storage: Contests ContestEntriesMap (r:1 w:0)
+	// Storage: Contests EntryVotes (r:500 w:0)
+	// Storage: Contests EntriesMap (r:10 w:10)
+	// Storage: Assets Asset (r:5 w:5)
+	// Storage: Assets Account (r:42 w:42)
+	// Storage: System Account (r:11 w:11)
+	// Storage: Contests Sponsorships (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:1 w:1)
+	/// The range of component `e` is `[0, 500]`.
+	fn close_ended_contest(e: u32, ) -> Weight {
+		Weight::from_ref_time(1_152_644_000 as u64)
+			// Standard Error: 9_000
+			.saturating_add(Weight::from_ref_time(7_842_000 as u64).saturating_mul(e as u64))
+			.saturating_add(RocksDbWeight::get().reads(73 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(e as u64)))
+			.saturating_add(RocksDbWeight::get().writes(72 as u64))
+	}
+	// Storage: Contests ContestsMap (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Contests Sponsorships (r:1 w:1)
+	fn sponsor_contest() -> Weight {
+		Weight::from_ref_time(58_104_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: Contests PendingPayouts (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:1 w:1)
+	// Storage: Contests ContestsMap (r:1 w:0)
+	// Storage: Assets Asset (r:5 w:5)
+	// Storage: Assets Account (r:10 w:10)
+	// Storage: System Account (r:2 w:2)
+	fn claim_payout() -> Weight {
+		Weight::from_ref_time(196_543_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(21 as u64))
+			.saturating_add(RocksDbWeight::get().writes(20 as u64))
+	}
+	// Storage: Contests PendingPayouts (r:1 w:1)
+	// Storage: Contests ContestsMap (r:1 w:0)
+	// Storage: Assets Asset (r:5 w:5)
+	// Storage: Assets Account (r:10 w:10)
+	// Storage: System Account (r:2 w:2)
+	fn enact_payout_release() -> Weight {
+		Weight::from_ref_time(171_032_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(19 as u64))
+			.saturating_add(RocksDbWeight::get().writes(18 as u64))
+	}
+	// Storage: Contests PendingPayouts (r:1 w:1)
+	// Storage: Contests AccountEntriesCount (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:1 w:1)
+	fn dispute_payout() -> Weight {
+		Weight::from_ref_time(63_718_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: Contests PendingPayouts (r:1 w:1)
+	// Storage: Contests EntriesMap (r:2 w:2)
+	// Storage: Contests ContestsMap (r:2 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Assets Asset (r:5 w:5)
+	// Storage: Assets Account (r:10 w:10)
+	fn resolve_dispute() -> Weight {
+		Weight::from_ref_time(204_287_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(23 as u64))
+			.saturating_add(RocksDbWeight::get().writes(22 as u64))
+	}
+}
@@ -0,0 +1,24 @@
+//! Runtime API definition for the dex pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_dex::SwapAsset;
+
+sp_api::decl_runtime_apis! {
+	pub trait DexApi<AssetId, AssetBalance> where
+		AssetId: Codec,
+		AssetBalance: Codec,
+	{
+		/// The path to swap `asset_in` for `asset_out`, through the native currency between assets.
+		fn swap_path(asset_in: SwapAsset<AssetId>, asset_out: SwapAsset<AssetId>) -> Vec<SwapAsset<AssetId>>;
+
+		/// Amount bought of the last currency of `path` by selling `amount_in` of the first one.
+		fn quote_exact_in(path: Vec<SwapAsset<AssetId>>, amount_in: AssetBalance) -> Option<AssetBalance>;
+
+		/// Amount sold of the first currency of `path` to buy `amount_out` of the last one.
+		fn quote_exact_out(path: Vec<SwapAsset<AssetId>>, amount_out: AssetBalance) -> Option<AssetBalance>;
+	}
+}
@@ -0,0 +1,194 @@
+//! Benchmarking for `pallet-dex`.
+
+// Only enable this module for benchmarking.
+#![cfg(feature = "runtime-benchmarks")]
+
+use crate::*;
+use frame_benchmarking::{account, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::benchmarking::benchmarks;
+use frame_system::RawOrigin;
+
+const ASSET: u32 = 1;
+const LIQUIDITY_TOKEN: u32 = 100;
+const OTHER_ASSET: u32 = 2;
+const OTHER_LIQUIDITY_TOKEN: u32 = 101;
+
+/// The asset with id `id`, the asset ids of the runtime decode from a `u32`.
+fn asset_id<T: Config>(id: u32) -> AssetIdOf<T> {
+	AssetIdOf::<T>::decode(&mut &id.encode()[..]).expect("asset ids decode from a u32")
+}
+
+/// Give native currency and `asset` to `who`, creating the asset when it does not exist.
+fn fund<T: Config>(who: &T::AccountId, asset: u32) {
+	T::Currency::make_free_balance_be(who, BalanceOf::<T>::saturated_from(1_000_000_000_000u128));
+	if T::Assets::total_issuance(asset_id::<T>(asset)).is_zero() {
+		T::AssetRegistry::create(asset_id::<T>(asset), who.clone(), true, One::one()).unwrap();
+	}
+	T::AssetRegistry::mint_into(asset_id::<T>(asset), who, AssetBalanceOf::<T>::saturated_from(1_000_000_000_000u128))
+		.unwrap();
+}
+
+/// Create the exchange of `asset` with liquidity of `provider`.
+fn create_exchange<T: Config>(provider: &T::AccountId, asset: u32, liquidity_token: u32) {
+	fund::<T>(provider, asset);
+	Pallet::<T>::create_exchange(
+		RawOrigin::Signed(provider.clone()).into(),
+		asset_id::<T>(asset),
+		asset_id::<T>(liquidity_token),
+		BalanceOf::<T>::saturated_from(1_000_000_000u128),
+		AssetBalanceOf::<T>::saturated_from(2_000_000_000u128),
+	)
+	.unwrap();
+}
+
+fn deadline<T: Config>() -> T::BlockNumber {
+	<frame_system::Pallet<T>>::block_number() + 10u32.into()
+}
+
+/// The path from `ASSET` to `OTHER_ASSET` through both of their exchanges.
+fn asset_to_asset_path<T: Config>() -> SwapPathOf<T> {
+	Pallet::<T>::swap_path(SwapAsset::Asset(asset_id::<T>(ASSET)), SwapAsset::Asset(asset_id::<T>(OTHER_ASSET)))
+		.try_into()
+		.unwrap()
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn create_exchange() {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller, ASSET);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			asset_id::<T>(ASSET),
+			asset_id::<T>(LIQUIDITY_TOKEN),
+			BalanceOf::<T>::saturated_from(1_000_000_000u128),
+			AssetBalanceOf::<T>::saturated_from(2_000_000_000u128),
+		);
+
+		assert!(Exchanges::<T>::contains_key(asset_id::<T>(ASSET)));
+	}
+
+	#[benchmark]
+	fn add_liquidity() {
+		create_exchange::<T>(&account("provider", 0, 0), ASSET, LIQUIDITY_TOKEN);
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller, ASSET);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			asset_id::<T>(ASSET),
+			BalanceOf::<T>::saturated_from(1_000_000u128),
+			Zero::zero(),
+			AssetBalanceOf::<T>::saturated_from(1_000_000_000u128),
+			deadline::<T>(),
+		);
+
+		assert!(!T::AssetRegistry::balance(asset_id::<T>(LIQUIDITY_TOKEN), &caller).is_zero());
+	}
+
+	#[benchmark]
+	fn remove_liquidity() {
+		let caller: T::AccountId = whitelisted_caller();
+		create_exchange::<T>(&caller, ASSET, LIQUIDITY_TOKEN);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			asset_id::<T>(ASSET),
+			AssetBalanceOf::<T>::saturated_from(1_000_000u128),
+			Zero::zero(),
+			Zero::zero(),
+			deadline::<T>(),
+		);
+
+		assert_eq!(
+			T::AssetRegistry::balance(asset_id::<T>(LIQUIDITY_TOKEN), &caller),
+			AssetBalanceOf::<T>::saturated_from(999_000_000u128)
+		);
+	}
+
+	#[benchmark]
+	fn currency_to_asset() {
+		create_exchange::<T>(&account("provider", 0, 0), ASSET, LIQUIDITY_TOKEN);
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller, ASSET);
+		let amount = TradeAmount::FixedInput {
+			input_amount: BalanceOf::<T>::saturated_from(1_000_000u128),
+			min_output: One::one(),
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), asset_id::<T>(ASSET), amount, deadline::<T>());
+
+		let exchange = Exchanges::<T>::get(asset_id::<T>(ASSET)).unwrap();
+		assert_eq!(exchange.currency_reserve, BalanceOf::<T>::saturated_from(1_001_000_000u128));
+	}
+
+	#[benchmark]
+	fn asset_to_currency() {
+		create_exchange::<T>(&account("provider", 0, 0), ASSET, LIQUIDITY_TOKEN);
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller, ASSET);
+		let amount = TradeAmount::FixedInput {
+			input_amount: AssetBalanceOf::<T>::saturated_from(1_000_000u128),
+			min_output: One::one(),
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), asset_id::<T>(ASSET), amount, deadline::<T>());
+
+		let exchange = Exchanges::<T>::get(asset_id::<T>(ASSET)).unwrap();
+		assert_eq!(exchange.token_reserve, AssetBalanceOf::<T>::saturated_from(2_001_000_000u128));
+	}
+
+	#[benchmark]
+	fn swap_exact_in() {
+		let provider: T::AccountId = account("provider", 0, 0);
+		create_exchange::<T>(&provider, ASSET, LIQUIDITY_TOKEN);
+		create_exchange::<T>(&provider, OTHER_ASSET, OTHER_LIQUIDITY_TOKEN);
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller, ASSET);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			asset_to_asset_path::<T>(),
+			AssetBalanceOf::<T>::saturated_from(1_000_000u128),
+			One::one(),
+			deadline::<T>(),
+		);
+
+		assert!(!T::Assets::balance(asset_id::<T>(OTHER_ASSET), &caller).is_zero());
+	}
+
+	#[benchmark]
+	fn swap_exact_out() {
+		let provider: T::AccountId = account("provider", 0, 0);
+		create_exchange::<T>(&provider, ASSET, LIQUIDITY_TOKEN);
+		create_exchange::<T>(&provider, OTHER_ASSET, OTHER_LIQUIDITY_TOKEN);
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller, ASSET);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			asset_to_asset_path::<T>(),
+			AssetBalanceOf::<T>::saturated_from(1_000_000u128),
+			AssetBalanceOf::<T>::saturated_from(1_000_000_000u128),
+			deadline::<T>(),
+		);
+
+		assert_eq!(
+			T::Assets::balance(asset_id::<T>(OTHER_ASSET), &caller),
+			AssetBalanceOf::<T>::saturated_from(1_000_000u128)
+		);
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,889 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Constant-product exchanges between the native currency and assets.
+/// Learn more about FRAME and the core library of Substrate FRAME pallets:
+/// <https://docs.substrate.io/reference/frame-pallets/>
+pub use pallet::*;
+
+use frame_support::pallet_prelude::*;
+
+use frame_support::{
+	traits::{
+		tokens::fungibles::{Create, Inspect, Mutate, Transfer},
+		Currency, ExistenceRequirement,
+	},
+	PalletId,
+};
+
+use sp_core::U256;
+
+use sp_std::{vec, vec::Vec};
+
+use sp_runtime::{
+	traits::{AccountIdConversion, AtLeast32BitUnsigned, Convert, One, Saturating, Zero},
+	DispatchError, SaturatedConversion,
+};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// Balance type alias.
+pub(crate) type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+/// Asset id type alias.
+pub(crate) type AssetIdOf<T> =
+	<<T as Config>::Assets as Inspect<<T as frame_system::Config>::AccountId>>::AssetId;
+/// Asset balance type alias.
+pub(crate) type AssetBalanceOf<T> = <T as Config>::AssetBalance;
+/// Exchange type alias.
+pub(crate) type ExchangeOf<T> = Exchange<AssetIdOf<T>, BalanceOf<T>, AssetBalanceOf<T>>;
+/// Swap path type alias.
+pub(crate) type SwapPathOf<T> = BoundedVec<SwapAsset<AssetIdOf<T>>, ConstU32<MAX_SWAP_PATH>>;
+
+/// Max number of currencies in a swap path. Every exchange pairs an asset with the native
+/// currency and is swapped through once, so a path goes through at most two exchanges.
+pub const MAX_SWAP_PATH: u32 = 3;
+
+/// A pool of the native currency and an asset, priced by the product of its reserves.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Exchange<AssetId, Balance, AssetBalance> {
+	/// The asset traded against the native currency.
+	pub asset_id: AssetId,
+	/// Native currency held by the exchange.
+	pub currency_reserve: Balance,
+	/// Asset held by the exchange.
+	pub token_reserve: AssetBalance,
+	/// The asset minted to liquidity providers for their share of the reserves.
+	pub liquidity_token_id: AssetId,
+}
+
+/// The amount of a trade, fixed on the side sold or on the side bought.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum TradeAmount<InputBalance, OutputBalance> {
+	/// Sell exactly `input_amount`, for at least `min_output`.
+	FixedInput { input_amount: InputBalance, min_output: OutputBalance },
+	/// Buy exactly `output_amount`, for at most `max_input`.
+	FixedOutput { max_input: InputBalance, output_amount: OutputBalance },
+}
+
+/// A currency of a swap path.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum SwapAsset<AssetId> {
+	/// Native Balances currency of the network.
+	Native,
+	/// AssetId from the Assets Pallet.
+	Asset(AssetId),
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The dex pallet id, its account holds the reserves of every exchange.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Native currency traded in every exchange.
+		type Currency: Currency<Self::AccountId>;
+
+		/// Balance of the assets traded and of the liquidity tokens.
+		type AssetBalance: Member
+			+ Parameter
+			+ AtLeast32BitUnsigned
+			+ Default
+			+ Copy
+			+ MaybeSerializeDeserialize
+			+ MaxEncodedLen
+			+ TypeInfo;
+
+		/// Converts asset balances to native balances.
+		type AssetToCurrencyBalance: Convert<Self::AssetBalance, BalanceOf<Self>>;
+
+		/// Converts native balances to asset balances, liquidity tokens are first minted one for
+		/// one with the currency deposited.
+		type CurrencyToAssetBalance: Convert<BalanceOf<Self>, Self::AssetBalance>;
+
+		/// The assets traded against the native currency.
+		type Assets: Inspect<Self::AccountId, Balance = Self::AssetBalance> + Transfer<Self::AccountId>;
+
+		/// Creates the liquidity tokens of the exchanges, and mints and burns them.
+		type AssetRegistry: Inspect<Self::AccountId, AssetId = AssetIdOf<Self>, Balance = Self::AssetBalance>
+			+ Mutate<Self::AccountId>
+			+ Create<Self::AccountId>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Numerator of the share of each trade kept by the exchange for its liquidity providers.
+		#[pallet::constant]
+		type ProviderFeeNumerator: Get<BalanceOf<Self>>;
+
+		/// Denominator of the share of each trade kept by the exchange for its liquidity providers.
+		#[pallet::constant]
+		type ProviderFeeDenominator: Get<BalanceOf<Self>>;
+
+		/// Min native currency deposited when adding liquidity to an exchange.
+		#[pallet::constant]
+		type MinDeposit: Get<BalanceOf<Self>>;
+	}
+
+	/// The exchange of the native currency and each asset.
+	#[pallet::storage]
+	#[pallet::getter(fn exchanges)]
+	pub type Exchanges<T: Config> = StorageMap<_, Blake2_128Concat, AssetIdOf<T>, ExchangeOf<T>>;
+
+	// Pallets use events to inform users when important changes are made.
+	// https://docs.substrate.io/main-docs/build/events-errors/
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An exchange of the native currency and an asset was created.
+		ExchangeCreated { asset_id: AssetIdOf<T>, liquidity_token_id: AssetIdOf<T> },
+		/// Liquidity was added to an exchange, for liquidity tokens.
+		LiquidityAdded {
+			provider: T::AccountId,
+			asset_id: AssetIdOf<T>,
+			currency_amount: BalanceOf<T>,
+			token_amount: AssetBalanceOf<T>,
+			liquidity_minted: AssetBalanceOf<T>,
+		},
+		/// Liquidity tokens were burned to withdraw their share of an exchange.
+		LiquidityRemoved {
+			provider: T::AccountId,
+			asset_id: AssetIdOf<T>,
+			currency_amount: BalanceOf<T>,
+			token_amount: AssetBalanceOf<T>,
+			liquidity_burned: AssetBalanceOf<T>,
+		},
+		/// Native currency was sold for an asset.
+		CurrencyTradedForAsset {
+			asset_id: AssetIdOf<T>,
+			buyer: T::AccountId,
+			currency_amount: BalanceOf<T>,
+			token_amount: AssetBalanceOf<T>,
+		},
+		/// An asset was sold for native currency.
+		AssetTradedForCurrency {
+			asset_id: AssetIdOf<T>,
+			buyer: T::AccountId,
+			token_amount: AssetBalanceOf<T>,
+			currency_amount: BalanceOf<T>,
+		},
+		/// A currency was swapped for another along a path of exchanges.
+		Swapped {
+			who: T::AccountId,
+			path: SwapPathOf<T>,
+			amount_in: AssetBalanceOf<T>,
+			amount_out: AssetBalanceOf<T>,
+		},
+	}
+
+	// Errors inform users that something went wrong.
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The asset does not exist.
+		AssetNotFound,
+		/// There is no exchange for this asset.
+		ExchangeNotFound,
+		/// An asset can only have one exchange.
+		ExchangeAlreadyExists,
+		/// The liquidity token should be a new asset, other than the asset traded.
+		InvalidLiquidityToken,
+		/// Native currency deposited should be at least `MinDeposit`.
+		DepositTooLow,
+		/// The asset deposited can't be zero.
+		TokenAmountIsZero,
+		/// The liquidity added or removed can't be zero.
+		LiquidityAmountIsZero,
+		/// Adding liquidity needs more asset than the max given.
+		MaxTokensExceeded,
+		/// Less liquidity tokens would be minted than the min given.
+		MinLiquidityNotMet,
+		/// Less native currency would be withdrawn than the min given.
+		MinCurrencyNotMet,
+		/// Less asset would be withdrawn than the min given.
+		MinTokensNotMet,
+		/// A trade can't sell or buy nothing.
+		TradeAmountIsZero,
+		/// Less would be bought than the min given.
+		MinOutputNotMet,
+		/// More would be sold than the max given.
+		MaxInputExceeded,
+		/// The exchange does not hold enough to buy this amount.
+		NotEnoughLiquidity,
+		/// The deadline of the call has passed.
+		DeadlinePassed,
+		/// An amount does not fit its balance type.
+		Overflow,
+		/// A swap path trades each currency for the next one through the exchange of an asset,
+		/// with each exchange once.
+		InvalidPath,
+	}
+
+	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
+	// These functions materialize as "extrinsics", which are often compared to transactions.
+	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create the exchange of an asset, with its first liquidity.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// The liquidity token is created as a new asset, and liquidity tokens are minted one for
+		/// one with the currency deposited.
+		///
+		/// - `asset_id`: The asset traded against the native currency.
+		/// - `liquidity_token_id`: Id of the new asset minted to liquidity providers.
+		/// - `currency_amount`: Native currency deposited, at least `MinDeposit`.
+		/// - `token_amount`: Asset deposited, which sets the first price of the exchange.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::create_exchange())]
+		pub fn create_exchange(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			liquidity_token_id: AssetIdOf<T>,
+			currency_amount: BalanceOf<T>,
+			token_amount: AssetBalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// Call inner function.
+			let liquidity_minted =
+				Self::try_create_exchange(&who, asset_id, liquidity_token_id, currency_amount, token_amount)?;
+			// Emit an event.
+			Self::deposit_event(Event::ExchangeCreated { asset_id, liquidity_token_id });
+			Self::deposit_event(Event::LiquidityAdded {
+				provider: who,
+				asset_id,
+				currency_amount,
+				token_amount,
+				liquidity_minted,
+			});
+			Ok(())
+		}
+
+		/// Add liquidity to the exchange of an asset, at its current price.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// - `asset_id`: The asset of the exchange.
+		/// - `currency_amount`: Native currency deposited, at least `MinDeposit`.
+		/// - `min_liquidity`: Min liquidity tokens minted.
+		/// - `max_tokens`: Max asset deposited with the currency.
+		/// - `deadline`: Last block the liquidity can be added in.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::add_liquidity())]
+		pub fn add_liquidity(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			currency_amount: BalanceOf<T>,
+			min_liquidity: AssetBalanceOf<T>,
+			max_tokens: AssetBalanceOf<T>,
+			deadline: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// Call inner function.
+			let (token_amount, liquidity_minted) =
+				Self::try_add_liquidity(&who, asset_id, currency_amount, min_liquidity, max_tokens, deadline)?;
+			// Emit an event.
+			Self::deposit_event(Event::LiquidityAdded {
+				provider: who,
+				asset_id,
+				currency_amount,
+				token_amount,
+				liquidity_minted,
+			});
+			Ok(())
+		}
+
+		/// Burn liquidity tokens to withdraw their share of the reserves of an exchange.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// - `asset_id`: The asset of the exchange.
+		/// - `liquidity_amount`: Liquidity tokens burned.
+		/// - `min_currency`: Min native currency withdrawn.
+		/// - `min_tokens`: Min asset withdrawn.
+		/// - `deadline`: Last block the liquidity can be removed in.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::remove_liquidity())]
+		pub fn remove_liquidity(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			liquidity_amount: AssetBalanceOf<T>,
+			min_currency: BalanceOf<T>,
+			min_tokens: AssetBalanceOf<T>,
+			deadline: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// Call inner function.
+			let (currency_amount, token_amount) =
+				Self::try_remove_liquidity(&who, asset_id, liquidity_amount, min_currency, min_tokens, deadline)?;
+			// Emit an event.
+			Self::deposit_event(Event::LiquidityRemoved {
+				provider: who,
+				asset_id,
+				currency_amount,
+				token_amount,
+				liquidity_burned: liquidity_amount,
+			});
+			Ok(())
+		}
+
+		/// Sell native currency for an asset.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// - `asset_id`: The asset bought.
+		/// - `amount`: Currency sold and asset bought, either side fixed with a limit on the other.
+		/// - `deadline`: Last block the trade can happen in.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::currency_to_asset())]
+		pub fn currency_to_asset(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			amount: TradeAmount<BalanceOf<T>, AssetBalanceOf<T>>,
+			deadline: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// Call inner function.
+			let (currency_amount, token_amount) = Self::try_currency_to_asset(&who, asset_id, amount, deadline)?;
+			// Emit an event.
+			Self::deposit_event(Event::CurrencyTradedForAsset { asset_id, buyer: who, currency_amount, token_amount });
+			Ok(())
+		}
+
+		/// Sell an asset for native currency.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// - `asset_id`: The asset sold.
+		/// - `amount`: Asset sold and currency bought, either side fixed with a limit on the other.
+		/// - `deadline`: Last block the trade can happen in.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::asset_to_currency())]
+		pub fn asset_to_currency(
+			origin: OriginFor<T>,
+			asset_id: AssetIdOf<T>,
+			amount: TradeAmount<AssetBalanceOf<T>, BalanceOf<T>>,
+			deadline: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// Call inner function.
+			let (token_amount, currency_amount) = Self::try_asset_to_currency(&who, asset_id, amount, deadline)?;
+			// Emit an event.
+			Self::deposit_event(Event::AssetTradedForCurrency { asset_id, buyer: who, token_amount, currency_amount });
+			Ok(())
+		}
+
+		/// Sell an exact amount of a currency for another, along a path of exchanges.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// Amounts are given as asset balances, native amounts are converted with
+		/// `AssetToCurrencyBalance` and `CurrencyToAssetBalance`.
+		///
+		/// - `path`: The currencies swapped through, from the currency sold to the one bought.
+		/// - `amount_in`: Amount sold of the first currency.
+		/// - `min_amount_out`: Min amount bought of the last currency.
+		/// - `deadline`: Last block the swap can happen in.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::swap_exact_in())]
+		pub fn swap_exact_in(
+			origin: OriginFor<T>,
+			path: SwapPathOf<T>,
+			amount_in: AssetBalanceOf<T>,
+			min_amount_out: AssetBalanceOf<T>,
+			deadline: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// Call inner function.
+			let amount_out = Self::try_swap_exact_in(&who, &path, amount_in, min_amount_out, deadline)?;
+			// Emit an event.
+			Self::deposit_event(Event::Swapped { who, path, amount_in, amount_out });
+			Ok(())
+		}
+
+		/// Buy an exact amount of a currency with another, along a path of exchanges.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// Amounts are given as asset balances, native amounts are converted with
+		/// `AssetToCurrencyBalance` and `CurrencyToAssetBalance`.
+		///
+		/// - `path`: The currencies swapped through, from the currency sold to the one bought.
+		/// - `amount_out`: Amount bought of the last currency.
+		/// - `max_amount_in`: Max amount sold of the first currency.
+		/// - `deadline`: Last block the swap can happen in.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::swap_exact_out())]
+		pub fn swap_exact_out(
+			origin: OriginFor<T>,
+			path: SwapPathOf<T>,
+			amount_out: AssetBalanceOf<T>,
+			max_amount_in: AssetBalanceOf<T>,
+			deadline: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// Call inner function.
+			let amount_in = Self::try_swap_exact_out(&who, &path, amount_out, max_amount_in, deadline)?;
+			// Emit an event.
+			Self::deposit_event(Event::Swapped { who, path, amount_in, amount_out });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The account of the pallet, holds the reserves of every exchange.
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// Create the exchange of an asset and deposit its first liquidity.
+	fn try_create_exchange(
+		who: &T::AccountId,
+		asset_id: AssetIdOf<T>,
+		liquidity_token_id: AssetIdOf<T>,
+		currency_amount: BalanceOf<T>,
+		token_amount: AssetBalanceOf<T>,
+	) -> Result<AssetBalanceOf<T>, DispatchError> {
+		ensure!(currency_amount >= T::MinDeposit::get(), Error::<T>::DepositTooLow);
+		ensure!(!token_amount.is_zero(), Error::<T>::TokenAmountIsZero);
+		ensure!(!Exchanges::<T>::contains_key(asset_id), Error::<T>::ExchangeAlreadyExists);
+		ensure!(!T::Assets::total_issuance(asset_id).is_zero(), Error::<T>::AssetNotFound);
+		ensure!(asset_id != liquidity_token_id, Error::<T>::InvalidLiquidityToken);
+
+		// The pallet account administers the liquidity token.
+		T::AssetRegistry::create(liquidity_token_id, Self::account_id(), false, One::one())
+			.map_err(|_| Error::<T>::InvalidLiquidityToken)?;
+
+		let exchange = Exchange {
+			asset_id,
+			currency_reserve: Zero::zero(),
+			token_reserve: Zero::zero(),
+			liquidity_token_id,
+		};
+		let liquidity_minted = T::CurrencyToAssetBalance::convert(currency_amount);
+		Self::deposit_liquidity(who, exchange, currency_amount, token_amount, liquidity_minted)?;
+
+		Ok(liquidity_minted)
+	}
+
+	/// Add liquidity to an exchange in the ratio of its reserves.
+	fn try_add_liquidity(
+		who: &T::AccountId,
+		asset_id: AssetIdOf<T>,
+		currency_amount: BalanceOf<T>,
+		min_liquidity: AssetBalanceOf<T>,
+		max_tokens: AssetBalanceOf<T>,
+		deadline: T::BlockNumber,
+	) -> Result<(AssetBalanceOf<T>, AssetBalanceOf<T>), DispatchError> {
+		Self::ensure_deadline(deadline)?;
+		ensure!(currency_amount >= T::MinDeposit::get(), Error::<T>::DepositTooLow);
+		let exchange = Exchanges::<T>::get(asset_id).ok_or(Error::<T>::ExchangeNotFound)?;
+
+		let total_liquidity = T::AssetRegistry::total_issuance(exchange.liquidity_token_id);
+		let (token_amount, liquidity_minted) = match total_liquidity.is_zero() {
+			// Every provider withdrew, the deposit sets the price again.
+			true => (max_tokens, T::CurrencyToAssetBalance::convert(currency_amount)),
+			false => {
+				let currency_amount = currency_amount.saturated_into::<u128>();
+				let currency_reserve = exchange.currency_reserve.saturated_into::<u128>();
+				let token_amount = Self::mul_div(currency_amount, exchange.token_reserve.saturated_into(), currency_reserve)?
+					.saturating_add(1);
+				let liquidity_minted = Self::mul_div(currency_amount, total_liquidity.saturated_into(), currency_reserve)?;
+				(token_amount.saturated_into(), liquidity_minted.saturated_into())
+			},
+		};
+
+		ensure!(!token_amount.is_zero(), Error::<T>::TokenAmountIsZero);
+		ensure!(token_amount <= max_tokens, Error::<T>::MaxTokensExceeded);
+		ensure!(!liquidity_minted.is_zero(), Error::<T>::LiquidityAmountIsZero);
+		ensure!(liquidity_minted >= min_liquidity, Error::<T>::MinLiquidityNotMet);
+
+		Self::deposit_liquidity(who, exchange, currency_amount, token_amount, liquidity_minted)?;
+		Ok((token_amount, liquidity_minted))
+	}
+
+	/// Move the liquidity deposited to the pallet account and mint its liquidity tokens.
+	fn deposit_liquidity(
+		who: &T::AccountId,
+		mut exchange: ExchangeOf<T>,
+		currency_amount: BalanceOf<T>,
+		token_amount: AssetBalanceOf<T>,
+		liquidity_minted: AssetBalanceOf<T>,
+	) -> DispatchResult {
+		let pallet_account = Self::account_id();
+		T::Currency::transfer(who, &pallet_account, currency_amount, ExistenceRequirement::KeepAlive)?;
+		T::Assets::transfer(exchange.asset_id, who, &pallet_account, token_amount, false)?;
+		T::AssetRegistry::mint_into(exchange.liquidity_token_id, who, liquidity_minted)?;
+
+		exchange.currency_reserve = exchange.currency_reserve.saturating_add(currency_amount);
+		exchange.token_reserve = exchange.token_reserve.saturating_add(token_amount);
+		Exchanges::<T>::insert(exchange.asset_id, exchange);
+		Ok(())
+	}
+
+	/// Burn liquidity tokens and withdraw their share of the reserves of an exchange.
+	fn try_remove_liquidity(
+		who: &T::AccountId,
+		asset_id: AssetIdOf<T>,
+		liquidity_amount: AssetBalanceOf<T>,
+		min_currency: BalanceOf<T>,
+		min_tokens: AssetBalanceOf<T>,
+		deadline: T::BlockNumber,
+	) -> Result<(BalanceOf<T>, AssetBalanceOf<T>), DispatchError> {
+		Self::ensure_deadline(deadline)?;
+		ensure!(!liquidity_amount.is_zero(), Error::<T>::LiquidityAmountIsZero);
+		let mut exchange = Exchanges::<T>::get(asset_id).ok_or(Error::<T>::ExchangeNotFound)?;
+
+		let total_liquidity = T::AssetRegistry::total_issuance(exchange.liquidity_token_id);
+		ensure!(liquidity_amount <= total_liquidity, Error::<T>::NotEnoughLiquidity);
+		let liquidity = liquidity_amount.saturated_into::<u128>();
+		let total_liquidity = total_liquidity.saturated_into::<u128>();
+		let currency_amount: BalanceOf<T> =
+			Self::mul_div(liquidity, exchange.currency_reserve.saturated_into(), total_liquidity)?.saturated_into();
+		let token_amount: AssetBalanceOf<T> =
+			Self::mul_div(liquidity, exchange.token_reserve.saturated_into(), total_liquidity)?.saturated_into();
+
+		ensure!(currency_amount >= min_currency, Error::<T>::MinCurrencyNotMet);
+		ensure!(token_amount >= min_tokens, Error::<T>::MinTokensNotMet);
+
+		let pallet_account = Self::account_id();
+		T::AssetRegistry::burn_from(exchange.liquidity_token_id, who, liquidity_amount)?;
+		T::Currency::transfer(&pallet_account, who, currency_amount, ExistenceRequirement::AllowDeath)?;
+		T::Assets::transfer(asset_id, &pallet_account, who, token_amount, false)?;
+
+		exchange.currency_reserve = exchange.currency_reserve.saturating_sub(currency_amount);
+		exchange.token_reserve = exchange.token_reserve.saturating_sub(token_amount);
+		Exchanges::<T>::insert(asset_id, exchange);
+		Ok((currency_amount, token_amount))
+	}
+
+	/// Sell native currency to the exchange of an asset.
+	fn try_currency_to_asset(
+		who: &T::AccountId,
+		asset_id: AssetIdOf<T>,
+		amount: TradeAmount<BalanceOf<T>, AssetBalanceOf<T>>,
+		deadline: T::BlockNumber,
+	) -> Result<(BalanceOf<T>, AssetBalanceOf<T>), DispatchError> {
+		Self::ensure_deadline(deadline)?;
+		let mut exchange = Exchanges::<T>::get(asset_id).ok_or(Error::<T>::ExchangeNotFound)?;
+
+		let (currency_amount, token_amount) =
+			Self::trade_amounts(amount, exchange.currency_reserve, exchange.token_reserve)?;
+
+		let pallet_account = Self::account_id();
+		T::Currency::transfer(who, &pallet_account, currency_amount, ExistenceRequirement::KeepAlive)?;
+		T::Assets::transfer(asset_id, &pallet_account, who, token_amount, false)?;
+
+		exchange.currency_reserve = exchange.currency_reserve.saturating_add(currency_amount);
+		exchange.token_reserve = exchange.token_reserve.saturating_sub(token_amount);
+		Exchanges::<T>::insert(asset_id, exchange);
+		Ok((currency_amount, token_amount))
+	}
+
+	/// Sell an asset to its exchange for native currency.
+	fn try_asset_to_currency(
+		who: &T::AccountId,
+		asset_id: AssetIdOf<T>,
+		amount: TradeAmount<AssetBalanceOf<T>, BalanceOf<T>>,
+		deadline: T::BlockNumber,
+	) -> Result<(AssetBalanceOf<T>, BalanceOf<T>), DispatchError> {
+		Self::ensure_deadline(deadline)?;
+		let mut exchange = Exchanges::<T>::get(asset_id).ok_or(Error::<T>::ExchangeNotFound)?;
+
+		let (token_amount, currency_amount) =
+			Self::trade_amounts(amount, exchange.token_reserve, exchange.currency_reserve)?;
+
+		let pallet_account = Self::account_id();
+		T::Assets::transfer(asset_id, who, &pallet_account, token_amount, false)?;
+		T::Currency::transfer(&pallet_account, who, currency_amount, ExistenceRequirement::AllowDeath)?;
+
+		exchange.token_reserve = exchange.token_reserve.saturating_add(token_amount);
+		exchange.currency_reserve = exchange.currency_reserve.saturating_sub(currency_amount);
+		Exchanges::<T>::insert(asset_id, exchange);
+		Ok((token_amount, currency_amount))
+	}
+
+	/// Sell `amount_in` of the first currency of `path` for at least `min_amount_out` of the last.
+	fn try_swap_exact_in(
+		who: &T::AccountId,
+		path: &[SwapAsset<AssetIdOf<T>>],
+		amount_in: AssetBalanceOf<T>,
+		min_amount_out: AssetBalanceOf<T>,
+		deadline: T::BlockNumber,
+	) -> Result<AssetBalanceOf<T>, DispatchError> {
+		Self::ensure_deadline(deadline)?;
+		let exchanges = Self::swap_exchanges(path)?;
+		let amounts = Self::amounts_exact_in(path, &exchanges, Self::path_amount(path[0], amount_in))?;
+
+		let amount_out = Self::path_balance(path[path.len() - 1], amounts[amounts.len() - 1]);
+		ensure!(amount_out >= min_amount_out, Error::<T>::MinOutputNotMet);
+
+		Self::swap(who, path, &amounts, exchanges)?;
+		Ok(amount_out)
+	}
+
+	/// Buy `amount_out` of the last currency of `path` for at most `max_amount_in` of the first.
+	fn try_swap_exact_out(
+		who: &T::AccountId,
+		path: &[SwapAsset<AssetIdOf<T>>],
+		amount_out: AssetBalanceOf<T>,
+		max_amount_in: AssetBalanceOf<T>,
+		deadline: T::BlockNumber,
+	) -> Result<AssetBalanceOf<T>, DispatchError> {
+		Self::ensure_deadline(deadline)?;
+		let exchanges = Self::swap_exchanges(path)?;
+		let amounts = Self::amounts_exact_out(path, &exchanges, Self::path_amount(path[path.len() - 1], amount_out))?;
+
+		let amount_in = Self::path_balance(path[0], amounts[0]);
+		ensure!(amount_in <= max_amount_in, Error::<T>::MaxInputExceeded);
+
+		Self::swap(who, path, &amounts, exchanges)?;
+		Ok(amount_in)
+	}
+
+	/// Move the first amount of a swap to the pallet account and the last one to `who`, and
+	/// trade the amounts in between with the reserves of each exchange of the path.
+	fn swap(
+		who: &T::AccountId,
+		path: &[SwapAsset<AssetIdOf<T>>],
+		amounts: &[u128],
+		exchanges: Vec<ExchangeOf<T>>,
+	) -> DispatchResult {
+		let pallet_account = Self::account_id();
+		Self::transfer_path_amount(path[0], who, &pallet_account, amounts[0], ExistenceRequirement::KeepAlive)?;
+
+		for ((input, mut exchange), hop) in path.iter().zip(exchanges).zip(amounts.windows(2)) {
+			let (amount_in, amount_out) = (hop[0], hop[1]);
+			match input {
+				SwapAsset::Native => {
+					exchange.currency_reserve = exchange.currency_reserve.saturating_add(amount_in.saturated_into());
+					exchange.token_reserve = exchange.token_reserve.saturating_sub(amount_out.saturated_into());
+				},
+				SwapAsset::Asset(_) => {
+					exchange.token_reserve = exchange.token_reserve.saturating_add(amount_in.saturated_into());
+					exchange.currency_reserve = exchange.currency_reserve.saturating_sub(amount_out.saturated_into());
+				},
+			}
+			Exchanges::<T>::insert(exchange.asset_id, exchange);
+		}
+
+		Self::transfer_path_amount(
+			path[path.len() - 1],
+			&pallet_account,
+			who,
+			amounts[amounts.len() - 1],
+			ExistenceRequirement::AllowDeath,
+		)
+	}
+
+	/// The exchanges along `path`, each trading a currency of the path for the next one.
+	fn swap_exchanges(path: &[SwapAsset<AssetIdOf<T>>]) -> Result<Vec<ExchangeOf<T>>, DispatchError> {
+		ensure!(path.len() >= 2 && path.len() as u32 <= MAX_SWAP_PATH, Error::<T>::InvalidPath);
+
+		let mut exchanges: Vec<ExchangeOf<T>> = Vec::new();
+		for hop in path.windows(2) {
+			let asset_id = match (hop[0], hop[1]) {
+				(SwapAsset::Native, SwapAsset::Asset(asset_id)) | (SwapAsset::Asset(asset_id), SwapAsset::Native) =>
+					asset_id,
+				_ => return Err(Error::<T>::InvalidPath.into()),
+			};
+			// Amounts are computed with the reserves before the swap, so each exchange is used once.
+			ensure!(!exchanges.iter().any(|exchange| exchange.asset_id == asset_id), Error::<T>::InvalidPath);
+			exchanges.push(Exchanges::<T>::get(asset_id).ok_or(Error::<T>::ExchangeNotFound)?);
+		}
+
+		Ok(exchanges)
+	}
+
+	/// Amounts of each currency of `path` when selling `amount_in` of the first one.
+	fn amounts_exact_in(
+		path: &[SwapAsset<AssetIdOf<T>>],
+		exchanges: &[ExchangeOf<T>],
+		amount_in: u128,
+	) -> Result<Vec<u128>, DispatchError> {
+		let mut amounts = vec![amount_in];
+		for (input, exchange) in path.iter().zip(exchanges) {
+			let (input_reserve, output_reserve) = Self::hop_reserves(*input, exchange);
+			let amount = Self::output_amount(amounts[amounts.len() - 1], input_reserve, output_reserve)?;
+			amounts.push(amount);
+		}
+		Ok(amounts)
+	}
+
+	/// Amounts of each currency of `path` when buying `amount_out` of the last one.
+	fn amounts_exact_out(
+		path: &[SwapAsset<AssetIdOf<T>>],
+		exchanges: &[ExchangeOf<T>],
+		amount_out: u128,
+	) -> Result<Vec<u128>, DispatchError> {
+		let mut amounts = vec![amount_out];
+		for (input, exchange) in path[..exchanges.len()].iter().zip(exchanges).rev() {
+			let (input_reserve, output_reserve) = Self::hop_reserves(*input, exchange);
+			let amount = Self::input_amount(amounts[0], input_reserve, output_reserve)?;
+			amounts.insert(0, amount);
+		}
+		Ok(amounts)
+	}
+
+	/// Reserves of an exchange selling `input`, as the input and output reserves.
+	fn hop_reserves(input: SwapAsset<AssetIdOf<T>>, exchange: &ExchangeOf<T>) -> (u128, u128) {
+		let currency_reserve = exchange.currency_reserve.saturated_into::<u128>();
+		let token_reserve = exchange.token_reserve.saturated_into::<u128>();
+		match input {
+			SwapAsset::Native => (currency_reserve, token_reserve),
+			SwapAsset::Asset(_) => (token_reserve, currency_reserve),
+		}
+	}
+
+	/// An amount of a currency of a path given as an asset balance.
+	fn path_amount(asset: SwapAsset<AssetIdOf<T>>, amount: AssetBalanceOf<T>) -> u128 {
+		match asset {
+			SwapAsset::Native => T::AssetToCurrencyBalance::convert(amount).saturated_into(),
+			SwapAsset::Asset(_) => amount.saturated_into(),
+		}
+	}
+
+	/// An amount of a currency of a path as an asset balance.
+	fn path_balance(asset: SwapAsset<AssetIdOf<T>>, amount: u128) -> AssetBalanceOf<T> {
+		match asset {
+			SwapAsset::Native => T::CurrencyToAssetBalance::convert(amount.saturated_into()),
+			SwapAsset::Asset(_) => amount.saturated_into(),
+		}
+	}
+
+	fn transfer_path_amount(
+		asset: SwapAsset<AssetIdOf<T>>,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: u128,
+		existence_requirement: ExistenceRequirement,
+	) -> DispatchResult {
+		match asset {
+			SwapAsset::Native => T::Currency::transfer(source, dest, amount.saturated_into(), existence_requirement),
+			SwapAsset::Asset(asset_id) =>
+				T::Assets::transfer(asset_id, source, dest, amount.saturated_into(), false).map(|_| ()),
+		}
+	}
+
+	/// The path to swap `asset_in` for `asset_out`, through the native currency between assets.
+	pub fn swap_path(asset_in: SwapAsset<AssetIdOf<T>>, asset_out: SwapAsset<AssetIdOf<T>>) -> Vec<SwapAsset<AssetIdOf<T>>> {
+		match (asset_in, asset_out) {
+			(SwapAsset::Asset(_), SwapAsset::Asset(_)) => vec![asset_in, SwapAsset::Native, asset_out],
+			_ => vec![asset_in, asset_out],
+		}
+	}
+
+	/// Amount bought of the last currency of `path` by selling `amount_in` of the first one, `None`
+	/// when the path can't be swapped.
+	pub fn quote_exact_in(path: Vec<SwapAsset<AssetIdOf<T>>>, amount_in: AssetBalanceOf<T>) -> Option<AssetBalanceOf<T>> {
+		let exchanges = Self::swap_exchanges(&path).ok()?;
+		let amounts = Self::amounts_exact_in(&path, &exchanges, Self::path_amount(path[0], amount_in)).ok()?;
+		Some(Self::path_balance(path[path.len() - 1], amounts[amounts.len() - 1]))
+	}
+
+	/// Amount sold of the first currency of `path` to buy `amount_out` of the last one, `None`
+	/// when the path can't be swapped.
+	pub fn quote_exact_out(path: Vec<SwapAsset<AssetIdOf<T>>>, amount_out: AssetBalanceOf<T>) -> Option<AssetBalanceOf<T>> {
+		let exchanges = Self::swap_exchanges(&path).ok()?;
+		let amounts =
+			Self::amounts_exact_out(&path, &exchanges, Self::path_amount(path[path.len() - 1], amount_out)).ok()?;
+		Some(Self::path_balance(path[0], amounts[0]))
+	}
+
+	/// Amounts sold and bought in a trade with the reserves of an exchange, within its limits.
+	fn trade_amounts<I: AtLeast32BitUnsigned + Copy, O: AtLeast32BitUnsigned + Copy>(
+		amount: TradeAmount<I, O>,
+		input_reserve: I,
+		output_reserve: O,
+	) -> Result<(I, O), DispatchError> {
+		let input_reserve = input_reserve.saturated_into::<u128>();
+		let output_reserve = output_reserve.saturated_into::<u128>();
+		match amount {
+			TradeAmount::FixedInput { input_amount, min_output } => {
+				let output_amount: O =
+					Self::output_amount(input_amount.saturated_into(), input_reserve, output_reserve)?.saturated_into();
+				ensure!(output_amount >= min_output, Error::<T>::MinOutputNotMet);
+				Ok((input_amount, output_amount))
+			},
+			TradeAmount::FixedOutput { max_input, output_amount } => {
+				let input_amount = Self::input_amount(output_amount.saturated_into(), input_reserve, output_reserve)?;
+				ensure!(input_amount <= max_input.saturated_into::<u128>(), Error::<T>::MaxInputExceeded);
+				Ok((input_amount.saturated_into(), output_amount))
+			},
+		}
+	}
+
+	/// Amount bought with `input_amount` from reserves, after the provider fee.
+	pub fn output_amount(input_amount: u128, input_reserve: u128, output_reserve: u128) -> Result<u128, DispatchError> {
+		ensure!(!input_amount.is_zero(), Error::<T>::TradeAmountIsZero);
+		ensure!(!input_reserve.is_zero() && !output_reserve.is_zero(), Error::<T>::NotEnoughLiquidity);
+
+		let (fee_numerator, fee_denominator) = Self::provider_fee();
+		let input_with_fee = U256::from(input_amount) * U256::from(fee_denominator.saturating_sub(fee_numerator));
+		let numerator = input_with_fee * U256::from(output_reserve);
+		let denominator = U256::from(input_reserve) * U256::from(fee_denominator) + input_with_fee;
+		let output_amount = Self::to_u128(numerator.checked_div(denominator))?;
+
+		ensure!(!output_amount.is_zero(), Error::<T>::TradeAmountIsZero);
+		Ok(output_amount)
+	}
+
+	/// Amount sold to buy `output_amount` from reserves, after the provider fee.
+	pub fn input_amount(output_amount: u128, input_reserve: u128, output_reserve: u128) -> Result<u128, DispatchError> {
+		ensure!(!output_amount.is_zero(), Error::<T>::TradeAmountIsZero);
+		ensure!(!input_reserve.is_zero() && output_amount < output_reserve, Error::<T>::NotEnoughLiquidity);
+
+		let (fee_numerator, fee_denominator) = Self::provider_fee();
+		let numerator = U256::from(input_reserve) * U256::from(output_amount) * U256::from(fee_denominator);
+		let denominator =
+			U256::from(output_reserve - output_amount) * U256::from(fee_denominator.saturating_sub(fee_numerator));
+
+		// Round up, in favour of the exchange.
+		Ok(Self::to_u128(numerator.checked_div(denominator))?.saturating_add(1))
+	}
+
+	/// The provider fee as its numerator and denominator.
+	fn provider_fee() -> (u128, u128) {
+		(T::ProviderFeeNumerator::get().saturated_into(), T::ProviderFeeDenominator::get().saturated_into())
+	}
+
+	/// `a * b / c` rounded down, without overflowing on the product.
+	fn mul_div(a: u128, b: u128, c: u128) -> Result<u128, DispatchError> {
+		Self::to_u128((U256::from(a) * U256::from(b)).checked_div(U256::from(c)))
+	}
+
+	fn to_u128(value: Option<U256>) -> Result<u128, DispatchError> {
+		value
+			.and_then(|value| u128::try_from(value).ok())
+			.ok_or_else(|| Error::<T>::Overflow.into())
+	}
+
+	fn ensure_deadline(deadline: T::BlockNumber) -> DispatchResult {
+		let now = <frame_system::Pallet<T>>::block_number();
+		ensure!(now <= deadline, Error::<T>::DeadlinePassed);
+		Ok(())
+	}
+}
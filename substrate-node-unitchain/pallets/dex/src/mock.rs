@@ -0,0 +1,155 @@
+use crate as pallet_dex;
+use crate::*;
+use frame_support::{
+	assert_ok, parameter_types, PalletId,
+	traits::{AsEnsureOriginWithArg, ConstU16, ConstU32, ConstU64},
+};
+use frame_system as system;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, Identity, IdentityLookup},
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+type Balance = u64;
+type AccountId = u64;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Assets: pallet_assets,
+		Dex: pallet_dex,
+	}
+);
+
+impl system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type MaxLocks = ConstU32<10>;
+	type Balance = Balance;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+impl pallet_assets::Config for Test {
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+	type RuntimeEvent = RuntimeEvent;
+	type RemoveItemsLimit = ConstU32<1000>;
+	type AssetIdParameter = codec::Compact<u32>;
+	type Balance = Balance;
+	type AssetId = u32;
+	type Currency = Balances;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type AssetDeposit = ConstU64<0>;
+	type AssetAccountDeposit = ConstU64<0>;
+	type MetadataDepositBase = ConstU64<0>;
+	type MetadataDepositPerByte = ConstU64<0>;
+	type ApprovalDeposit = ConstU64<0>;
+	type StringLimit = ConstU32<20>;
+	type Freezer = ();
+	type Extra = ();
+	type WeightInfo = ();
+	type CallbackHandle = ();
+}
+
+parameter_types! {
+	pub const DexPalletId: PalletId = PalletId(*b"unit_dex");
+}
+
+impl pallet_dex::Config for Test {
+	type PalletId = DexPalletId;
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type AssetBalance = Balance;
+	type AssetToCurrencyBalance = Identity;
+	type CurrencyToAssetBalance = Identity;
+	type Assets = Assets;
+	type AssetRegistry = Assets;
+	type WeightInfo = ();
+	// Provider fee is 0.3%
+	type ProviderFeeNumerator = ConstU64<3>;
+	type ProviderFeeDenominator = ConstU64<1000>;
+	type MinDeposit = ConstU64<10>;
+}
+
+/// The asset traded in the exchanges of the tests.
+pub const ASSET: u32 = 1;
+/// The liquidity token of the exchange of `ASSET`.
+pub const LIQUIDITY_TOKEN: u32 = 100;
+/// Another asset, to swap `ASSET` for.
+pub const OTHER_ASSET: u32 = 2;
+/// The liquidity token of the exchange of `OTHER_ASSET`.
+pub const OTHER_LIQUIDITY_TOKEN: u32 = 101;
+
+// Build genesis storage according to the mock runtime, accounts 1 to 3 have 10_000.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut storage = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: (1..=3).map(|account| (account, 10_000)).collect() }
+		.assimilate_storage(&mut storage)
+		.unwrap();
+
+	let mut ext: sp_io::TestExternalities = storage.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+/// Give `10_000` of `asset_id` to each account, creating the asset.
+pub fn fund(asset_id: u32, accounts: &[AccountId]) {
+	assert_ok!(Assets::force_create(RuntimeOrigin::root(), asset_id.into(), 1, false, 1));
+	for account in accounts {
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), asset_id.into(), *account, 10_000));
+	}
+}
+
+/// Create the exchange of `ASSET` from account 1 with 1_000 currency and 2_000 of the asset.
+pub fn begin_exchange() {
+	fund(ASSET, &[1, 2]);
+	assert_ok!(Dex::create_exchange(RuntimeOrigin::signed(1), ASSET, LIQUIDITY_TOKEN, 1_000, 2_000));
+}
+
+/// Create the exchange of `ASSET`, and the exchange of `OTHER_ASSET` with 1_000 currency and 500
+/// of the asset.
+pub fn begin_exchanges() {
+	begin_exchange();
+	fund(OTHER_ASSET, &[1, 2]);
+	assert_ok!(Dex::create_exchange(RuntimeOrigin::signed(1), OTHER_ASSET, OTHER_LIQUIDITY_TOKEN, 1_000, 500));
+}
@@ -0,0 +1,302 @@
+use crate::{mock::*, Error, *};
+use frame_support::{assert_noop, assert_ok};
+
+fn exchange() -> ExchangeOf<Test> {
+	Dex::exchanges(ASSET).unwrap()
+}
+
+#[test]
+fn create_exchange_should_work() {
+	new_test_ext().execute_with(|| {
+		begin_exchange();
+
+		assert_eq!(
+			exchange(),
+			Exchange { asset_id: ASSET, currency_reserve: 1_000, token_reserve: 2_000, liquidity_token_id: LIQUIDITY_TOKEN }
+		);
+		assert_eq!(Balances::free_balance(Dex::account_id()), 1_000);
+		assert_eq!(Assets::balance(ASSET, Dex::account_id()), 2_000);
+		assert_eq!(Assets::balance(LIQUIDITY_TOKEN, 1), 1_000);
+		assert_eq!((Balances::free_balance(1), Assets::balance(ASSET, 1)), (9_000, 8_000));
+		System::assert_has_event(Event::ExchangeCreated { asset_id: ASSET, liquidity_token_id: LIQUIDITY_TOKEN }.into());
+		System::assert_last_event(
+			Event::LiquidityAdded {
+				provider: 1,
+				asset_id: ASSET,
+				currency_amount: 1_000,
+				token_amount: 2_000,
+				liquidity_minted: 1_000,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn create_exchange_checks_assets_and_deposit() {
+	new_test_ext().execute_with(|| {
+		fund(ASSET, &[1]);
+
+		assert_noop!(Dex::create_exchange(RuntimeOrigin::signed(1), 2, LIQUIDITY_TOKEN, 1_000, 2_000), Error::<Test>::AssetNotFound);
+		assert_noop!(Dex::create_exchange(RuntimeOrigin::signed(1), ASSET, LIQUIDITY_TOKEN, 9, 2_000), Error::<Test>::DepositTooLow);
+		assert_noop!(Dex::create_exchange(RuntimeOrigin::signed(1), ASSET, LIQUIDITY_TOKEN, 1_000, 0), Error::<Test>::TokenAmountIsZero);
+		assert_noop!(Dex::create_exchange(RuntimeOrigin::signed(1), ASSET, ASSET, 1_000, 2_000), Error::<Test>::InvalidLiquidityToken);
+
+		// The liquidity token should be a new asset.
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), LIQUIDITY_TOKEN.into(), 1, false, 1));
+		assert_noop!(
+			Dex::create_exchange(RuntimeOrigin::signed(1), ASSET, LIQUIDITY_TOKEN, 1_000, 2_000),
+			Error::<Test>::InvalidLiquidityToken
+		);
+
+		assert_ok!(Dex::create_exchange(RuntimeOrigin::signed(1), ASSET, LIQUIDITY_TOKEN + 1, 1_000, 2_000));
+		assert_noop!(
+			Dex::create_exchange(RuntimeOrigin::signed(1), ASSET, LIQUIDITY_TOKEN + 2, 1_000, 2_000),
+			Error::<Test>::ExchangeAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_keeps_the_price() {
+	new_test_ext().execute_with(|| {
+		begin_exchange();
+
+		assert_noop!(
+			Dex::add_liquidity(RuntimeOrigin::signed(2), ASSET, 100, 0, 200, 10),
+			Error::<Test>::MaxTokensExceeded
+		);
+		assert_noop!(
+			Dex::add_liquidity(RuntimeOrigin::signed(2), ASSET, 100, 101, 201, 10),
+			Error::<Test>::MinLiquidityNotMet
+		);
+		assert_noop!(Dex::add_liquidity(RuntimeOrigin::signed(2), 2, 100, 0, 201, 10), Error::<Test>::ExchangeNotFound);
+
+		assert_ok!(Dex::add_liquidity(RuntimeOrigin::signed(2), ASSET, 100, 100, 201, 10));
+		System::assert_last_event(
+			Event::LiquidityAdded {
+				provider: 2,
+				asset_id: ASSET,
+				currency_amount: 100,
+				token_amount: 201,
+				liquidity_minted: 100,
+			}
+			.into(),
+		);
+		assert_eq!((exchange().currency_reserve, exchange().token_reserve), (1_100, 2_201));
+		assert_eq!(Assets::balance(LIQUIDITY_TOKEN, 2), 100);
+	});
+}
+
+#[test]
+fn remove_liquidity_withdraws_share_of_reserves() {
+	new_test_ext().execute_with(|| {
+		begin_exchange();
+		assert_ok!(Dex::add_liquidity(RuntimeOrigin::signed(2), ASSET, 100, 100, 201, 10));
+
+		assert_noop!(
+			Dex::remove_liquidity(RuntimeOrigin::signed(1), ASSET, 550, 551, 0, 10),
+			Error::<Test>::MinCurrencyNotMet
+		);
+		assert_noop!(
+			Dex::remove_liquidity(RuntimeOrigin::signed(1), ASSET, 550, 0, 1_101, 10),
+			Error::<Test>::MinTokensNotMet
+		);
+		assert_noop!(
+			Dex::remove_liquidity(RuntimeOrigin::signed(1), ASSET, 0, 0, 0, 10),
+			Error::<Test>::LiquidityAmountIsZero
+		);
+
+		assert_ok!(Dex::remove_liquidity(RuntimeOrigin::signed(1), ASSET, 550, 550, 1_100, 10));
+		System::assert_last_event(
+			Event::LiquidityRemoved {
+				provider: 1,
+				asset_id: ASSET,
+				currency_amount: 550,
+				token_amount: 1_100,
+				liquidity_burned: 550,
+			}
+			.into(),
+		);
+		assert_eq!((exchange().currency_reserve, exchange().token_reserve), (550, 1_101));
+		assert_eq!(Assets::balance(LIQUIDITY_TOKEN, 1), 450);
+		assert_eq!((Balances::free_balance(1), Assets::balance(ASSET, 1)), (9_550, 9_100));
+
+		// Only liquidity tokens held can be burned.
+		assert!(Dex::remove_liquidity(RuntimeOrigin::signed(2), ASSET, 101, 0, 0, 10).is_err());
+	});
+}
+
+#[test]
+fn currency_to_asset_should_work() {
+	new_test_ext().execute_with(|| {
+		begin_exchange();
+
+		assert_noop!(
+			Dex::currency_to_asset(RuntimeOrigin::signed(2), ASSET, TradeAmount::FixedInput { input_amount: 100, min_output: 182 }, 10),
+			Error::<Test>::MinOutputNotMet
+		);
+		assert_ok!(Dex::currency_to_asset(
+			RuntimeOrigin::signed(2),
+			ASSET,
+			TradeAmount::FixedInput { input_amount: 100, min_output: 181 },
+			10
+		));
+		System::assert_last_event(
+			Event::CurrencyTradedForAsset { asset_id: ASSET, buyer: 2, currency_amount: 100, token_amount: 181 }.into(),
+		);
+		assert_eq!((exchange().currency_reserve, exchange().token_reserve), (1_100, 1_819));
+		assert_eq!((Balances::free_balance(2), Assets::balance(ASSET, 2)), (9_900, 10_181));
+
+		// Buying an exact amount, 1_100 * 219 * 1000 / (1_600 * 997) rounded up.
+		assert_noop!(
+			Dex::currency_to_asset(RuntimeOrigin::signed(2), ASSET, TradeAmount::FixedOutput { max_input: 151, output_amount: 219 }, 10),
+			Error::<Test>::MaxInputExceeded
+		);
+		assert_ok!(Dex::currency_to_asset(
+			RuntimeOrigin::signed(2),
+			ASSET,
+			TradeAmount::FixedOutput { max_input: 152, output_amount: 219 },
+			10
+		));
+		assert_eq!((exchange().currency_reserve, exchange().token_reserve), (1_252, 1_600));
+
+		// An exchange can't be drained.
+		assert_noop!(
+			Dex::currency_to_asset(RuntimeOrigin::signed(2), ASSET, TradeAmount::FixedOutput { max_input: 9_000, output_amount: 1_600 }, 10),
+			Error::<Test>::NotEnoughLiquidity
+		);
+	});
+}
+
+#[test]
+fn asset_to_currency_should_work() {
+	new_test_ext().execute_with(|| {
+		begin_exchange();
+
+		assert_ok!(Dex::asset_to_currency(
+			RuntimeOrigin::signed(2),
+			ASSET,
+			TradeAmount::FixedInput { input_amount: 200, min_output: 90 },
+			10
+		));
+		System::assert_last_event(
+			Event::AssetTradedForCurrency { asset_id: ASSET, buyer: 2, token_amount: 200, currency_amount: 90 }.into(),
+		);
+		assert_eq!((exchange().currency_reserve, exchange().token_reserve), (910, 2_200));
+		assert_eq!((Balances::free_balance(2), Assets::balance(ASSET, 2)), (10_090, 9_800));
+
+		// Buying an exact amount, 2_200 * 110 * 1000 / (800 * 997) rounded up.
+		assert_ok!(Dex::asset_to_currency(
+			RuntimeOrigin::signed(2),
+			ASSET,
+			TradeAmount::FixedOutput { max_input: 304, output_amount: 110 },
+			10
+		));
+		assert_eq!((exchange().currency_reserve, exchange().token_reserve), (800, 2_504));
+		assert_noop!(
+			Dex::asset_to_currency(RuntimeOrigin::signed(2), ASSET, TradeAmount::FixedInput { input_amount: 0, min_output: 0 }, 10),
+			Error::<Test>::TradeAmountIsZero
+		);
+	});
+}
+
+#[test]
+fn calls_fail_after_deadline() {
+	new_test_ext().execute_with(|| {
+		begin_exchange();
+		System::set_block_number(11);
+
+		assert_noop!(Dex::add_liquidity(RuntimeOrigin::signed(2), ASSET, 100, 0, 201, 10), Error::<Test>::DeadlinePassed);
+		assert_noop!(Dex::remove_liquidity(RuntimeOrigin::signed(1), ASSET, 100, 0, 0, 10), Error::<Test>::DeadlinePassed);
+		assert_noop!(
+			Dex::currency_to_asset(RuntimeOrigin::signed(2), ASSET, TradeAmount::FixedInput { input_amount: 100, min_output: 0 }, 10),
+			Error::<Test>::DeadlinePassed
+		);
+		assert_noop!(
+			Dex::asset_to_currency(RuntimeOrigin::signed(2), ASSET, TradeAmount::FixedInput { input_amount: 100, min_output: 0 }, 10),
+			Error::<Test>::DeadlinePassed
+		);
+	});
+}
+
+fn path(path: Vec<SwapAsset<u32>>) -> SwapPathOf<Test> {
+	path.try_into().unwrap()
+}
+
+#[test]
+fn swap_exact_in_routes_through_native_currency() {
+	new_test_ext().execute_with(|| {
+		begin_exchanges();
+		let route = Dex::swap_path(SwapAsset::Asset(ASSET), SwapAsset::Asset(OTHER_ASSET));
+		assert_eq!(route, vec![SwapAsset::Asset(ASSET), SwapAsset::Native, SwapAsset::Asset(OTHER_ASSET)]);
+
+		// 200 of the asset buy 90 currency, which buy 41 of the other asset.
+		assert_eq!(Dex::quote_exact_in(route.clone(), 200), Some(41));
+		assert_noop!(
+			Dex::swap_exact_in(RuntimeOrigin::signed(2), path(route.clone()), 200, 42, 10),
+			Error::<Test>::MinOutputNotMet
+		);
+		assert_ok!(Dex::swap_exact_in(RuntimeOrigin::signed(2), path(route.clone()), 200, 41, 10));
+		System::assert_last_event(Event::Swapped { who: 2, path: path(route), amount_in: 200, amount_out: 41 }.into());
+
+		assert_eq!((exchange().currency_reserve, exchange().token_reserve), (910, 2_200));
+		let other = Dex::exchanges(OTHER_ASSET).unwrap();
+		assert_eq!((other.currency_reserve, other.token_reserve), (1_090, 459));
+		assert_eq!(Balances::free_balance(2), 10_000);
+		assert_eq!((Assets::balance(ASSET, 2), Assets::balance(OTHER_ASSET, 2)), (9_800, 10_041));
+	});
+}
+
+#[test]
+fn swap_exact_out_routes_through_native_currency() {
+	new_test_ext().execute_with(|| {
+		begin_exchanges();
+		let route = Dex::swap_path(SwapAsset::Asset(ASSET), SwapAsset::Asset(OTHER_ASSET));
+
+		// 41 of the other asset cost 90 currency, which cost 199 of the asset.
+		assert_eq!(Dex::quote_exact_out(route.clone(), 41), Some(199));
+		assert_noop!(
+			Dex::swap_exact_out(RuntimeOrigin::signed(2), path(route.clone()), 41, 198, 10),
+			Error::<Test>::MaxInputExceeded
+		);
+		assert_ok!(Dex::swap_exact_out(RuntimeOrigin::signed(2), path(route.clone()), 41, 199, 10));
+		System::assert_last_event(Event::Swapped { who: 2, path: path(route), amount_in: 199, amount_out: 41 }.into());
+
+		assert_eq!((exchange().currency_reserve, exchange().token_reserve), (910, 2_199));
+		let other = Dex::exchanges(OTHER_ASSET).unwrap();
+		assert_eq!((other.currency_reserve, other.token_reserve), (1_090, 459));
+		assert_eq!((Assets::balance(ASSET, 2), Assets::balance(OTHER_ASSET, 2)), (9_801, 10_041));
+
+		// A single exchange is a path too.
+		assert_ok!(Dex::swap_exact_out(RuntimeOrigin::signed(2), path(vec![SwapAsset::Native, SwapAsset::Asset(ASSET)]), 100, 100, 10));
+	});
+}
+
+#[test]
+fn swaps_need_a_valid_path() {
+	new_test_ext().execute_with(|| {
+		begin_exchanges();
+		let (native, asset, other) = (SwapAsset::Native, SwapAsset::Asset(ASSET), SwapAsset::Asset(OTHER_ASSET));
+
+		for route in [vec![asset], vec![asset, other], vec![native, native], vec![asset, native, asset]] {
+			assert_noop!(
+				Dex::swap_exact_in(RuntimeOrigin::signed(2), path(route.clone()), 100, 0, 10),
+				Error::<Test>::InvalidPath
+			);
+			assert_eq!(Dex::quote_exact_in(route, 100), None);
+		}
+		assert_noop!(
+			Dex::swap_exact_in(RuntimeOrigin::signed(2), path(vec![native, SwapAsset::Asset(3)]), 100, 0, 10),
+			Error::<Test>::ExchangeNotFound
+		);
+		assert_noop!(
+			Dex::swap_exact_out(RuntimeOrigin::signed(2), path(vec![asset, native, other]), 500, 10_000, 10),
+			Error::<Test>::NotEnoughLiquidity
+		);
+		assert_noop!(
+			Dex::swap_exact_in(RuntimeOrigin::signed(2), path(vec![asset, native, other]), 100, 0, 0),
+			Error::<Test>::DeadlinePassed
+		);
+	});
+}
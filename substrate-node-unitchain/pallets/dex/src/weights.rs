@@ -0,0 +1,158 @@
+//! Weights for pallet_dex.
+//!
+//! Estimated from the storage read and written by each call until `./scripts/benchmark.sh dex`
+//! is run on reference hardware, which overwrites this file with the measured weights.
+//! Swaps that go through two exchanges are estimated for both.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_dex.
+pub trait WeightInfo {
+	fn create_exchange() -> Weight;
+	fn add_liquidity() -> Weight;
+	fn remove_liquidity() -> Weight;
+	fn currency_to_asset() -> Weight;
+	fn asset_to_currency() -> Weight;
+	fn swap_exact_in() -> Weight;
+	fn swap_exact_out() -> Weight;
+}
+
+/// Weights for pallet_dex using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Dex Exchanges (r:1 w:1)
+	// Storage: Assets Asset (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Assets Account (r:3 w:3)
+	fn create_exchange() -> Weight {
+		Weight::from_ref_time(58_614_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// Storage: Dex Exchanges (r:1 w:1)
+	// Storage: Assets Asset (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Assets Account (r:3 w:3)
+	fn add_liquidity() -> Weight {
+		Weight::from_ref_time(71_209_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// Storage: Dex Exchanges (r:1 w:1)
+	// Storage: Assets Asset (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Assets Account (r:3 w:3)
+	fn remove_liquidity() -> Weight {
+		Weight::from_ref_time(69_847_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// Storage: Dex Exchanges (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Assets Account (r:2 w:2)
+	fn currency_to_asset() -> Weight {
+		Weight::from_ref_time(52_371_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: Dex Exchanges (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Assets Account (r:2 w:2)
+	fn asset_to_currency() -> Weight {
+		Weight::from_ref_time(53_096_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: Dex Exchanges (r:2 w:2)
+	// Storage: Assets Asset (r:2 w:2)
+	// Storage: Assets Account (r:4 w:4)
+	// Storage: System Account (r:2 w:0)
+	fn swap_exact_in() -> Weight {
+		Weight::from_ref_time(68_452_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// Storage: Dex Exchanges (r:2 w:2)
+	// Storage: Assets Asset (r:2 w:2)
+	// Storage: Assets Account (r:4 w:4)
+	// Storage: System Account (r:2 w:0)
+	fn swap_exact_out() -> Weight {
+		Weight::from_ref_time(69_318_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Dex Exchanges (r:1 w:1)
+	// Storage: Assets Asset (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Assets Account (r:3 w:3)
+	fn create_exchange() -> Weight {
+		Weight::from_ref_time(58_614_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
+	// Storage: Dex Exchanges (r:1 w:1)
+	// Storage: Assets Asset (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Assets Account (r:3 w:3)
+	fn add_liquidity() -> Weight {
+		Weight::from_ref_time(71_209_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
+	// Storage: Dex Exchanges (r:1 w:1)
+	// Storage: Assets Asset (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Assets Account (r:3 w:3)
+	fn remove_liquidity() -> Weight {
+		Weight::from_ref_time(69_847_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
+	// Storage: Dex Exchanges (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Assets Account (r:2 w:2)
+	fn currency_to_asset() -> Weight {
+		Weight::from_ref_time(52_371_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	// Storage: Dex Exchanges (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Assets Account (r:2 w:2)
+	fn asset_to_currency() -> Weight {
+		Weight::from_ref_time(53_096_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	// Storage: Dex Exchanges (r:2 w:2)
+	// Storage: Assets Asset (r:2 w:2)
+	// Storage: Assets Account (r:4 w:4)
+	// Storage: System Account (r:2 w:0)
+	fn swap_exact_in() -> Weight {
+		Weight::from_ref_time(68_452_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
+	// Storage: Dex Exchanges (r:2 w:2)
+	// Storage: Assets Asset (r:2 w:2)
+	// Storage: Assets Account (r:4 w:4)
+	// Storage: System Account (r:2 w:0)
+	fn swap_exact_out() -> Weight {
+		Weight::from_ref_time(69_318_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
+}
@@ -0,0 +1,150 @@
+//! RPC interface for the polls pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use pallet_polls_runtime_api::{
+	PollCurrency, PollInfo, PollStatusFilter, PollsApi as PollsRuntimeApi,
+};
+
+/// Max number of polls returned by a single `polls_byCreator` or `polls_byCurrency` call.
+const MAX_POLLS_PAGE: u32 = 100;
+
+/// Error code of a failed runtime API call.
+const RUNTIME_ERROR: i32 = 1;
+
+#[rpc(server)]
+pub trait PollsApi<BlockHash, AccountId, AssetId, Balance, BlockNumber, PollIndex> {
+	/// Up to `limit` polls created by `creator` starting at `offset`, ordered by poll id, with
+	/// the vote of `voter` in each. `offset` and `limit` count the polls matching `status`.
+	#[method(name = "polls_byCreator")]
+	fn polls_by_creator(
+		&self,
+		creator: AccountId,
+		status: Option<PollStatusFilter>,
+		voter: Option<AccountId>,
+		offset: u32,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<PollInfo<PollIndex, AccountId, AssetId, Balance, BlockNumber>>>;
+
+	/// Up to `limit` polls in `currency` starting at `offset`, ordered by poll id, with the vote
+	/// of `voter` in each. `offset` and `limit` count the polls matching `status`.
+	#[method(name = "polls_byCurrency")]
+	fn polls_by_currency(
+		&self,
+		currency: PollCurrency<AssetId>,
+		status: Option<PollStatusFilter>,
+		voter: Option<AccountId>,
+		offset: u32,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<PollInfo<PollIndex, AccountId, AssetId, Balance, BlockNumber>>>;
+
+	/// A poll with its tally and the vote of `voter` in it.
+	#[method(name = "polls_poll")]
+	fn poll(
+		&self,
+		currency: PollCurrency<AssetId>,
+		poll_id: PollIndex,
+		voter: Option<AccountId>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<PollInfo<PollIndex, AccountId, AssetId, Balance, BlockNumber>>>;
+}
+
+/// Provides RPC methods to query polls.
+pub struct Polls<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Polls<C, Block> {
+	/// Create new `Polls` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+
+	fn block_id(&self, at: Option<<Block as BlockT>::Hash>) -> BlockId<Block>
+	where
+		Block: BlockT,
+		C: HeaderBackend<Block>,
+	{
+		BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash))
+	}
+}
+
+fn runtime_error(err: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		RUNTIME_ERROR,
+		"Unable to query polls.",
+		Some(format!("{:?}", err)),
+	))
+	.into()
+}
+
+#[async_trait]
+impl<C, Block, AccountId, AssetId, Balance, BlockNumber, PollIndex>
+	PollsApiServer<<Block as BlockT>::Hash, AccountId, AssetId, Balance, BlockNumber, PollIndex>
+	for Polls<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: PollsRuntimeApi<Block, AccountId, AssetId, Balance, BlockNumber, PollIndex>,
+	AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	AssetId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	Balance: Codec + Serialize + Send + Sync + 'static,
+	BlockNumber: Codec + Serialize + Send + Sync + 'static,
+	PollIndex: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+	fn polls_by_creator(
+		&self,
+		creator: AccountId,
+		status: Option<PollStatusFilter>,
+		voter: Option<AccountId>,
+		offset: u32,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<PollInfo<PollIndex, AccountId, AssetId, Balance, BlockNumber>>> {
+		let api = self.client.runtime_api();
+
+		api.polls_by_creator(&self.block_id(at), creator, status, voter, offset, limit.min(MAX_POLLS_PAGE))
+			.map_err(runtime_error)
+	}
+
+	fn polls_by_currency(
+		&self,
+		currency: PollCurrency<AssetId>,
+		status: Option<PollStatusFilter>,
+		voter: Option<AccountId>,
+		offset: u32,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<PollInfo<PollIndex, AccountId, AssetId, Balance, BlockNumber>>> {
+		let api = self.client.runtime_api();
+
+		api.polls_by_currency(&self.block_id(at), currency, status, voter, offset, limit.min(MAX_POLLS_PAGE))
+			.map_err(runtime_error)
+	}
+
+	fn poll(
+		&self,
+		currency: PollCurrency<AssetId>,
+		poll_id: PollIndex,
+		voter: Option<AccountId>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<PollInfo<PollIndex, AccountId, AssetId, Balance, BlockNumber>>> {
+		let api = self.client.runtime_api();
+
+		api.poll(&self.block_id(at), currency, poll_id, voter).map_err(runtime_error)
+	}
+}
@@ -0,0 +1,45 @@
+//! Runtime API definition for the polls pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_polls::{PollCurrency, PollInfo, PollStatusFilter};
+
+sp_api::decl_runtime_apis! {
+	pub trait PollsApi<AccountId, AssetId, Balance, BlockNumber, PollIndex> where
+		AccountId: Codec,
+		AssetId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+		PollIndex: Codec,
+	{
+		/// Up to `limit` polls created by `creator` starting at `offset`, ordered by poll id,
+		/// with the vote of `voter` in each. `offset` and `limit` count the polls matching `status`.
+		fn polls_by_creator(
+			creator: AccountId,
+			status: Option<PollStatusFilter>,
+			voter: Option<AccountId>,
+			offset: u32,
+			limit: u32,
+		) -> Vec<PollInfo<PollIndex, AccountId, AssetId, Balance, BlockNumber>>;
+
+		/// Up to `limit` polls in `currency` starting at `offset`, ordered by poll id, with the
+		/// vote of `voter` in each. `offset` and `limit` count the polls matching `status`.
+		fn polls_by_currency(
+			currency: PollCurrency<AssetId>,
+			status: Option<PollStatusFilter>,
+			voter: Option<AccountId>,
+			offset: u32,
+			limit: u32,
+		) -> Vec<PollInfo<PollIndex, AccountId, AssetId, Balance, BlockNumber>>;
+
+		/// A poll with its tally and the vote of `voter` in it.
+		fn poll(
+			currency: PollCurrency<AssetId>,
+			poll_id: PollIndex,
+			voter: Option<AccountId>,
+		) -> Option<PollInfo<PollIndex, AccountId, AssetId, Balance, BlockNumber>>;
+	}
+}
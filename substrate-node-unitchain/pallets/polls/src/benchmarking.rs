@@ -1,20 +1,402 @@
-//! Benchmarking setup for pallet-template
+//! Benchmarking for `pallet-polls`.
 
-use super::*;
+// Only enable this module for benchmarking.
+#![cfg(feature = "runtime-benchmarks")]
 
-#[allow(unused)]
-use crate::Pallet as Template;
-use frame_benchmarking::{benchmarks, whitelisted_caller};
+use crate::*;
+use frame_benchmarking::{account, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::benchmarking::{benchmarks, Linear};
 use frame_system::RawOrigin;
 
-benchmarks! {
-	do_something {
-		let s in 0 .. 100;
+const MAX_OPTIONS: u8 = u8::MAX;
+
+fn ipfs_cid(len: u32) -> IpfsCid {
+	vec![b'x'; len as usize]
+}
+
+/// Create a native currency poll of `creator` with `options` options, voting starts `delay`
+/// blocks from now and lasts 10 blocks.
+fn create_native_poll<T: Config>(
+	creator: &T::AccountId,
+	options: u8,
+	ballot_type: BallotType,
+	delay: u32,
+	reveal_period: Option<u32>,
+) -> T::PollIndex {
+	let start = <frame_system::Pallet<T>>::block_number() + delay.into();
+
+	Pallet::<T>::create_poll(
+		RawOrigin::Signed(creator.clone()).into(),
+		ipfs_cid(1_000),
+		options,
+		ballot_type,
+		PollCurrency::Native,
+		start,
+		start + 10u32.into(),
+		Zero::zero(),
+		PollThresholds::default(),
+		reveal_period.map(Into::into),
+		false,
+	)
+	.unwrap();
+
+	PollCount::<T>::get()
+}
+
+fn fund_native<T: Config>(who: &T::AccountId) {
+	T::Currency::make_free_balance_be(who, BalanceOf::<T>::saturated_from(1_000_000_000_000u128));
+}
+
+/// Vote with the native balance of `voter` in `count` new polls, so the lock of the account
+/// is computed from the most votes.
+fn fill_vote_locks<T: Config>(voter: &T::AccountId, count: u32) {
+	let creator: T::AccountId = account("creator", 0, 0);
+
+	for _ in 0..count {
+		let poll_id = create_native_poll::<T>(&creator, 2, BallotType::SingleChoice, 0, None);
+		Pallet::<T>::vote(RawOrigin::Signed(voter.clone()).into(), PollCurrency::Native, poll_id, Ballot::Single(0), Conviction::None).unwrap();
+	}
+}
+
+fn outcome<T: Config>(poll_id: T::PollIndex) -> <T as Config>::PollCall {
+	Call::<T>::remove_vote { poll_currency: PollCurrency::Native, poll_id }.into()
+}
+
+/// Set an outcome for each of the `options` of a poll.
+fn set_outcomes<T: Config>(poll_id: T::PollIndex, options: u8) {
+	for option in 0..options {
+		Outcomes::<T>::insert(poll_id, option, outcome::<T>(poll_id));
+	}
+}
+
+/// Commit `count` votes of other accounts to a commit-reveal poll, none of them revealed.
+fn fill_commitments<T: Config>(poll_id: T::PollIndex, count: u32) {
+	for i in 0..count {
+		let voter: T::AccountId = account("committer", i, 0);
+		Commitments::<T>::insert(poll_id, voter, VoteCommitment { hash: T::Hash::default(), votes: 1 });
+	}
+
+	PollDetailsOf::<T>::mutate(PollCurrency::Native, poll_id, |poll| {
+		if let Some(poll) = poll {
+			poll.commitments = count;
+		}
+	});
+}
+
+/// Fund `count` accounts delegating their native votes to `delegate`.
+fn fill_delegators<T: Config>(delegate: &T::AccountId, count: u32) {
+	for i in 0..count {
+		let delegator: T::AccountId = account("delegator", i, 0);
+		fund_native::<T>(&delegator);
+		Pallet::<T>::delegate(RawOrigin::Signed(delegator).into(), PollCurrency::Native, delegate.clone(), None).unwrap();
+	}
+}
+
+fn max_thresholds() -> PollThresholds {
+	PollThresholds { min_turnout: Some(u128::MAX), min_voters: Some(u32::MAX), approval: Some(Perbill::one()) }
+}
+
+/// Ranking of all `options` of a poll, from the last option to the first.
+fn full_ranking(options: u8) -> Vec<u8> {
+	(0..options).rev().collect()
+}
+
+/// Cast `count` rankings of all `options` in a ranked-choice poll from other accounts, each
+/// starting from a different option.
+fn fill_ranked_ballots<T: Config>(poll_id: T::PollIndex, options: u8, count: u32) {
+	for i in 0..count {
+		let voter: T::AccountId = account("ranker", i, 0);
+		let mut ranking: Vec<u8> = (0..options).collect();
+		ranking.rotate_left(i as usize % options as usize);
+
+		RankedVoters::<T>::insert(poll_id, &voter, ());
+		VotingOf::<T>::insert((voter, poll_id), AccountVote { ballot: Ballot::Ranked(ranking), votes: i as u128 + 1 });
+	}
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn create_poll(o: Linear<2, 255>, c: Linear<0, 1_000>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let start = <frame_system::Pallet<T>>::block_number();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			ipfs_cid(c),
+			o as u8,
+			BallotType::SingleChoice,
+			PollCurrency::Native,
+			start,
+			start + 10u32.into(),
+			Zero::zero(),
+			max_thresholds(),
+			Some(10u32.into())
+		);
+
+		assert!(PollDetailsOf::<T>::contains_key(PollCurrency::Native, PollCount::<T>::get()));
+	}
+
+	#[benchmark]
+	fn vote(o: Linear<2, 255>, d: Linear<0, 128>) {
+		let creator: T::AccountId = whitelisted_caller();
+		let poll_id = create_native_poll::<T>(&creator, o as u8, BallotType::RankedChoice, 0, None);
+
+		let caller: T::AccountId = account("voter", 0, 0);
+		fund_native::<T>(&caller);
+		fill_vote_locks::<T>(&caller, T::MaxVotes::get() - 1);
+		fill_delegators::<T>(&caller, d.min(T::MaxDelegators::get()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), PollCurrency::Native, poll_id, Ballot::Ranked(full_ranking(o as u8)), Conviction::Locked6x);
+
+		assert!(VotingOf::<T>::contains_key((caller, poll_id)));
+	}
+
+	#[benchmark]
+	fn enact_poll_end(o: Linear<2, 255>, b: Linear<0, 256>, c: Linear<0, 1_024>) {
+		let creator: T::AccountId = whitelisted_caller();
+		let poll_id = create_native_poll::<T>(&creator, o as u8, BallotType::RankedChoice, 0, Some(10));
+		// The last page of the last round, every ballot is counted for the last option left.
+		fill_ranked_ballots::<T>(poll_id, o as u8, b.min(T::MaxRankedBallotsPerBlock::get() - 1));
+		let mut tally = RankedTally::new(o as u8);
+		tally.eliminated.iter_mut().take(o as usize - 1).for_each(|eliminated| *eliminated = true);
+		RankedTallies::<T>::insert(poll_id, tally);
+		fill_commitments::<T>(poll_id, c.min(T::MaxCommitments::get()));
+		set_outcomes::<T>(poll_id, o as u8);
+		// Turnout high enough for the outcome of the winning option to be scheduled.
+		PollDetailsOf::<T>::mutate(PollCurrency::Native, poll_id, |poll| {
+			if let Some(poll) = poll {
+				poll.turnout = u128::MAX;
+			}
+		});
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, PollCurrency::Native, poll_id);
+
+		assert!(!PollDetailsOf::<T>::get(PollCurrency::Native, poll_id).unwrap().status.is_ongoing());
+	}
+
+	#[benchmark]
+	fn emergency_cancel() {
+		let caller: T::AccountId = whitelisted_caller();
+		let poll_id = create_native_poll::<T>(&caller, MAX_OPTIONS, BallotType::RankedChoice, 0, Some(10));
+		fill_commitments::<T>(poll_id, T::MaxCommitments::get());
+		set_outcomes::<T>(poll_id, MAX_OPTIONS);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), PollCurrency::Native, poll_id);
+
+		assert!(!PollDetailsOf::<T>::get(PollCurrency::Native, poll_id).unwrap().status.is_ongoing());
+	}
+
+	#[benchmark]
+	fn update_poll(o: Linear<2, 255>, c: Linear<0, 1_000>) {
 		let caller: T::AccountId = whitelisted_caller();
-	}: _(RawOrigin::Signed(caller), s)
-	verify {
-		assert_eq!(Something::<T>::get(), Some(s));
+		let poll_id = create_native_poll::<T>(&caller, 2, BallotType::SingleChoice, 1, None);
+		set_outcomes::<T>(poll_id, 2);
+		let start = <frame_system::Pallet<T>>::block_number() + 1u32.into();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			poll_id,
+			ipfs_cid(c),
+			o as u8,
+			BallotType::RankedChoice,
+			PollCurrency::Native,
+			start,
+			start + 10u32.into(),
+			Zero::zero(),
+			max_thresholds(),
+			Some(10u32.into())
+		);
+
+		assert_eq!(PollDetailsOf::<T>::get(PollCurrency::Native, poll_id).unwrap().options_count, o as u8);
+	}
+
+	#[benchmark]
+	fn unlock() {
+		let creator: T::AccountId = whitelisted_caller();
+		let caller: T::AccountId = account("voter", 0, 0);
+		fund_native::<T>(&caller);
+
+		let poll_id = create_native_poll::<T>(&creator, MAX_OPTIONS, BallotType::SingleChoice, 0, None);
+		Pallet::<T>::vote(RawOrigin::Signed(caller.clone()).into(), PollCurrency::Native, poll_id, Ballot::Single(0), Conviction::Locked6x).unwrap();
+		fill_vote_locks::<T>(&caller, T::MaxVotes::get() - 1);
+
+		Pallet::<T>::emergency_cancel(RawOrigin::Signed(creator).into(), PollCurrency::Native, poll_id).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), PollCurrency::Native, poll_id);
+
+		assert_eq!(VoteLocks::<T>::get(caller).len() as u32, T::MaxVotes::get() - 1);
+	}
+
+	#[benchmark]
+	fn change_vote(o: Linear<2, 255>) {
+		let creator: T::AccountId = whitelisted_caller();
+		let poll_id = create_native_poll::<T>(&creator, o as u8, BallotType::RankedChoice, 0, None);
+
+		let caller: T::AccountId = account("voter", 0, 0);
+		fund_native::<T>(&caller);
+		Pallet::<T>::vote(RawOrigin::Signed(caller.clone()).into(), PollCurrency::Native, poll_id, Ballot::Ranked(full_ranking(o as u8)), Conviction::Locked6x).unwrap();
+
+		let mut ranking = full_ranking(o as u8);
+		ranking.swap(0, 1);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), PollCurrency::Native, poll_id, Ballot::Ranked(ranking.clone()));
+
+		assert_eq!(VotingOf::<T>::get((caller, poll_id)).map(|v| v.ballot), Some(Ballot::Ranked(ranking)));
+	}
+
+	#[benchmark]
+	fn remove_vote(o: Linear<2, 255>, d: Linear<0, 128>) {
+		let creator: T::AccountId = whitelisted_caller();
+		let poll_id = create_native_poll::<T>(&creator, o as u8, BallotType::RankedChoice, 0, None);
+
+		let caller: T::AccountId = account("voter", 0, 0);
+		fund_native::<T>(&caller);
+		fill_delegators::<T>(&caller, d.min(T::MaxDelegators::get()));
+		Pallet::<T>::vote(RawOrigin::Signed(caller.clone()).into(), PollCurrency::Native, poll_id, Ballot::Ranked(full_ranking(o as u8)), Conviction::Locked6x).unwrap();
+		fill_vote_locks::<T>(&caller, T::MaxVotes::get() - 1);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), PollCurrency::Native, poll_id);
+
+		assert!(!VotingOf::<T>::contains_key((caller, poll_id)));
+	}
+
+	#[benchmark]
+	fn set_outcome() {
+		let caller: T::AccountId = whitelisted_caller();
+		let poll_id = create_native_poll::<T>(&caller, MAX_OPTIONS, BallotType::SingleChoice, 1, None);
+		PollDetailsOf::<T>::mutate(PollCurrency::Native, poll_id, |poll| {
+			if let Some(poll) = poll {
+				poll.thresholds = max_thresholds();
+			}
+		});
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), PollCurrency::Native, poll_id, MAX_OPTIONS - 1, Some(Box::new(outcome::<T>(poll_id))));
+
+		assert!(Outcomes::<T>::contains_key(poll_id, MAX_OPTIONS - 1));
+	}
+
+	#[benchmark]
+	fn commit_vote() {
+		let creator: T::AccountId = whitelisted_caller();
+		let poll_id = create_native_poll::<T>(&creator, MAX_OPTIONS, BallotType::SingleChoice, 0, Some(10));
+		fill_commitments::<T>(poll_id, T::MaxCommitments::get() - 1);
+
+		let caller: T::AccountId = account("voter", 0, 0);
+		fund_native::<T>(&caller);
+		fill_vote_locks::<T>(&caller, T::MaxVotes::get() - 1);
+		let commitment = Pallet::<T>::commitment_hash(&caller, poll_id, &Ballot::Single(0), &[0; 32]);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), PollCurrency::Native, poll_id, commitment, Conviction::Locked6x);
+
+		assert!(Commitments::<T>::contains_key(poll_id, caller));
+	}
+
+	#[benchmark]
+	fn reveal_vote(o: Linear<2, 255>) {
+		let creator: T::AccountId = whitelisted_caller();
+		let poll_id = create_native_poll::<T>(&creator, o as u8, BallotType::RankedChoice, 0, Some(10));
+
+		let caller: T::AccountId = account("voter", 0, 0);
+		fund_native::<T>(&caller);
+		let ballot = Ballot::Ranked(full_ranking(o as u8));
+		let commitment = Pallet::<T>::commitment_hash(&caller, poll_id, &ballot, &[0; 32]);
+		Pallet::<T>::commit_vote(RawOrigin::Signed(caller.clone()).into(), PollCurrency::Native, poll_id, commitment, Conviction::Locked6x).unwrap();
+
+		// Voting ended.
+		let now = <frame_system::Pallet<T>>::block_number();
+		<frame_system::Pallet<T>>::set_block_number(now + 10u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), PollCurrency::Native, poll_id, ballot, [0; 32]);
+
+		assert!(VotingOf::<T>::contains_key((caller, poll_id)));
+	}
+
+	#[benchmark]
+	fn delegate(p: Linear<0, 32>, d: Linear<0, 128>) {
+		let creator: T::AccountId = whitelisted_caller();
+
+		// The delegate is at the start of a chain as deep as it can be, and voted in `p` polls.
+		let delegates: Vec<T::AccountId> = (0..T::MaxDelegationDepth::get()).map(|i| account("delegate", i, 0)).collect();
+		for pair in delegates.windows(2) {
+			Pallet::<T>::delegate(RawOrigin::Signed(pair[0].clone()).into(), PollCurrency::Native, pair[1].clone(), None).unwrap();
+		}
+		fund_native::<T>(&delegates[0]);
+		for _ in 0..p.min(T::MaxVotes::get()) {
+			let poll_id = create_native_poll::<T>(&creator, 2, BallotType::SingleChoice, 0, None);
+			Pallet::<T>::vote(RawOrigin::Signed(delegates[0].clone()).into(), PollCurrency::Native, poll_id, Ballot::Single(0), Conviction::None).unwrap();
+		}
+
+		// The delegation replaces a previous one, the caller and `d` delegators are counted in
+		// each vote.
+		let caller: T::AccountId = account("caller", 0, 0);
+		fund_native::<T>(&caller);
+		fill_delegators::<T>(&caller, d.min(T::MaxDelegators::get() - 1));
+		Pallet::<T>::delegate(RawOrigin::Signed(caller.clone()).into(), PollCurrency::Native, creator, None).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), PollCurrency::Native, delegates[0].clone(), None);
+
+		assert_eq!(Delegations::<T>::get(caller, PollCurrency::Native), Some(delegates[0].clone()));
+	}
+
+	#[benchmark]
+	fn undelegate(p: Linear<0, 32>, d: Linear<0, 128>) {
+		let creator: T::AccountId = whitelisted_caller();
+		let delegate: T::AccountId = account("delegate", 0, 0);
+		fund_native::<T>(&delegate);
+
+		// The caller and `d` delegators are counted in the votes of the delegate in `p` polls.
+		let caller: T::AccountId = account("caller", 0, 0);
+		fund_native::<T>(&caller);
+		fill_delegators::<T>(&caller, d.min(T::MaxDelegators::get() - 1));
+		Pallet::<T>::delegate(RawOrigin::Signed(caller.clone()).into(), PollCurrency::Native, delegate.clone(), None).unwrap();
+		for _ in 0..p.min(T::MaxVotes::get()) {
+			let poll_id = create_native_poll::<T>(&creator, 2, BallotType::SingleChoice, 0, None);
+			Pallet::<T>::vote(RawOrigin::Signed(delegate.clone()).into(), PollCurrency::Native, poll_id, Ballot::Single(0), Conviction::None).unwrap();
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), PollCurrency::Native, None);
+
+		assert!(!Delegations::<T>::contains_key(caller, PollCurrency::Native));
+	}
+
+	#[benchmark]
+	fn snapshot_balance() {
+		let creator: T::AccountId = whitelisted_caller();
+		let caller: T::AccountId = account("voter", 0, 0);
+		fund_native::<T>(&caller);
+		fill_vote_locks::<T>(&caller, T::MaxVotes::get() - 1);
+
+		let poll_id = create_native_poll::<T>(&creator, 2, BallotType::SingleChoice, 1, None);
+		PollDetailsOf::<T>::mutate(PollCurrency::Native, poll_id, |poll| {
+			if let Some(poll) = poll {
+				poll.snapshot = true;
+			}
+		});
+
+		// The snapshot replaces a previous one.
+		Pallet::<T>::snapshot_balance(RawOrigin::Signed(caller.clone()).into(), PollCurrency::Native, poll_id).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), PollCurrency::Native, poll_id);
+
+		assert!(Snapshots::<T>::contains_key(poll_id, caller));
 	}
 
-	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -14,19 +14,25 @@ use frame_support::{
 	traits::{
 		schedule::{DispatchTime, Named as ScheduleNamed},
 		tokens::fungibles::{Balanced, Inspect, Transfer},
-		Currency, LockIdentifier, LockableCurrency, ReservableCurrency,
+		Currency, EnsureOrigin, LockIdentifier, LockableCurrency, ReservableCurrency, WithdrawReasons,
 	},
+	PalletId,
 };
 
+use sp_std::{boxed::Box, marker::PhantomData};
+
 use sp_runtime::SaturatedConversion;
 
 use codec::{Encode, Decode, HasCompact};
 
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
 use sp_runtime::{
 	traits::{
-		AtLeast32BitUnsigned, Dispatchable, Saturating, Zero,
+		AccountIdConversion, AtLeast32BitUnsigned, Dispatchable, Hash, IntegerSquareRoot, Saturating, Zero,
 	},
-	DispatchError};
+	DispatchError, PerThing, Perbill};
 
 #[cfg(test)]
 mod mock;
@@ -37,7 +43,19 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
 const POLLS_ID: LockIdentifier = *b"UnitPoll";
+/// Prefix of the scheduler names of poll outcomes.
+const OUTCOME_ID: [u8; 8] = *b"UnitOutc";
+/// Prefix of the scheduler names of the pages of ranked-choice tallies.
+const TALLY_ID: [u8; 8] = *b"UnitTall";
+
+/// The current storage version.
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 /// Balance type alias.
 pub(crate) type BalanceOf<T> =
@@ -54,11 +72,14 @@ pub(crate) type PollTypeOf<T> = PollDetails<
 	AssetIdOf<T>, 
 	BlockNumberOf<T>
 	>;
+/// Vote lock type alias.
+pub(crate) type VoteLockOf<T> = VoteLock<BalanceOf<T>, AssetIdOf<T>>;
 
 pub type IpfsCid = Vec<u8>;
 
 /// Details of a poll.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct PollDetails<Balance, AccountId, AssetId, BlockNumber> {
 	/// Account who created this poll.
 	pub created_by: AccountId,
@@ -66,6 +87,8 @@ pub struct PollDetails<Balance, AccountId, AssetId, BlockNumber> {
 	pub ipfs_cid: IpfsCid,
 	/// The number of poll options.
 	pub options_count: u8,
+	/// How ballots of this poll are cast and tallied.
+	pub ballot_type: BallotType,
 	/// Info regrading stake on poll options.
 	pub votes: Votes,
 	/// Currency of the poll.
@@ -74,6 +97,20 @@ pub struct PollDetails<Balance, AccountId, AssetId, BlockNumber> {
 	pub status: PollStatus<BlockNumber>,
 	// Min balance to be able to vote on a poll.
 	pub min_balance: Balance,
+	/// Conditions the poll must meet to not fail.
+	pub thresholds: PollThresholds,
+	/// Total votes of the accounts that voted.
+	pub turnout: u128,
+	/// Number of accounts that voted.
+	pub voters: u32,
+	/// Blocks after `end` in which committed votes are revealed, `None` when votes are cast in
+	/// the clear.
+	pub reveal_period: Option<BlockNumber>,
+	/// Number of committed votes not revealed yet.
+	pub commitments: u32,
+	/// Whether votes are weighted by the balance snapshots taken before `start`, instead of the
+	/// balance of the voter when voting.
+	pub snapshot: bool,
 }
 
 impl<Balance: AtLeast32BitUnsigned + Copy, AccountId: Clone + Eq, AssetId, BlockNumber>
@@ -84,26 +121,66 @@ impl<Balance: AtLeast32BitUnsigned + Copy, AccountId: Clone + Eq, AssetId, Block
 		created_by: AccountId,
 		ipfs_cid: IpfsCid,
 		options_count: u8,
+		ballot_type: BallotType,
 		currency: PollCurrency<AssetId>,
 		start: BlockNumber,
 		end: BlockNumber,
 		min_balance: Balance,
+		thresholds: PollThresholds,
+		reveal_period: Option<BlockNumber>,
+		snapshot: bool,
 	) -> Self {
 		Self {
 			created_by,
 			ipfs_cid,
 			options_count,
+			ballot_type,
 			votes: Votes::new(options_count),
 			currency,
 			status: PollStatus::Ongoing { start, end },
 			min_balance,
+			thresholds,
+			turnout: 0,
+			voters: 0,
+			reveal_period,
+			commitments: 0,
+			snapshot,
 		}
 	}
 
+	/// Whether the turnout of the poll meets its quorum.
+	pub fn has_quorum(&self) -> bool {
+		self.thresholds.min_turnout.map_or(true, |min_turnout| self.turnout >= min_turnout) &&
+			self.thresholds.min_voters.map_or(true, |min_voters| self.voters >= min_voters)
+	}
+
+	/// Whether `winning_option` has the approval threshold of the poll in `result`.
+	///
+	/// The share is taken of the turnout for approval ballots, where every option can get the
+	/// votes of each account, and of the votes counted in the result otherwise.
+	pub fn has_approval(&self, winning_option: Option<u8>, result: &PollResult) -> bool {
+		let approval = match self.thresholds.approval {
+			Some(approval) => approval,
+			None => return true,
+		};
+		let winning_option = match winning_option {
+			Some(winning_option) => winning_option,
+			None => return true,
+		};
+
+		let total = match self.ballot_type {
+			BallotType::Approval => self.turnout,
+			_ => result.votes.0.iter().fold(0u128, |total, votes| total.saturating_add(*votes)),
+		};
+
+		result.votes.0[winning_option as usize] >= approval.mul_ceil(total)
+	}
+
 }
 
 /// A vote for a poll.
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct Votes(pub Vec<u128>);
 
 impl Votes {
@@ -133,10 +210,245 @@ impl Votes {
 		}
 	}
 
+	/// Add the votes of a ballot to the options.
+	pub fn add(&mut self, option_votes: &[(u8, u128)]) {
+		for (option, votes) in option_votes {
+			self.0[*option as usize] = self.0[*option as usize].saturating_add(*votes);
+		}
+	}
+
+	/// Back the votes of a ballot out of the options.
+	pub fn sub(&mut self, option_votes: &[(u8, u128)]) {
+		for (option, votes) in option_votes {
+			self.0[*option as usize] = self.0[*option as usize].saturating_sub(*votes);
+		}
+	}
+
+	/// Tally ranked `ballots` by instant-runoff, returns the winning option, if any, and the
+	/// votes of each round.
+	///
+	/// Each round counts every ballot for its most preferred option still in the race. An
+	/// option with more than half of the votes counted wins, otherwise the option with the
+	/// fewest votes is eliminated, the highest index on a tie, and options without votes are
+	/// eliminated all at once. If every remaining option is tied there is no winner.
+	pub fn instant_runoff(options_count: u8, ballots: &[(Vec<u8>, u128)]) -> (Option<u8>, Vec<Votes>) {
+		let mut eliminated = vec![false; options_count as usize];
+		let mut rounds = Vec::new();
+
+		loop {
+			let mut round = Votes::new(options_count);
+			for (ranking, votes) in ballots {
+				round.add_ranking(ranking, *votes, &eliminated);
+			}
+
+			rounds.push(round.clone());
+
+			if let Some(winning_option) = round.runoff(&mut eliminated) {
+				return (winning_option, rounds)
+			}
+		}
+	}
+
+	/// Count the votes of a ranking for its most preferred option not `eliminated`.
+	pub fn add_ranking(&mut self, ranking: &[u8], votes: u128, eliminated: &[bool]) {
+		if let Some(option) = ranking.iter().find(|option| !eliminated[**option as usize]) {
+			self.add(&[(*option, votes)]);
+		}
+	}
+
+	/// Decide an instant-runoff round with these votes, returns the winning option, if any, once
+	/// the tally is over, otherwise eliminates options for the next round.
+	pub fn runoff(&self, eliminated: &mut [bool]) -> Option<Option<u8>> {
+		let total = self.0.iter().fold(0u128, |total, votes| total.saturating_add(*votes));
+		let continuing: Vec<usize> = (0..self.0.len()).filter(|option| !eliminated[*option]).collect();
+
+		if total.is_zero() {
+			return Some(None)
+		}
+
+		if let Some(winner) = continuing.iter().find(|option| self.0[**option].saturating_mul(2) > total) {
+			return Some(Some(*winner as u8))
+		}
+
+		let fewest = continuing.iter().map(|option| self.0[*option]).min().unwrap_or_default();
+		let lowest: Vec<usize> = continuing.iter().copied().filter(|option| self.0[*option] == fewest).collect();
+
+		if lowest.len() == continuing.len() {
+			return Some(None)
+		}
+
+		if fewest.is_zero() {
+			lowest.iter().for_each(|option| eliminated[*option] = true);
+		} else if let Some(option) = lowest.last() {
+			eliminated[*option] = true;
+		}
+
+		None
+	}
+
 }
 
-/// Status of a poll, present, cancelled, or past.
+/// Poll as returned by the runtime API.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct PollInfo<PollIndex, AccountId, AssetId, Balance, BlockNumber> {
+	/// Id of the poll.
+	pub poll_id: PollIndex,
+	/// Details of the poll, with its tally.
+	pub details: PollDetails<Balance, AccountId, AssetId, BlockNumber>,
+	/// Vote of the account the poll was queried for, if any.
+	pub vote: Option<AccountVote>,
+}
+
+/// Status of a poll to filter polls by.
 #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum PollStatusFilter {
+	Ongoing,
+	Cancelled,
+	Finished,
+	Failed,
+}
+
+/// Conditions a poll must meet when it ends, otherwise it fails.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct PollThresholds {
+	/// Minimum total votes of the accounts that voted.
+	pub min_turnout: Option<u128>,
+	/// Minimum number of accounts that voted.
+	pub min_voters: Option<u32>,
+	/// Minimum share of the votes the winning option must have.
+	pub approval: Option<Perbill>,
+}
+
+/// Breakdown of the votes of a finished poll.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct PollResult {
+	/// Final votes of every option.
+	pub votes: Votes,
+	/// Votes of every option in each instant-runoff round, empty for other ballot types.
+	pub rounds: Vec<Votes>,
+}
+
+/// Progress of the instant-runoff tally of a ranked-choice poll, counted in pages of ballots.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct RankedTally {
+	/// Options eliminated in the rounds decided.
+	pub eliminated: Vec<bool>,
+	/// Votes counted so far in the current round.
+	pub round: Votes,
+	/// Votes of every round decided.
+	pub rounds: Vec<Votes>,
+	/// Raw storage key of the last ranked voter counted in the current round.
+	pub cursor: Option<Vec<u8>>,
+	/// Number of pages counted.
+	pub pages: u32,
+}
+
+impl RankedTally {
+	/// A tally of a poll with `options_count` options, no option eliminated yet.
+	pub fn new(options_count: u8) -> Self {
+		Self {
+			eliminated: vec![false; options_count as usize],
+			round: Votes::new(options_count),
+			rounds: Vec::new(),
+			cursor: None,
+			pages: 0,
+		}
+	}
+}
+
+/// How ballots of a poll are cast and tallied.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum BallotType {
+	/// A single option, the most voted option wins.
+	SingleChoice,
+	/// Any number of options, each approved option gets all the votes.
+	Approval,
+	/// Options ranked by preference, tallied by instant-runoff.
+	RankedChoice,
+	/// Votes spent as credits across options, each option gets the square root of its credits.
+	Quadratic,
+}
+
+impl Default for BallotType {
+	fn default() -> Self {
+		BallotType::SingleChoice
+	}
+}
+
+/// A ballot cast in a poll, matching the ballot type of the poll.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Ballot {
+	/// The option voted for.
+	Single(u8),
+	/// The options approved.
+	Approval(Vec<u8>),
+	/// The options ranked from most to least preferred.
+	Ranked(Vec<u8>),
+	/// The credits spent on each option.
+	Quadratic(Vec<(u8, u128)>),
+}
+
+impl Ballot {
+	/// The ballot type this ballot can be cast in.
+	pub fn ballot_type(&self) -> BallotType {
+		match self {
+			Ballot::Single(_) => BallotType::SingleChoice,
+			Ballot::Approval(_) => BallotType::Approval,
+			Ballot::Ranked(_) => BallotType::RankedChoice,
+			Ballot::Quadratic(_) => BallotType::Quadratic,
+		}
+	}
+
+	/// The options on the ballot.
+	pub fn options(&self) -> Vec<u8> {
+		match self {
+			Ballot::Single(option) => vec![*option],
+			Ballot::Approval(options) | Ballot::Ranked(options) => options.clone(),
+			Ballot::Quadratic(credits) => credits.iter().map(|(option, _)| *option).collect(),
+		}
+	}
+
+	/// Votes the ballot adds to each option while the poll is ongoing, given the votes of the
+	/// account. Ranked ballots only count for their first preference until the poll is tallied.
+	pub fn option_votes(&self, votes: u128) -> Vec<(u8, u128)> {
+		match self {
+			Ballot::Single(option) => vec![(*option, votes)],
+			Ballot::Approval(options) => options.iter().map(|option| (*option, votes)).collect(),
+			Ballot::Ranked(ranking) => ranking.first().map(|option| vec![(*option, votes)]).unwrap_or_default(),
+			Ballot::Quadratic(credits) =>
+				credits.iter().map(|(option, credits)| (*option, credits.integer_sqrt())).collect(),
+		}
+	}
+}
+
+/// The vote of an account in a poll.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AccountVote {
+	/// The ballot cast.
+	pub ballot: Ballot,
+	/// The votes of the account the ballot was cast with, so they can be backed out exactly.
+	pub votes: u128,
+}
+
+/// A vote committed to a commit-reveal poll, counted once it is revealed.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct VoteCommitment<Hash> {
+	/// Hash of the account, poll, ballot and salt of the vote, see `Pallet::commitment_hash`.
+	pub hash: Hash,
+	/// The votes of the account, fixed when the vote was committed.
+	pub votes: u128,
+}
+
+/// Status of a poll, present, cancelled, or past.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum PollStatus<BlockNumber> {
 	/// Poll is happening, the args are the block number at which it will start and end.
 	Ongoing {
@@ -153,8 +465,10 @@ pub enum PollStatus<BlockNumber> {
 		winning_option: Option<u8>,
 		/// When voting on this poll ended.
 		end: BlockNumber,
+		/// The tally of the poll.
+		result: PollResult,
 	},
-	/// State for situations where some condition of succes was not met
+	/// Poll ended at the given block without meeting its thresholds.
 	Failed(BlockNumber),
 }
 
@@ -165,9 +479,21 @@ impl<BlockNumber> PollStatus<BlockNumber> {
 			_ => false,
 		}
 	}
+
+	/// Whether the status passes `filter`.
+	pub fn matches(&self, filter: PollStatusFilter) -> bool {
+		match (self, filter) {
+			(PollStatus::Ongoing { .. }, PollStatusFilter::Ongoing) |
+			(PollStatus::Cancelled(_), PollStatusFilter::Cancelled) |
+			(PollStatus::Finished { .. }, PollStatusFilter::Finished) |
+			(PollStatus::Failed(_), PollStatusFilter::Failed) => true,
+			_ => false,
+		}
+	}
 }
 
 #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum PollCurrency<AssetId> {
 	/// AssetId from the Assets Pallet.
 	Asset(AssetId),
@@ -175,6 +501,117 @@ pub enum PollCurrency<AssetId> {
 	Native,
 }
 
+/// How long the balance of a vote stays locked after the poll ends, in exchange for more votes.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum Conviction {
+	/// 1x votes, unlocked when the poll ends.
+	None,
+	/// 2x votes, locked for 1 period after the poll ends.
+	Locked2x,
+	/// 3x votes, locked for 2 periods after the poll ends.
+	Locked3x,
+	/// 4x votes, locked for 4 periods after the poll ends.
+	Locked4x,
+	/// 5x votes, locked for 8 periods after the poll ends.
+	Locked5x,
+	/// 6x votes, locked for 16 periods after the poll ends.
+	Locked6x,
+}
+
+impl Default for Conviction {
+	fn default() -> Self {
+		Conviction::None
+	}
+}
+
+impl Conviction {
+	/// Votes given by `balance` with this conviction.
+	pub fn votes(&self, balance: u128) -> u128 {
+		let multiplier: u128 = match self {
+			Conviction::None => 1,
+			Conviction::Locked2x => 2,
+			Conviction::Locked3x => 3,
+			Conviction::Locked4x => 4,
+			Conviction::Locked5x => 5,
+			Conviction::Locked6x => 6,
+		};
+		balance.saturating_mul(multiplier)
+	}
+
+	/// Number of `VoteLockingPeriod`s the balance stays locked after the poll ends.
+	pub fn lock_periods(&self) -> u32 {
+		match self {
+			Conviction::None => 0,
+			Conviction::Locked2x => 1,
+			Conviction::Locked3x => 2,
+			Conviction::Locked4x => 4,
+			Conviction::Locked5x => 8,
+			Conviction::Locked6x => 16,
+		}
+	}
+}
+
+/// Balance locked by a vote.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct VoteLock<Balance, AssetId> {
+	/// Currency of the poll voted on.
+	pub currency: PollCurrency<AssetId>,
+	/// Balance locked, or held by the pallet account for assets.
+	pub amount: Balance,
+	/// Conviction of the vote.
+	pub conviction: Conviction,
+}
+
+/// Origins of this pallet.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum RawOrigin<AssetId> {
+	/// The holders of a poll currency, dispatching the outcome of a poll they voted.
+	TokenHolders(PollCurrency<AssetId>),
+}
+
+/// Ensure the origin is the token holders of a poll currency, succeeds with the currency.
+pub struct EnsureTokenHolders<T>(PhantomData<T>);
+
+impl<T: Config, O> EnsureOrigin<O> for EnsureTokenHolders<T>
+where
+	O: Into<Result<RawOrigin<AssetIdOf<T>>, O>> + From<RawOrigin<AssetIdOf<T>>>,
+{
+	type Success = PollCurrency<AssetIdOf<T>>;
+
+	fn try_origin(o: O) -> Result<Self::Success, O> {
+		o.into().map(|o| match o {
+			RawOrigin::TokenHolders(currency) => currency,
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<O, ()> {
+		Ok(O::from(RawOrigin::TokenHolders(PollCurrency::Native)))
+	}
+}
+
+/// Ensure the origin is the token holders of the native currency.
+pub struct EnsureNativeTokenHolders<T>(PhantomData<T>);
+
+impl<T: Config, O> EnsureOrigin<O> for EnsureNativeTokenHolders<T>
+where
+	O: Into<Result<RawOrigin<AssetIdOf<T>>, O>> + From<RawOrigin<AssetIdOf<T>>>,
+{
+	type Success = ();
+
+	fn try_origin(o: O) -> Result<Self::Success, O> {
+		o.into().and_then(|o| match o {
+			RawOrigin::TokenHolders(PollCurrency::Native) => Ok(()),
+			o => Err(O::from(o)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<O, ()> {
+		Ok(O::from(RawOrigin::TokenHolders(PollCurrency::Native)))
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -183,8 +620,13 @@ pub mod pallet {
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	/// The origin of this pallet.
+	#[pallet::origin]
+	pub type Origin<T> = RawOrigin<AssetIdOf<T>>;
+
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -214,7 +656,7 @@ pub mod pallet {
 			+ TypeInfo;
 
 		/// Overarching type of all pallets origins.
-		type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>>;
+		type PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>> + From<RawOrigin<AssetIdOf<Self>>>;
 
 		/// The overarching call type for Scheduler.
 		type PollCall: Parameter + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin> + From<Call<Self>>;
@@ -222,6 +664,45 @@ pub mod pallet {
 		/// The Scheduler.
 		type Scheduler: ScheduleNamed<Self::BlockNumber, Self::PollCall, Self::PalletsOrigin>;
 
+		/// The polls pallet id, its account holds the assets voted with until they are unlocked.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Max number of polls an account can have balance locked in at the same time.
+		#[pallet::constant]
+		type MaxVotes: Get<u32>;
+
+		/// Blocks a conviction lock period lasts.
+		#[pallet::constant]
+		type VoteLockingPeriod: Get<Self::BlockNumber>;
+
+		/// Max number of ranked ballots counted in a block when tallying a ranked-choice poll.
+		#[pallet::constant]
+		type MaxRankedBallotsPerBlock: Get<u32>;
+
+		/// Max encoded length of the call dispatched when an option of a poll wins.
+		#[pallet::constant]
+		type MaxOutcomeLen: Get<u32>;
+
+		/// Min turnout, as a share of the issuance of the poll currency, a poll must require and
+		/// reach for the outcome of its winning option to be dispatched.
+		#[pallet::constant]
+		type MinOutcomeTurnout: Get<Perbill>;
+
+		/// Max number of committed votes waiting to be revealed in a poll.
+		#[pallet::constant]
+		type MaxCommitments: Get<u32>;
+
+		/// Max number of accounts delegating to an account, and of delegators counted in a vote.
+		#[pallet::constant]
+		type MaxDelegators: Get<u32>;
+
+		/// Max number of delegations between a delegator and the account voting for it.
+		#[pallet::constant]
+		type MaxDelegationDepth: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	/// The number of polls that have been made so far.
@@ -241,11 +722,149 @@ pub mod pallet {
 		PollDetails<BalanceOf<T>, T::AccountId, AssetIdOf<T>, BlockNumberOf<T>>,
 	>;
 
+	/// Polls created by an account with their currency, keyed by the order they were created in.
+	#[pallet::storage]
+	#[pallet::getter(fn polls_by_creator)]
+	pub type PollsByCreator<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		u32,
+		(PollCurrency<AssetIdOf<T>>, T::PollIndex),
+	>;
+
+	/// The number of polls an account has created.
+	#[pallet::storage]
+	#[pallet::getter(fn creator_poll_count)]
+	pub type CreatorPollCount<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Polls in a currency, keyed by the order they were created in.
+	#[pallet::storage]
+	#[pallet::getter(fn polls_by_currency)]
+	pub type PollsByCurrency<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, PollCurrency<AssetIdOf<T>>, Twox64Concat, u32, T::PollIndex>;
+
+	/// The number of polls created in a currency.
+	#[pallet::storage]
+	#[pallet::getter(fn currency_poll_count)]
+	pub type CurrencyPollCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, PollCurrency<AssetIdOf<T>>, u32, ValueQuery>;
+
 	/// All votes for a particular voter. So to avoid voting twice.
 	#[pallet::storage]
 	#[pallet::getter(fn voting_of)]
 	pub type VotingOf<T: Config> =
-		StorageMap<_, Blake2_128Concat, (T::AccountId, T::PollIndex), u8>;
+		StorageMap<_, Blake2_128Concat, (T::AccountId, T::PollIndex), AccountVote>;
+
+	/// Accounts that cast a ranked ballot in a poll, their ballot is in `VotingOf`.
+	#[pallet::storage]
+	#[pallet::getter(fn ranked_voter_of)]
+	pub type RankedVoters<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::PollIndex, Blake2_128Concat, T::AccountId, ()>;
+
+	/// Tallies of ranked-choice polls in progress, until the instant-runoff is over.
+	#[pallet::storage]
+	#[pallet::getter(fn ranked_tally_of)]
+	pub type RankedTallies<T: Config> = StorageMap<_, Blake2_128Concat, T::PollIndex, RankedTally>;
+
+	/// Votes committed to commit-reveal polls, until they are revealed or the poll is tallied.
+	#[pallet::storage]
+	#[pallet::getter(fn commitment_of)]
+	pub type Commitments<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::PollIndex,
+		Blake2_128Concat,
+		T::AccountId,
+		VoteCommitment<T::Hash>,
+	>;
+
+	/// Calls dispatched from the token holders of the poll currency when an option wins.
+	#[pallet::storage]
+	#[pallet::getter(fn outcome_of)]
+	pub type Outcomes<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::PollIndex, Blake2_128Concat, u8, <T as Config>::PollCall>;
+
+	/// Account each account delegates its votes in a currency to.
+	#[pallet::storage]
+	#[pallet::getter(fn delegation_of)]
+	pub type Delegations<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		PollCurrency<AssetIdOf<T>>,
+		T::AccountId,
+	>;
+
+	/// Account each account delegates its votes in a poll to, over its delegation in the currency.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_delegation_of)]
+	pub type PollDelegations<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::PollIndex, T::AccountId>;
+
+	/// Accounts delegating their votes in a currency to an account.
+	#[pallet::storage]
+	#[pallet::getter(fn delegators_of)]
+	pub type DelegatorsOf<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		PollCurrency<AssetIdOf<T>>,
+		BoundedVec<T::AccountId, T::MaxDelegators>,
+		ValueQuery,
+	>;
+
+	/// Accounts delegating their votes in a poll to an account.
+	#[pallet::storage]
+	#[pallet::getter(fn poll_delegators_of)]
+	pub type PollDelegatorsOf<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::PollIndex,
+		BoundedVec<T::AccountId, T::MaxDelegators>,
+		ValueQuery,
+	>;
+
+	/// Delegators counted in the vote of an account in a poll, with their votes.
+	#[pallet::storage]
+	#[pallet::getter(fn delegated_votes_of)]
+	pub type DelegatedVotes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::PollIndex,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<(T::AccountId, u128), T::MaxDelegators>,
+		ValueQuery,
+	>;
+
+	/// The account whose vote in a poll counts the votes of a delegator.
+	#[pallet::storage]
+	#[pallet::getter(fn counted_by)]
+	pub type CountedBy<T: Config> =
+		StorageMap<_, Blake2_128Concat, (T::AccountId, T::PollIndex), T::AccountId>;
+
+	/// Balance of an account snapshotted before a snapshot poll started, locked until it ends.
+	#[pallet::storage]
+	#[pallet::getter(fn snapshot_of)]
+	pub type Snapshots<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::PollIndex, Blake2_128Concat, T::AccountId, BalanceOf<T>>;
+
+	/// Balance locked by the votes of an account, until it is unlocked once each poll ends.
+	#[pallet::storage]
+	#[pallet::getter(fn vote_locks_of)]
+	pub type VoteLocks<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<(T::PollIndex, VoteLockOf<T>), T::MaxVotes>,
+		ValueQuery,
+	>;
 
 
 	// Pallets use events to inform users when important changes are made.
@@ -253,14 +872,63 @@ pub mod pallet {
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
+		/// An account delegated its votes in a currency, or in a poll when `poll_id` is set.
+		Delegated {
+			delegator: T::AccountId,
+			delegate: T::AccountId,
+			currency: PollCurrency<AssetIdOf<T>>,
+			poll_id: Option<T::PollIndex>,
+		},
+		/// An account revoked the delegation of its votes in a currency, or in a poll.
+		Undelegated { delegator: T::AccountId, currency: PollCurrency<AssetIdOf<T>>, poll_id: Option<T::PollIndex> },
 		/// A poll was created.
-		PollCreated { currency: PollCurrency<AssetIdOf<T>>, poll_id: T::PollIndex, cid: IpfsCid, creator: T::AccountId },
+		PollCreated {
+			currency: PollCurrency<AssetIdOf<T>>,
+			poll_id: T::PollIndex,
+			cid: IpfsCid,
+			creator: T::AccountId,
+			thresholds: PollThresholds,
+		},
 		/// An account has voted in a poll.
-		Voted { voter: T::AccountId, currency: PollCurrency<AssetIdOf<T>>, poll_id: T::PollIndex, vote: u8 },
+		Voted { voter: T::AccountId, currency: PollCurrency<AssetIdOf<T>>, poll_id: T::PollIndex, vote: Ballot },
+		/// An account has committed a secret vote to a poll.
+		VoteCommitted { voter: T::AccountId, currency: PollCurrency<AssetIdOf<T>>, poll_id: T::PollIndex },
+		/// An account has revealed its committed vote, which is now counted.
+		VoteRevealed { voter: T::AccountId, currency: PollCurrency<AssetIdOf<T>>, poll_id: T::PollIndex, vote: Ballot },
+		/// A committed vote was not revealed before the poll was tallied and is not counted.
+		CommitmentDropped { voter: T::AccountId, currency: PollCurrency<AssetIdOf<T>>, poll_id: T::PollIndex },
+		/// An account has replaced its ballot in a poll.
+		VoteChanged { voter: T::AccountId, currency: PollCurrency<AssetIdOf<T>>, poll_id: T::PollIndex, vote: Ballot },
+		/// An account has removed its vote from a poll.
+		VoteRemoved { voter: T::AccountId, currency: PollCurrency<AssetIdOf<T>>, poll_id: T::PollIndex },
+		/// The balance an account votes with in a snapshot poll was snapshotted and locked.
+		BalanceSnapshotted {
+			who: T::AccountId,
+			currency: PollCurrency<AssetIdOf<T>>,
+			poll_id: T::PollIndex,
+			amount: BalanceOf<T>,
+		},
+		/// The balance an account voted with was unlocked.
+		Unlocked { who: T::AccountId, currency: PollCurrency<AssetIdOf<T>>, poll_id: T::PollIndex, amount: BalanceOf<T> },
 		/// A poll was finished.
 		Finished { currency: PollCurrency<AssetIdOf<T>>, poll_id: T::PollIndex },
+		/// The outcome of an option of a poll was set, or cleared.
+		OutcomeSet { currency: PollCurrency<AssetIdOf<T>>, poll_id: T::PollIndex, option: u8 },
+		/// The outcome of the winning option of a poll was scheduled.
+		OutcomeScheduled { currency: PollCurrency<AssetIdOf<T>>, poll_id: T::PollIndex, option: u8 },
+		/// The outcome of the winning option of a poll was dropped, the scheduler rejected it or
+		/// the turnout of the poll was too low.
+		OutcomeNotScheduled { currency: PollCurrency<AssetIdOf<T>>, poll_id: T::PollIndex, option: u8 },
+		/// A poll ended without meeting its thresholds.
+		PollFailed { currency: PollCurrency<AssetIdOf<T>>, poll_id: T::PollIndex, thresholds: PollThresholds },
 		/// A poll was updated
-		PollUpdated { currency: PollCurrency<AssetIdOf<T>>, poll_id: T::PollIndex, cid: IpfsCid, creator: T::AccountId },
+		PollUpdated {
+			currency: PollCurrency<AssetIdOf<T>>,
+			poll_id: T::PollIndex,
+			cid: IpfsCid,
+			creator: T::AccountId,
+			thresholds: PollThresholds,
+		},
 		/// A poll was cancelled
 		Cancelled { currency: PollCurrency<AssetIdOf<T>>, poll_id: T::PollIndex },
 	}
@@ -300,6 +968,50 @@ pub mod pallet {
 		AlreadyVoted,
 		/// Poll options should be more than ohe
 		InvalidPollOptions,
+		/// An account can only have balance locked in `MaxVotes` polls.
+		TooManyVotes,
+		/// The account has no balance locked in this poll.
+		NotLocked,
+		/// Balance can't be unlocked while the poll is ongoing.
+		PollStillOngoing,
+		/// The conviction lock of the vote has not expired yet.
+		VoteStillLocked,
+		/// The account has not voted in this poll.
+		NotVoted,
+		/// The ballot does not match the ballot type of the poll.
+		InvalidBallotType,
+		/// A ballot should have at least one option, and each option only once.
+		InvalidBallot,
+		/// More credits were spent on a quadratic ballot than the votes of the account.
+		InsufficientCredits,
+		/// The outcome call is longer than `MaxOutcomeLen`.
+		OutcomeTooLong,
+		/// Votes in this poll are cast in the clear, not committed.
+		NotCommitReveal,
+		/// Votes in a commit-reveal poll are committed and then revealed.
+		CommitRevealPoll,
+		/// Committed votes are revealed after voting ends, within the reveal period of the poll.
+		NotRevealPeriod,
+		/// The account has not committed a vote to this poll.
+		NotCommitted,
+		/// The ballot and salt do not match the committed vote.
+		InvalidReveal,
+		/// A poll can only have `MaxCommitments` committed votes waiting to be revealed.
+		TooManyCommitments,
+		/// An account can't delegate to itself, directly or through other delegates.
+		DelegationCycle,
+		/// The delegate is more than `MaxDelegationDepth` delegations away from a voter.
+		DelegationTooDeep,
+		/// An account can only have `MaxDelegators` delegators in a currency or poll.
+		TooManyDelegators,
+		/// The account does not delegate its votes in this currency or poll.
+		NotDelegating,
+		/// Votes in this poll are weighted by the balance of the voter when voting.
+		NotSnapshotPoll,
+		/// The account did not snapshot its balance before the poll started.
+		NoSnapshot,
+		/// A poll with an outcome must require a turnout of `MinOutcomeTurnout` of the issuance.
+		OutcomeTurnoutTooLow,
 	}
 
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -314,20 +1026,30 @@ pub mod pallet {
 		/// 
 		///	- `ipfs_cid`: The IPFS CID of the poll.
 		///	- `options_count`: The number of poll options.
+		///	- `ballot_type`: How ballots of the poll are cast and tallied.
 		///	- `currency`: Currency of the poll.
 		///	- `start`: When voting on this poll will begin.
 		///	- `end`:  When voting on this poll will end.
 		///	- `min_balance`: Minimum balance required to vote. 
+		///	- `thresholds`: Quorum and approval the poll must meet to not fail.
+		///	- `reveal_period`: Blocks after `end` to reveal committed votes in, `None` to vote in
+		///	  the clear.
+		///	- `snapshot`: Whether votes are weighted by the balance snapshotted before `start`,
+		///	  which can't be changed once the poll is created.
 		#[pallet::call_index(2)]
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::create_poll(*options_count as u32, ipfs_cid.len() as u32))]
 		pub fn create_poll(
 			origin: OriginFor<T>,
 			ipfs_cid: IpfsCid,
 			options_count: u8,
+			ballot_type: BallotType,
 			currency: PollCurrency<AssetIdOf<T>>,
 			start: BlockNumberOf<T>,
 			end: BlockNumberOf<T>,
 			min_balance: BalanceOf<T>,
+			thresholds: PollThresholds,
+			reveal_period: Option<BlockNumberOf<T>>,
+			snapshot: bool,
 		) -> DispatchResult {
 
 			let who = ensure_signed(origin)?;
@@ -337,17 +1059,21 @@ pub mod pallet {
 				who.clone(),
 				ipfs_cid.clone(),
 				options_count,
+				ballot_type,
 				currency.clone(),
 				start,
 				end,
 				min_balance,
+				thresholds.clone(),
+				reveal_period,
+				snapshot,
 			);
 
 			// Call inner function.
 			let poll_id = Self::try_create_poll(poll)?;
 
 			// Emit an event.
-			Self::deposit_event(Event::PollCreated { currency, poll_id, cid: ipfs_cid, creator: who });
+			Self::deposit_event(Event::PollCreated { currency, poll_id, cid: ipfs_cid, creator: who, thresholds });
 
 			Ok(())
 		}
@@ -356,21 +1082,32 @@ pub mod pallet {
 		///
 		/// The dispatch origin of this call must be _Signed_.
 		///
+		/// The whole balance of the poll currency is locked until the poll ends, native balance
+		/// with a lock and assets held by the pallet account. Higher convictions multiply the
+		/// votes and keep the balance locked for longer, see `unlock`. The same balance can vote
+		/// in other polls while it is locked, the locks of the polls overlap.
+		///
+		/// The balance of the accounts delegating to the voter that did not vote is counted in the
+		/// vote too, without conviction and without being locked, see `delegate`. Voting directly
+		/// takes the votes of the account out of the vote of its delegate.
+		///
 		///	- `poll_currency`: Currency of the poll.
 		/// - `poll_id`: The index of the poll to vote for.
-		/// - `vote`: The index of the option to vote for.
+		/// - `vote`: The ballot, matching the ballot type of the poll.
+		/// - `conviction`: The conviction of the vote.
 		#[pallet::call_index(3)]
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::vote(u8::MAX as u32, T::MaxDelegators::get()))]
 		pub fn vote(
 			origin: OriginFor<T>,
 			poll_currency: PollCurrency<AssetIdOf<T>>,
 			poll_id: T::PollIndex,
-			vote: u8,
+			vote: Ballot,
+			conviction: Conviction,
 		) -> DispatchResult {
 
 			let who = ensure_signed(origin)?;
 			// Call inner function.
-			Self::try_vote(&who, poll_currency, poll_id, vote.clone())?;
+			Self::try_vote(&who, poll_currency, poll_id, vote.clone(), conviction)?;
 			// Emit an event.
 			Self::deposit_event(Event::<T>::Voted { voter: who, currency: poll_currency,  poll_id: poll_id, vote: vote });
 
@@ -381,10 +1118,17 @@ pub mod pallet {
 		///
 		/// The dispatch origin of this call must be _ROOT_.
 		///
+		/// Ranked-choice polls are tallied in pages of `MaxRankedBallotsPerBlock` ballots, one
+		/// page per block, and stay ongoing until the instant-runoff is over.
+		///
 		///	- `poll_currency`: Currency of the poll.
 		/// - `poll_id`: The index of the poll to enact end.
 		#[pallet::call_index(4)]
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::enact_poll_end(
+			u8::MAX as u32,
+			T::MaxRankedBallotsPerBlock::get(),
+			T::MaxCommitments::get(),
+		))]
 		pub fn enact_poll_end(
 			origin: OriginFor<T>,
 			poll_currency: PollCurrency<AssetIdOf<T>>, 
@@ -393,10 +1137,15 @@ pub mod pallet {
 
 			ensure_root(origin)?;
 
-			Self::do_enact_poll_end(poll_currency, poll_id)?;
+			let poll = Self::do_enact_poll_end(poll_currency, poll_id)?;
 
-			// Emit an event.
-			Self::deposit_event(Event::Finished { currency: poll_currency, poll_id });
+			// Emit an event, unless the tally goes on in the next block.
+			match poll.status {
+				PollStatus::Ongoing { .. } => {},
+				PollStatus::Failed(_) =>
+					Self::deposit_event(Event::PollFailed { currency: poll_currency, poll_id, thresholds: poll.thresholds }),
+				_ => Self::deposit_event(Event::Finished { currency: poll_currency, poll_id }),
+			}
 
 			Ok(())
 		}
@@ -408,7 +1157,7 @@ pub mod pallet {
 		///	- `poll_currency`: Currency of the poll.
 		/// - `poll_id`: The index of the poll to enact end.
 		#[pallet::call_index(5)]
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::emergency_cancel())]
 		pub fn emergency_cancel(
 			origin: OriginFor<T>,
 			poll_currency: PollCurrency<AssetIdOf<T>>, 
@@ -431,21 +1180,28 @@ pub mod pallet {
 		/// - `poll_id`: The index of the poll to enact end.
 		/// - `ipfs_cid`: The IPFS CID of the poll.
 		///	- `options_count`: The number of poll options.
+		///	- `ballot_type`: How ballots of the poll are cast and tallied.
 		///	- `currency`: Currency of the poll.
 		///	- `start`: When voting on this poll will begin.
 		///	- `end`: When voting on this poll will end.
 		///	- `min_balance`: Minimum balance required to vote.
+		///	- `thresholds`: Quorum and approval the poll must meet to not fail.
+		///	- `reveal_period`: Blocks after `end` to reveal committed votes in, `None` to vote in
+		///	  the clear.
 		#[pallet::call_index(6)]
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::update_poll(*options_count as u32, ipfs_cid.len() as u32))]
 		pub fn update_poll(
 			origin: OriginFor<T>,
 			poll_id: T::PollIndex,
 			ipfs_cid: IpfsCid,
 			options_count: u8,
+			ballot_type: BallotType,
 			currency: PollCurrency<AssetIdOf<T>>,
 			start: BlockNumberOf<T>,
 			end: BlockNumberOf<T>,
 			min_balance: BalanceOf<T>,
+			thresholds: PollThresholds,
+			reveal_period: Option<BlockNumberOf<T>>,
 		) -> DispatchResult {
 
 			let who = ensure_signed(origin)?;
@@ -457,48 +1213,314 @@ pub mod pallet {
 				poll_id.clone(),
 				ipfs_cid.clone(),
 				options_count,
+				ballot_type,
 				start,
 				end,
 				min_balance,
+				thresholds.clone(),
+				reveal_period,
 			)?;
 
 			// Emit an event.
-			Self::deposit_event(Event::PollUpdated { currency, poll_id, cid: ipfs_cid, creator: who });
+			Self::deposit_event(Event::PollUpdated { currency, poll_id, cid: ipfs_cid, creator: who, thresholds });
 
 			Ok(())
 		}
-	}
-}
-
-impl<T: Config> Pallet<T> {
-	
-	/// Actually create a poll.
-	fn try_create_poll(poll: PollTypeOf<T>) -> Result<T::PollIndex, DispatchError> {
-
-		// Validate poll options is a number grater than 1
-		ensure!(poll.options_count > 1, Error::<T>::InvalidPollOptions);
-
-		let (start, end) = match poll.status {
-			PollStatus::Ongoing { start, end } => (start, end),
-			_ => return Err(Error::<T>::InvalidPollDetails.into()),
-		};
-
-		// Ensure start and end blocks are valid.
-		let now = <frame_system::Pallet<T>>::block_number();
-		ensure!(start >= now && end > now && end > start, Error::<T>::InvalidPollPeriod);
 
-		// Ensure currency asset exists.
-		if let PollCurrency::Asset(asset_id) = poll.currency {
-			let total_issuance = <T::Fungibles as Inspect<T::AccountId>>::total_issuance(asset_id);
-			ensure!(total_issuance > BalanceOf::<T>::zero(), Error::<T>::InvalidPollCurrency);
+		/// Unlock the balance voted with in a poll.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// The balance is unlocked once the poll ends, plus the lock periods of the conviction
+		/// of the vote. Votes in cancelled polls are unlocked right away.
+		///
+		///	- `poll_currency`: Currency of the poll.
+		/// - `poll_id`: The index of the poll voted on.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::unlock())]
+		pub fn unlock(
+			origin: OriginFor<T>,
+			poll_currency: PollCurrency<AssetIdOf<T>>,
+			poll_id: T::PollIndex,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// Call inner function.
+			let amount = Self::try_unlock(&who, poll_currency, poll_id)?;
+			// Emit an event.
+			Self::deposit_event(Event::<T>::Unlocked { who, currency: poll_currency, poll_id, amount });
+			Ok(())
 		}
 
-		// Get next poll_id from storage.
-		let mut poll_id = PollCount::<T>::get();
-
-		poll_id.saturating_inc();
+		/// Replace the ballot of a vote while the poll is ongoing.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// The new ballot is cast with the votes of the first one, the balance locked stays the same.
+		///
+		///	- `poll_currency`: Currency of the poll.
+		/// - `poll_id`: The index of the poll voted on.
+		/// - `vote`: The new ballot, matching the ballot type of the poll.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::change_vote(u8::MAX as u32))]
+		pub fn change_vote(
+			origin: OriginFor<T>,
+			poll_currency: PollCurrency<AssetIdOf<T>>,
+			poll_id: T::PollIndex,
+			vote: Ballot,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// Call inner function.
+			Self::try_change_vote(&who, poll_currency, poll_id, vote.clone())?;
+			// Emit an event.
+			Self::deposit_event(Event::<T>::VoteChanged { voter: who, currency: poll_currency, poll_id, vote });
+			Ok(())
+		}
+
+		/// Remove a vote while the poll is ongoing.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// The votes are subtracted from the option and the balance voted with is unlocked,
+		/// whatever the conviction of the vote. The account and the delegators counted in its vote
+		/// are counted in the vote its own delegation leads to, if any.
+		///
+		///	- `poll_currency`: Currency of the poll.
+		/// - `poll_id`: The index of the poll voted on.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::remove_vote(u8::MAX as u32, T::MaxDelegators::get()))]
+		pub fn remove_vote(
+			origin: OriginFor<T>,
+			poll_currency: PollCurrency<AssetIdOf<T>>,
+			poll_id: T::PollIndex,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// Call inner function.
+			Self::try_remove_vote(&who, poll_currency, poll_id)?;
+			// Emit an event.
+			Self::deposit_event(Event::<T>::VoteRemoved { voter: who, currency: poll_currency, poll_id });
+			Ok(())
+		}
+
+		/// Set the call dispatched when an option of a poll wins.
+		///
+		/// Only poll creator can call this function, before the poll starts.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// The call is scheduled once the poll finishes, from the token holders of the poll
+		/// currency, see `EnsureTokenHolders`. Failed polls dispatch nothing.
+		///
+		/// The `min_turnout` threshold of the poll must be at least `MinOutcomeTurnout` of the
+		/// issuance of its currency, and can't be lowered below it while an outcome is set.
+		///
+		///	- `poll_currency`: Currency of the poll.
+		/// - `poll_id`: The index of the poll.
+		/// - `option`: The index of the option.
+		/// - `call`: The call to dispatch, `None` to clear it.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::set_outcome())]
+		pub fn set_outcome(
+			origin: OriginFor<T>,
+			poll_currency: PollCurrency<AssetIdOf<T>>,
+			poll_id: T::PollIndex,
+			option: u8,
+			call: Option<Box<<T as Config>::PollCall>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// Call inner function.
+			Self::try_set_outcome(&who, poll_currency, poll_id, option, call)?;
+			// Emit an event.
+			Self::deposit_event(Event::<T>::OutcomeSet { currency: poll_currency, poll_id, option });
+			Ok(())
+		}
+
+		/// Commit a secret vote to a commit-reveal poll.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// The balance is locked and the votes fixed as with `vote`, but the ballot is only
+		/// counted once revealed with `reveal_vote` after voting ends. Votes not revealed within
+		/// the reveal period of the poll are dropped.
+		///
+		///	- `poll_currency`: Currency of the poll.
+		/// - `poll_id`: The index of the poll to vote for.
+		/// - `commitment`: Hash of the account, the poll, the ballot and a salt, see
+		///   `commitment_hash`.
+		/// - `conviction`: The conviction of the vote.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::commit_vote())]
+		pub fn commit_vote(
+			origin: OriginFor<T>,
+			poll_currency: PollCurrency<AssetIdOf<T>>,
+			poll_id: T::PollIndex,
+			commitment: T::Hash,
+			conviction: Conviction,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// Call inner function.
+			Self::try_commit_vote(&who, poll_currency, poll_id, commitment, conviction)?;
+			// Emit an event.
+			Self::deposit_event(Event::<T>::VoteCommitted { voter: who, currency: poll_currency, poll_id });
+			Ok(())
+		}
+
+		/// Reveal the vote committed to a commit-reveal poll, so it is counted.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// Votes are revealed after voting ends, within the reveal period of the poll.
+		///
+		///	- `poll_currency`: Currency of the poll.
+		/// - `poll_id`: The index of the poll voted on.
+		/// - `vote`: The ballot committed to, matching the ballot type of the poll.
+		/// - `salt`: The salt the ballot was committed with.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::reveal_vote(u8::MAX as u32))]
+		pub fn reveal_vote(
+			origin: OriginFor<T>,
+			poll_currency: PollCurrency<AssetIdOf<T>>,
+			poll_id: T::PollIndex,
+			vote: Ballot,
+			salt: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// Call inner function.
+			Self::try_reveal_vote(&who, poll_currency, poll_id, vote.clone(), salt)?;
+			// Emit an event.
+			Self::deposit_event(Event::<T>::VoteRevealed { voter: who, currency: poll_currency, poll_id, vote });
+			Ok(())
+		}
+
+		/// Delegate the votes of the account in a currency, or in one of its polls.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// When the delegate, or the account it delegates to in turn, votes in a single-choice,
+		/// approval or ranked-choice poll with votes in the clear, the balance of the account is
+		/// counted in that vote, unless the account voted directly. The balance is not locked, the
+		/// votes weigh the balance when counted, or its snapshot in snapshot polls. Delegators more
+		/// than `MaxDelegationDepth` delegations away from the voter are not counted. A delegation
+		/// in a poll takes over the one in its currency, and replaces a previous delegation.
+		///
+		/// The account is recounted in the open polls the delegation changes the vote of.
+		///
+		///	- `currency`: Currency of the polls to delegate in.
+		/// - `delegate`: The account to delegate to.
+		/// - `poll_id`: The poll to delegate in, `None` for every poll in the currency.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::delegate(
+			poll_id.map_or(2 * T::MaxDelegationDepth::get() * T::MaxVotes::get(), |_| 1),
+			T::MaxDelegators::get(),
+		))]
+		pub fn delegate(
+			origin: OriginFor<T>,
+			currency: PollCurrency<AssetIdOf<T>>,
+			delegate: T::AccountId,
+			poll_id: Option<T::PollIndex>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// Call inner function.
+			Self::try_delegate(&who, currency, &delegate, poll_id)?;
+			// Emit an event.
+			Self::deposit_event(Event::<T>::Delegated { delegator: who, delegate, currency, poll_id });
+			Ok(())
+		}
+
+		/// Revoke the delegation of the votes of the account in a currency, or in a poll.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// The account, and the delegators counted through it, are taken out of the votes of the
+		/// delegate in the open polls.
+		///
+		///	- `currency`: Currency of the polls delegated in.
+		/// - `poll_id`: The poll delegated in, `None` for the delegation in the currency.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::undelegate(
+			poll_id.map_or(T::MaxDelegationDepth::get() * T::MaxVotes::get(), |_| 1),
+			T::MaxDelegators::get(),
+		))]
+		pub fn undelegate(
+			origin: OriginFor<T>,
+			currency: PollCurrency<AssetIdOf<T>>,
+			poll_id: Option<T::PollIndex>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// Call inner function.
+			Self::try_undelegate(&who, currency, poll_id)?;
+			// Emit an event.
+			Self::deposit_event(Event::<T>::Undelegated { delegator: who, currency, poll_id });
+			Ok(())
+		}
+
+		/// Snapshot and lock the balance the account votes with in a snapshot poll.
+		///
+		/// The dispatch origin of this call must be _Signed_.
+		///
+		/// Votes in a snapshot poll, including the votes delegated to other accounts, are weighted
+		/// by the balance snapshotted before the poll starts, so balance moved while voting is
+		/// open has no effect. The balance stays locked until the poll ends, and snapshotting again
+		/// before `start` replaces the previous snapshot.
+		///
+		///	- `poll_currency`: Currency of the poll.
+		/// - `poll_id`: The poll to snapshot the balance for.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::snapshot_balance())]
+		pub fn snapshot_balance(
+			origin: OriginFor<T>,
+			poll_currency: PollCurrency<AssetIdOf<T>>,
+			poll_id: T::PollIndex,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			// Call inner function.
+			let amount = Self::try_snapshot_balance(&who, poll_currency, poll_id)?;
+			// Emit an event.
+			Self::deposit_event(Event::<T>::BalanceSnapshotted { who, currency: poll_currency, poll_id, amount });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	
+	/// Actually create a poll.
+	fn try_create_poll(poll: PollTypeOf<T>) -> Result<T::PollIndex, DispatchError> {
+
+		// Validate poll options is a number grater than 1
+		ensure!(poll.options_count > 1, Error::<T>::InvalidPollOptions);
+
+		let (start, end) = match poll.status {
+			PollStatus::Ongoing { start, end } => (start, end),
+			_ => return Err(Error::<T>::InvalidPollDetails.into()),
+		};
+
+		// Ensure start and end blocks are valid.
+		let now = <frame_system::Pallet<T>>::block_number();
+		ensure!(start >= now && end > now && end > start, Error::<T>::InvalidPollPeriod);
+		ensure!(poll.reveal_period.map_or(true, |period| !period.is_zero()), Error::<T>::InvalidPollPeriod);
+
+		// Ensure currency asset exists.
+		if let PollCurrency::Asset(asset_id) = poll.currency {
+			let total_issuance = <T::Fungibles as Inspect<T::AccountId>>::total_issuance(asset_id);
+			ensure!(total_issuance > BalanceOf::<T>::zero(), Error::<T>::InvalidPollCurrency);
+		}
+
+		// Get next poll_id from storage.
+		let mut poll_id = PollCount::<T>::get();
+
+		poll_id.saturating_inc();
+
+		// Index the poll after the previous polls of its creator and currency.
+		let creator_index = CreatorPollCount::<T>::get(&poll.created_by);
+		let currency_index = CurrencyPollCount::<T>::get(poll.currency);
+		let creator_count = creator_index.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
+		let currency_count = currency_index.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
 
 		PollDetailsOf::<T>::insert(poll.currency, poll_id, poll.clone());
+		PollsByCreator::<T>::insert(&poll.created_by, creator_index, (poll.currency, poll_id));
+		CreatorPollCount::<T>::insert(&poll.created_by, creator_count);
+		PollsByCurrency::<T>::insert(poll.currency, currency_index, poll_id);
+		CurrencyPollCount::<T>::insert(poll.currency, currency_count);
 
 		// Updates poll count.
 		PollCount::<T>::put(poll_id);
@@ -506,7 +1528,7 @@ impl<T: Config> Pallet<T> {
 		// Actually schedule end of the poll.
 		if T::Scheduler::schedule_named(
 			(POLLS_ID, poll.currency, poll_id).encode(),
-			DispatchTime::At(end),
+			DispatchTime::At(Self::tally_block(end, poll.reveal_period)),
 			None,
 			63,
 			frame_system::RawOrigin::Root.into(),
@@ -529,79 +1551,162 @@ impl<T: Config> Pallet<T> {
 		poll_id: T::PollIndex,
 		ipfs_cid: IpfsCid,
 		options_count: u8,
+		ballot_type: BallotType,
 		start: BlockNumberOf<T>,
 		end: BlockNumberOf<T>,
 		min_balance: BalanceOf<T>,
+		thresholds: PollThresholds,
+		reveal_period: Option<BlockNumberOf<T>>,
 	) -> DispatchResult {
 
 		// Get poll details.
 		let mut poll = PollDetailsOf::<T>::get(poll_currency, poll_id).ok_or(Error::<T>::PollNotFound)?;
 
 		// Ensure poll did not started yet
-		if let PollStatus::Ongoing { start, .. } = poll.status {
-			let now = <frame_system::Pallet<T>>::block_number();
-			ensure!(now < start, Error::<T>::PollAlreadyStarted);
-		}
+		let now = <frame_system::Pallet<T>>::block_number();
+		let scheduled_end = match poll.status {
+			PollStatus::Ongoing { start, end } => {
+				ensure!(now < start, Error::<T>::PollAlreadyStarted);
+				end
+			},
+			_ => return Err(Error::<T>::PollAlreadyFinished.into()),
+		};
 
 		// Ensure poll creator is the same as who.
 		ensure!(poll.created_by == *who, Error::<T>::NotPollCreator);
 
+		// Ensure start and end blocks are valid.
+		ensure!(start >= now && end > now && end > start, Error::<T>::InvalidPollPeriod);
+		ensure!(reveal_period.map_or(true, |period| !period.is_zero()), Error::<T>::InvalidPollPeriod);
+
+		// Ensure the outcomes of the poll keep their turnout.
+		if Outcomes::<T>::iter_prefix(poll_id).next().is_some() {
+			ensure!(Self::requires_outcome_turnout(poll_currency, &thresholds), Error::<T>::OutcomeTurnoutTooLow);
+		}
+
+		// Move the end of the poll when it is tallied at another block.
+		let tally_block = Self::tally_block(end, reveal_period);
+		if Self::tally_block(scheduled_end, poll.reveal_period) != tally_block {
+			T::Scheduler::reschedule_named((POLLS_ID, poll_currency, poll_id).encode(), DispatchTime::At(tally_block))
+				.map_err(|_| Error::<T>::InvalidPollPeriod)?;
+		}
+
 		// Update poll details.
 		poll.ipfs_cid = ipfs_cid.clone();
 		poll.options_count = options_count;
+		poll.ballot_type = ballot_type;
+		poll.votes = Votes::new(options_count);
 		poll.status = PollStatus::Ongoing { start, end };
 		poll.min_balance = min_balance;
+		poll.thresholds = thresholds;
+		poll.reveal_period = reveal_period;
 
 		// Update poll details in storage.
 		PollDetailsOf::<T>::insert(poll_currency, poll_id, poll.clone());
 
-		// Emit an event.
-		Self::deposit_event(Event::PollUpdated { currency: poll_currency, poll_id, cid: ipfs_cid, creator: who.clone() });
-
 		Ok(())
 	}
 
 	// 
 	fn check_balance(
-	 	who: &T::AccountId,
-	 	currency: PollCurrency<AssetIdOf<T>>,
 	 	poll: &PollTypeOf<T>,
+	 	balance: BalanceOf<T>,
 	) -> bool {
 
 		// Get min balance from poll.
 		let min_balance = poll.min_balance;
 
-	 	match currency {
-	 		
-	 		PollCurrency::Native => min_balance < T::Currency::free_balance(who).into(),
-	 		PollCurrency::Asset(asset_id) =>
-	 		min_balance < <T::Fungibles as Inspect<T::AccountId>>::balance(asset_id, who).into(),
-	 	}
+		min_balance < balance
 	}
 
-	fn vote_weight(
+	fn voting_balance(
 		who: &T::AccountId,
 		currency: PollCurrency<AssetIdOf<T>>,
-   	) -> u128 {
+   	) -> BalanceOf<T> {
 
 		match currency {
 			
-			PollCurrency::Native => Self::balance_to_u128_saturated(T::Currency::free_balance(who)),
-			PollCurrency::Asset(asset_id) =>
-			Self::balance_to_u128_saturated(<T::Fungibles as Inspect<T::AccountId>>::balance(asset_id, who)),
+			PollCurrency::Native => T::Currency::free_balance(who),
+			PollCurrency::Asset(asset_id) => <T::Fungibles as Inspect<T::AccountId>>::balance(asset_id, who)
+				.saturating_add(Self::escrowed_asset(asset_id, &VoteLocks::<T>::get(who))),
 		}
    	}
 
+	/// Balance `who` votes with in a poll, the balance snapshotted before `start` in snapshot polls.
+	fn voting_power(
+		who: &T::AccountId,
+		poll: &PollTypeOf<T>,
+		poll_id: T::PollIndex,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		match poll.snapshot {
+			true => Snapshots::<T>::get(poll_id, who).ok_or_else(|| Error::<T>::NoSnapshot.into()),
+			false => Ok(Self::voting_balance(who, poll.currency)),
+		}
+	}
+
+	/// Lock the balance `who` votes with, in snapshot polls it is locked already and only the
+	/// conviction of the lock is set.
+	fn lock_voting_power(
+		who: &T::AccountId,
+		poll: &PollTypeOf<T>,
+		poll_id: T::PollIndex,
+		amount: BalanceOf<T>,
+		conviction: Conviction,
+	) -> DispatchResult {
+		match poll.snapshot {
+			true => Self::set_lock_conviction(who, poll.currency, poll_id, conviction),
+			false => Self::lock_vote(who, poll_id, VoteLock { currency: poll.currency, amount, conviction }),
+		}
+	}
+
+	/// Release the balance `who` voted with, in snapshot polls it stays locked as the snapshot
+	/// without conviction, so the account can vote again.
+	fn unlock_voting_power(who: &T::AccountId, poll: &PollTypeOf<T>, poll_id: T::PollIndex) -> DispatchResult {
+		match poll.snapshot {
+			true => Self::set_lock_conviction(who, poll.currency, poll_id, Conviction::None),
+			false => Self::remove_lock(who, poll.currency, poll_id),
+		}
+	}
+
+	/// Snapshot and lock the balance of an account in a snapshot poll that did not start yet.
+	fn try_snapshot_balance(
+		who: &T::AccountId,
+		poll_currency: PollCurrency<AssetIdOf<T>>,
+		poll_id: T::PollIndex,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let poll = Self::poll_status(poll_currency, poll_id)?;
+		ensure!(poll.snapshot, Error::<T>::NotSnapshotPoll);
+
+		// Snapshots are taken before voting opens.
+		if let PollStatus::Ongoing { start, .. } = poll.status {
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(now < start, Error::<T>::PollAlreadyStarted);
+		}
+
+		// Release the previous snapshot to replace it.
+		if Snapshots::<T>::contains_key(poll_id, who) {
+			Self::remove_lock(who, poll_currency, poll_id)?;
+		}
+
+		let amount = Self::voting_balance(who, poll_currency);
+		ensure!(!amount.is_zero(), Error::<T>::InsufficientFunds);
+
+		// Lock the balance snapshotted until the poll ends.
+		Self::lock_vote(who, poll_id, VoteLock { currency: poll_currency, amount, conviction: Conviction::None })?;
+		Snapshots::<T>::insert(poll_id, who, amount);
+
+		Ok(amount)
+	}
+
 	fn try_vote(
 		who: &T::AccountId,
 		poll_currency: PollCurrency<AssetIdOf<T>>,
 		poll_id: T::PollIndex,
-		votes: u8,
+		ballot: Ballot,
+		conviction: Conviction,
 	) -> DispatchResult {
 		let mut poll = Self::poll_status(poll_currency, poll_id)?;
-
-		// Check if Votes has valid number of options.
-		ensure!(votes <= poll.options_count, Error::<T>::InvalidPollVote);
+		ensure!(poll.reveal_period.is_none(), Error::<T>::CommitRevealPoll);
 
 		// Ensure start and end blocks are valid.
 		if let PollStatus::Ongoing { start, .. } = poll.status {
@@ -609,31 +1714,630 @@ impl<T: Config> Pallet<T> {
 			ensure!(start <= now, Error::<T>::PollNotStarted);
 		}
 
+		// check account has not already voted
+		ensure!(
+		 	!VotingOf::<T>::contains_key((who, poll_id)),
+		 	Error::<T>::AlreadyVoted,
+		);
+
+		// Voting directly overrides the vote of the delegate.
+		Self::uncount_delegator(who, &mut poll, poll_id)?;
+
 		// check account has enough balance to vote
+		let balance = Self::voting_power(who, &poll, poll_id)?;
 		ensure!(
-			Self::check_balance(who, poll.currency, &poll),
+			Self::check_balance(&poll, balance),
 			Error::<T>::InsufficientFunds,
 		);
 
-		// check account has not already voted
+		// Get vote weight
+		let vote_weight = conviction.votes(Self::balance_to_u128_saturated(balance));
+
+		// Check the ballot can be cast in the poll.
+		Self::validate_ballot(&poll, &ballot, vote_weight)?;
+
+		// Lock the balance voted with
+		Self::lock_voting_power(who, &poll, poll_id, balance, conviction)?;
+
+		// Count the votes delegated to the account.
+		let mut delegated = BoundedVec::<_, T::MaxDelegators>::default();
+		let delegators = Self::delegators_in_poll(who, poll.currency, poll_id);
+		let delegated_votes =
+			Self::count_delegators(who, &poll, poll_id, delegators, T::MaxDelegationDepth::get(), &mut delegated);
+		let votes = vote_weight.saturating_add(delegated_votes);
+
+		// Add vote weight to chosen options
+		poll.votes.add(&ballot.option_votes(votes));
+		poll.turnout = poll.turnout.saturating_add(votes);
+		poll.voters = poll.voters.saturating_add(1).saturating_add(delegated.len() as u32);
+
+		// Update poll in storage.
+		if !delegated.is_empty() {
+			DelegatedVotes::<T>::insert(poll_id, who, delegated);
+		}
+		Self::insert_vote(who, poll_id, AccountVote { ballot, votes });
+		PollDetailsOf::<T>::insert(poll.currency, poll_id, poll);
+		Ok(())
+	}
+
+	/// Replace the ballot of an account, cast with the same votes.
+	fn try_change_vote(
+		who: &T::AccountId,
+		poll_currency: PollCurrency<AssetIdOf<T>>,
+		poll_id: T::PollIndex,
+		ballot: Ballot,
+	) -> DispatchResult {
+		let mut poll = Self::poll_status(poll_currency, poll_id)?;
+		ensure!(poll.reveal_period.is_none(), Error::<T>::CommitRevealPoll);
+
+		VotingOf::<T>::try_mutate((who, poll_id), |account_vote| -> DispatchResult {
+			let account_vote = account_vote.as_mut().ok_or(Error::<T>::NotVoted)?;
+
+			// Check the ballot can be cast in the poll.
+			Self::validate_ballot(&poll, &ballot, account_vote.votes)?;
+
+			// Move the vote weight to the new options.
+			poll.votes.sub(&account_vote.ballot.option_votes(account_vote.votes));
+			poll.votes.add(&ballot.option_votes(account_vote.votes));
+			account_vote.ballot = ballot;
+
+			// Update poll in storage.
+			PollDetailsOf::<T>::insert(poll_currency, poll_id, poll);
+			Ok(())
+		})
+	}
+
+	/// Back the votes of an account out of a poll and release the balance voted with.
+	fn try_remove_vote(
+		who: &T::AccountId,
+		poll_currency: PollCurrency<AssetIdOf<T>>,
+		poll_id: T::PollIndex,
+	) -> DispatchResult {
+		let mut poll = Self::poll_status(poll_currency, poll_id)?;
+		ensure!(poll.reveal_period.is_none(), Error::<T>::CommitRevealPoll);
+
+		let account_vote = VotingOf::<T>::take((who, poll_id)).ok_or(Error::<T>::NotVoted)?;
+		RankedVoters::<T>::remove(poll_id, who);
+
+		// Subtract vote weight from the chosen options.
+		poll.votes.sub(&account_vote.ballot.option_votes(account_vote.votes));
+		poll.turnout = poll.turnout.saturating_sub(account_vote.votes);
+		poll.voters = poll.voters.saturating_sub(1);
+
+		// Release the balance voted with.
+		Self::unlock_voting_power(who, &poll, poll_id)?;
+
+		// Take out the delegators counted.
+		let delegated = DelegatedVotes::<T>::take(poll_id, who);
+		poll.voters = poll.voters.saturating_sub(delegated.len() as u32);
+		for (delegator, _) in delegated {
+			CountedBy::<T>::remove((&delegator, poll_id));
+		}
+
+		// Update poll in storage.
+		PollDetailsOf::<T>::insert(poll_currency, poll_id, poll);
+
+		// The account and its delegators count in the vote its delegation leads to instead.
+		Self::recount_delegator(who, poll_currency, poll_id)
+	}
+
+	/// Lock the balance of a vote committed to a commit-reveal poll and keep its hash.
+	fn try_commit_vote(
+		who: &T::AccountId,
+		poll_currency: PollCurrency<AssetIdOf<T>>,
+		poll_id: T::PollIndex,
+		hash: T::Hash,
+		conviction: Conviction,
+	) -> DispatchResult {
+		let mut poll = Self::poll_status(poll_currency, poll_id)?;
+		ensure!(poll.reveal_period.is_some(), Error::<T>::NotCommitReveal);
+
+		// Ensure voting is open, the poll stays ongoing while votes are revealed.
+		if let PollStatus::Ongoing { start, end } = poll.status {
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(start <= now, Error::<T>::PollNotStarted);
+			ensure!(now < end, Error::<T>::PollAlreadyFinished);
+		}
+
+		// check account has enough balance to vote
+		let balance = Self::voting_power(who, &poll, poll_id)?;
 		ensure!(
-		 	!VotingOf::<T>::contains_key((who, poll_id)),
-		 	Error::<T>::AlreadyVoted,
+			Self::check_balance(&poll, balance),
+			Error::<T>::InsufficientFunds,
 		);
 
-		// Get vote weight
-		let vote_weight = Self::vote_weight(who, poll.currency);
+		// check account has not already voted
+		ensure!(!Commitments::<T>::contains_key(poll_id, who), Error::<T>::AlreadyVoted);
+		ensure!(poll.commitments < T::MaxCommitments::get(), Error::<T>::TooManyCommitments);
+
+		// Votes are fixed when committed.
+		let votes = conviction.votes(Self::balance_to_u128_saturated(balance));
 
-		// Add vote weight to chosen option
-		poll.votes.0[votes as usize] += vote_weight;
+		// Lock the balance voted with
+		Self::lock_voting_power(who, &poll, poll_id, balance, conviction)?;
 
+		poll.commitments = poll.commitments.saturating_add(1);
 
 		// Update poll in storage.
-		VotingOf::<T>::insert((who, poll_id), votes);
+		Commitments::<T>::insert(poll_id, who, VoteCommitment { hash, votes });
 		PollDetailsOf::<T>::insert(poll.currency, poll_id, poll);
 		Ok(())
 	}
 
+	/// Count a committed vote once its ballot and salt match the commitment.
+	fn try_reveal_vote(
+		who: &T::AccountId,
+		poll_currency: PollCurrency<AssetIdOf<T>>,
+		poll_id: T::PollIndex,
+		ballot: Ballot,
+		salt: [u8; 32],
+	) -> DispatchResult {
+		let mut poll = Self::poll_status(poll_currency, poll_id)?;
+		let reveal_period = poll.reveal_period.ok_or(Error::<T>::NotCommitReveal)?;
+
+		// Ensure voting ended and the reveal period did not.
+		if let PollStatus::Ongoing { end, .. } = poll.status {
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(end <= now && now < Self::tally_block(end, Some(reveal_period)), Error::<T>::NotRevealPeriod);
+		}
+
+		let commitment = Commitments::<T>::get(poll_id, who).ok_or(Error::<T>::NotCommitted)?;
+		ensure!(Self::commitment_hash(who, poll_id, &ballot, &salt) == commitment.hash, Error::<T>::InvalidReveal);
+
+		// Check the ballot can be cast in the poll.
+		Self::validate_ballot(&poll, &ballot, commitment.votes)?;
+
+		// Add the committed votes to the chosen options.
+		poll.votes.add(&ballot.option_votes(commitment.votes));
+		poll.turnout = poll.turnout.saturating_add(commitment.votes);
+		poll.voters = poll.voters.saturating_add(1);
+		poll.commitments = poll.commitments.saturating_sub(1);
+
+		// Update poll in storage.
+		Commitments::<T>::remove(poll_id, who);
+		Self::insert_vote(who, poll_id, AccountVote { ballot, votes: commitment.votes });
+		PollDetailsOf::<T>::insert(poll_currency, poll_id, poll);
+		Ok(())
+	}
+
+	/// Hash `who` commits a ballot to a commit-reveal poll with.
+	///
+	/// The account and poll are hashed with the ballot and salt, so a commitment copied from
+	/// another account, or another poll, can't be revealed.
+	pub fn commitment_hash(who: &T::AccountId, poll_id: T::PollIndex, ballot: &Ballot, salt: &[u8; 32]) -> T::Hash {
+		T::Hashing::hash_of(&(who, poll_id, ballot, salt))
+	}
+
+	/// Drop the committed votes of a poll that were not revealed, their balance stays locked
+	/// as for any other vote.
+	fn drop_commitments(poll_currency: PollCurrency<AssetIdOf<T>>, poll_id: T::PollIndex) {
+		for (voter, _) in Commitments::<T>::drain_prefix(poll_id) {
+			Self::deposit_event(Event::CommitmentDropped { voter, currency: poll_currency, poll_id });
+		}
+	}
+
+	/// Block a poll ending at `end` is tallied at, after the reveal period of commit-reveal polls.
+	fn tally_block(end: BlockNumberOf<T>, reveal_period: Option<BlockNumberOf<T>>) -> BlockNumberOf<T> {
+		end.saturating_add(reveal_period.unwrap_or_else(Zero::zero))
+	}
+
+	/// Delegate the votes of `who` in a currency, or in a poll, to `delegate`.
+	fn try_delegate(
+		who: &T::AccountId,
+		currency: PollCurrency<AssetIdOf<T>>,
+		delegate: &T::AccountId,
+		poll_id: Option<T::PollIndex>,
+	) -> DispatchResult {
+		if let Some(poll_id) = poll_id {
+			Self::poll_status(currency, poll_id)?;
+		}
+
+		// Check the chain of delegates from `delegate` neither loops back nor gets too deep.
+		let mut next = Some(delegate.clone());
+		for _ in 0..T::MaxDelegationDepth::get() {
+			match next {
+				Some(account) => {
+					ensure!(account != *who, Error::<T>::DelegationCycle);
+					next = match poll_id {
+						Some(poll_id) => Self::delegate_in_poll(&account, currency, poll_id),
+						None => Delegations::<T>::get(&account, currency),
+					};
+				},
+				None => break,
+			}
+		}
+		ensure!(next.is_none(), Error::<T>::DelegationTooDeep);
+
+		// Replace the previous delegation, if any.
+		let previous = Self::remove_delegation(who, currency, poll_id);
+
+		match poll_id {
+			Some(poll_id) => {
+				PollDelegatorsOf::<T>::try_append(delegate, poll_id, who)
+					.map_err(|_| Error::<T>::TooManyDelegators)?;
+				PollDelegations::<T>::insert(who, poll_id, delegate);
+			},
+			None => {
+				DelegatorsOf::<T>::try_append(delegate, currency, who)
+					.map_err(|_| Error::<T>::TooManyDelegators)?;
+				Delegations::<T>::insert(who, currency, delegate);
+			},
+		}
+
+		Self::recount_delegation(who, currency, poll_id, [previous, Some(delegate.clone())])
+	}
+
+	/// Revoke the delegation of the votes of `who` in a currency, or in a poll.
+	fn try_undelegate(
+		who: &T::AccountId,
+		currency: PollCurrency<AssetIdOf<T>>,
+		poll_id: Option<T::PollIndex>,
+	) -> DispatchResult {
+		let previous = Self::remove_delegation(who, currency, poll_id).ok_or(Error::<T>::NotDelegating)?;
+		Self::recount_delegation(who, currency, poll_id, [Some(previous), None])
+	}
+
+	/// Remove the delegation of `who` in a currency, or in a poll, returns the delegate if there
+	/// was one.
+	fn remove_delegation(
+		who: &T::AccountId,
+		currency: PollCurrency<AssetIdOf<T>>,
+		poll_id: Option<T::PollIndex>,
+	) -> Option<T::AccountId> {
+		match poll_id {
+			Some(poll_id) => PollDelegations::<T>::take(who, poll_id).map(|delegate| {
+				PollDelegatorsOf::<T>::mutate(&delegate, poll_id, |delegators| delegators.retain(|d| d != who));
+				delegate
+			}),
+			None => Delegations::<T>::take(who, currency).map(|delegate| {
+				DelegatorsOf::<T>::mutate(&delegate, currency, |delegators| delegators.retain(|d| d != who));
+				delegate
+			}),
+		}
+	}
+
+	/// Recount `who` in the open polls a change of its delegation in a currency, or in a poll,
+	/// affects: the poll, or the polls voted in along the chains of its old and new delegates
+	/// that `who` has no delegation of its own in.
+	fn recount_delegation(
+		who: &T::AccountId,
+		currency: PollCurrency<AssetIdOf<T>>,
+		poll_id: Option<T::PollIndex>,
+		delegates: [Option<T::AccountId>; 2],
+	) -> DispatchResult {
+		let polls = match poll_id {
+			Some(poll_id) => vec![poll_id],
+			None => {
+				let mut polls = Vec::new();
+				for delegate in delegates.into_iter().flatten() {
+					let mut next = Some(delegate);
+					for _ in 0..T::MaxDelegationDepth::get() {
+						match next {
+							Some(account) => {
+								polls.extend(
+									VoteLocks::<T>::get(&account)
+										.into_iter()
+										.filter(|(_, lock)| lock.currency == currency)
+										.map(|(poll_id, _)| poll_id),
+								);
+								next = Delegations::<T>::get(&account, currency);
+							},
+							None => break,
+						}
+					}
+				}
+				polls.sort();
+				polls.dedup();
+				polls.retain(|poll_id| !PollDelegations::<T>::contains_key(who, poll_id));
+				polls
+			},
+		};
+
+		for poll_id in polls {
+			Self::recount_delegator(who, currency, poll_id)?;
+		}
+		Ok(())
+	}
+
+	/// Move `who`, and the delegators counted through it, from the vote that counted them in a
+	/// poll to the vote its delegation leads to now, while the poll is open to votes.
+	fn recount_delegator(
+		who: &T::AccountId,
+		currency: PollCurrency<AssetIdOf<T>>,
+		poll_id: T::PollIndex,
+	) -> DispatchResult {
+		let mut poll = match Self::poll_status(currency, poll_id) {
+			Ok(poll) => poll,
+			Err(_) => return Ok(()),
+		};
+		if VotingOf::<T>::contains_key((who, poll_id)) {
+			return Ok(());
+		}
+
+		Self::uncount_delegator(who, &mut poll, poll_id)?;
+
+		if let Some((voter, distance)) = Self::voter_in_poll(who, currency, poll_id) {
+			let mut account_vote = VotingOf::<T>::get((&voter, poll_id)).ok_or(Error::<T>::NotVoted)?;
+			let mut delegated = DelegatedVotes::<T>::get(poll_id, &voter);
+			let count = delegated.len();
+			let levels = T::MaxDelegationDepth::get().saturating_add(1).saturating_sub(distance);
+			let votes = Self::count_delegators(&voter, &poll, poll_id, vec![who.clone()], levels, &mut delegated);
+
+			// Cast the ballot of the voter with the votes counted.
+			poll.votes.sub(&account_vote.ballot.option_votes(account_vote.votes));
+			account_vote.votes = account_vote.votes.saturating_add(votes);
+			poll.votes.add(&account_vote.ballot.option_votes(account_vote.votes));
+			poll.turnout = poll.turnout.saturating_add(votes);
+			poll.voters = poll.voters.saturating_add(delegated.len().saturating_sub(count) as u32);
+
+			if !delegated.is_empty() {
+				DelegatedVotes::<T>::insert(poll_id, &voter, delegated);
+			}
+			VotingOf::<T>::insert((&voter, poll_id), account_vote);
+		}
+
+		PollDetailsOf::<T>::insert(currency, poll_id, poll);
+		Ok(())
+	}
+
+	/// The account that voted in a poll the delegation of `who` leads to, with the number of
+	/// delegations to it, if the accounts in between are counted in its vote.
+	fn voter_in_poll(
+		who: &T::AccountId,
+		currency: PollCurrency<AssetIdOf<T>>,
+		poll_id: T::PollIndex,
+	) -> Option<(T::AccountId, u32)> {
+		let mut account = who.clone();
+		for distance in 1..=T::MaxDelegationDepth::get() {
+			account = Self::delegate_in_poll(&account, currency, poll_id)?;
+			if VotingOf::<T>::contains_key((&account, poll_id)) {
+				return Some((account, distance));
+			}
+			if !CountedBy::<T>::contains_key((&account, poll_id)) {
+				return None;
+			}
+		}
+		None
+	}
+
+	/// The account `who` delegates its votes in a poll to, over its delegation in the currency.
+	fn delegate_in_poll(
+		who: &T::AccountId,
+		currency: PollCurrency<AssetIdOf<T>>,
+		poll_id: T::PollIndex,
+	) -> Option<T::AccountId> {
+		PollDelegations::<T>::get(who, poll_id).or_else(|| Delegations::<T>::get(who, currency))
+	}
+
+	/// The accounts delegating their votes in a poll to `delegate`.
+	fn delegators_in_poll(
+		delegate: &T::AccountId,
+		currency: PollCurrency<AssetIdOf<T>>,
+		poll_id: T::PollIndex,
+	) -> Vec<T::AccountId> {
+		let mut delegators = PollDelegatorsOf::<T>::get(delegate, poll_id).into_inner();
+		delegators.extend(
+			DelegatorsOf::<T>::get(delegate, currency)
+				.into_iter()
+				.filter(|delegator| !PollDelegations::<T>::contains_key(delegator, poll_id)),
+		);
+		delegators
+	}
+
+	/// Count the balance of `delegators` in the vote of `voter` in a poll, and of the accounts
+	/// delegating to them in turn up to `levels` delegations away from the voter, that neither
+	/// voted nor are counted already. Returns the votes counted, the delegators are added to
+	/// `delegated`.
+	///
+	/// Delegators of an account that voted are counted in its vote instead, delegated votes are
+	/// only counted in single-choice, approval and ranked-choice polls with votes in the clear.
+	/// The balance of delegators is counted without being locked, so it stays free to transfer
+	/// or to vote with in other polls.
+	fn count_delegators(
+		voter: &T::AccountId,
+		poll: &PollTypeOf<T>,
+		poll_id: T::PollIndex,
+		mut delegators: Vec<T::AccountId>,
+		levels: u32,
+		delegated: &mut BoundedVec<(T::AccountId, u128), T::MaxDelegators>,
+	) -> u128 {
+		let mut votes = 0u128;
+		if poll.ballot_type == BallotType::Quadratic || poll.reveal_period.is_some() {
+			return votes;
+		}
+
+		for _ in 0..levels {
+			let mut next = Vec::new();
+			for delegator in delegators {
+				if delegated.len() as u32 >= T::MaxDelegators::get() {
+					return votes;
+				}
+				if delegator == *voter ||
+					VotingOf::<T>::contains_key((&delegator, poll_id)) ||
+					CountedBy::<T>::contains_key((&delegator, poll_id))
+				{
+					continue;
+				}
+
+				// Delegators without a snapshot in snapshot polls are not counted.
+				let balance = Self::voting_power(&delegator, poll, poll_id).unwrap_or_else(|_| Zero::zero());
+				if balance.is_zero() {
+					continue;
+				}
+
+				let balance = Self::balance_to_u128_saturated(balance);
+				let _ = delegated.try_push((delegator.clone(), balance));
+				CountedBy::<T>::insert((&delegator, poll_id), voter);
+				votes = votes.saturating_add(balance);
+				next.extend(Self::delegators_in_poll(&delegator, poll.currency, poll_id));
+			}
+			delegators = next;
+		}
+
+		votes
+	}
+
+	/// Take the votes of `who`, and of the delegators counted through it, out of the vote of the
+	/// delegate that counted them, so `who` votes directly or is counted in another vote.
+	fn uncount_delegator(who: &T::AccountId, poll: &mut PollTypeOf<T>, poll_id: T::PollIndex) -> DispatchResult {
+		let delegate = match CountedBy::<T>::get((who, poll_id)) {
+			Some(delegate) => delegate,
+			None => return Ok(()),
+		};
+		let mut account_vote = VotingOf::<T>::get((&delegate, poll_id)).ok_or(Error::<T>::NotVoted)?;
+
+		let (overridden, kept): (Vec<_>, Vec<_>) = DelegatedVotes::<T>::take(poll_id, &delegate)
+			.into_iter()
+			.partition(|(delegator, _)| Self::delegates_through(delegator, who, &delegate, poll.currency, poll_id));
+		let votes = overridden.iter().fold(0u128, |votes, (_, delegated)| votes.saturating_add(*delegated));
+
+		// Cast the ballot of the delegate with the votes left.
+		poll.votes.sub(&account_vote.ballot.option_votes(account_vote.votes));
+		account_vote.votes = account_vote.votes.saturating_sub(votes);
+		poll.votes.add(&account_vote.ballot.option_votes(account_vote.votes));
+		poll.turnout = poll.turnout.saturating_sub(votes);
+		poll.voters = poll.voters.saturating_sub(overridden.len() as u32);
+
+		for (delegator, _) in overridden {
+			CountedBy::<T>::remove((&delegator, poll_id));
+		}
+
+		if !kept.is_empty() {
+			DelegatedVotes::<T>::insert(poll_id, &delegate, BoundedVec::<_, T::MaxDelegators>::truncate_from(kept));
+		}
+		VotingOf::<T>::insert((&delegate, poll_id), account_vote);
+		Ok(())
+	}
+
+	/// Whether the chain of delegates from `delegator` to `delegate` goes through `through`.
+	fn delegates_through(
+		delegator: &T::AccountId,
+		through: &T::AccountId,
+		delegate: &T::AccountId,
+		currency: PollCurrency<AssetIdOf<T>>,
+		poll_id: T::PollIndex,
+	) -> bool {
+		let mut account = delegator.clone();
+		for _ in 0..T::MaxDelegationDepth::get() {
+			if account == *through {
+				return true;
+			}
+			match Self::delegate_in_poll(&account, currency, poll_id) {
+				Some(next) if next != *delegate => account = next,
+				_ => return false,
+			}
+		}
+		false
+	}
+
+	/// Set or clear the outcome of an option of a poll that did not start yet.
+	fn try_set_outcome(
+		who: &T::AccountId,
+		poll_currency: PollCurrency<AssetIdOf<T>>,
+		poll_id: T::PollIndex,
+		option: u8,
+		call: Option<Box<<T as Config>::PollCall>>,
+	) -> DispatchResult {
+		let poll = PollDetailsOf::<T>::get(poll_currency, poll_id).ok_or(Error::<T>::PollNotFound)?;
+
+		// Ensure poll creator is the same as who.
+		ensure!(poll.created_by == *who, Error::<T>::NotPollCreator);
+
+		// Ensure poll did not started yet
+		match poll.status {
+			PollStatus::Ongoing { start, .. } => {
+				let now = <frame_system::Pallet<T>>::block_number();
+				ensure!(now < start, Error::<T>::PollAlreadyStarted);
+			},
+			_ => return Err(Error::<T>::PollAlreadyFinished.into()),
+		}
+
+		ensure!(option < poll.options_count, Error::<T>::InvalidPollVote);
+
+		match call {
+			Some(call) => {
+				ensure!(call.encoded_size() as u32 <= T::MaxOutcomeLen::get(), Error::<T>::OutcomeTooLong);
+				ensure!(Self::requires_outcome_turnout(poll_currency, &poll.thresholds), Error::<T>::OutcomeTurnoutTooLow);
+				Outcomes::<T>::insert(poll_id, option, *call);
+			},
+			None => Outcomes::<T>::remove(poll_id, option),
+		}
+
+		Ok(())
+	}
+
+	/// Turnout a poll of `currency` must reach to dispatch an outcome, `MinOutcomeTurnout` of the
+	/// issuance of the currency.
+	fn min_outcome_turnout(currency: PollCurrency<AssetIdOf<T>>) -> u128 {
+		let issuance = match currency {
+			PollCurrency::Native => T::Currency::total_issuance(),
+			PollCurrency::Asset(asset_id) => <T::Fungibles as Inspect<T::AccountId>>::total_issuance(asset_id),
+		};
+		T::MinOutcomeTurnout::get() * issuance.saturated_into::<u128>()
+	}
+
+	/// Whether `thresholds` require the turnout a poll of `currency` needs to have an outcome.
+	fn requires_outcome_turnout(currency: PollCurrency<AssetIdOf<T>>, thresholds: &PollThresholds) -> bool {
+		thresholds.min_turnout.unwrap_or_default() >= Self::min_outcome_turnout(currency)
+	}
+
+	/// Schedule the outcome of the winning option of a poll, if any, from the token holders of
+	/// the poll currency, and clear the outcomes of the other options. An outcome the scheduler
+	/// rejects, or of a poll whose turnout fell below `MinOutcomeTurnout` of an issuance that
+	/// grew while it ran, is reported with `OutcomeNotScheduled`, the poll still ends.
+	fn schedule_outcome(poll_currency: PollCurrency<AssetIdOf<T>>, poll_id: T::PollIndex, option: u8, turnout: u128) {
+		if let Some(call) = Outcomes::<T>::take(poll_id, option) {
+			if turnout < Self::min_outcome_turnout(poll_currency) || T::Scheduler::schedule_named(
+				(OUTCOME_ID, poll_currency, poll_id).encode(),
+				DispatchTime::After(Zero::zero()),
+				None,
+				63,
+				RawOrigin::TokenHolders(poll_currency).into(),
+				call,
+			)
+			.is_err()
+			{
+				Self::deposit_event(Event::OutcomeNotScheduled { currency: poll_currency, poll_id, option });
+			} else {
+				Self::deposit_event(Event::OutcomeScheduled { currency: poll_currency, poll_id, option });
+			}
+		}
+
+		Self::clear_outcomes(poll_id);
+	}
+
+	/// Remove the outcomes of every option of a poll.
+	fn clear_outcomes(poll_id: T::PollIndex) {
+		let _ = Outcomes::<T>::clear_prefix(poll_id, u8::MAX as u32 + 1, None);
+	}
+
+	/// Check a ballot matches the poll and spends at most `votes`.
+	fn validate_ballot(poll: &PollTypeOf<T>, ballot: &Ballot, votes: u128) -> DispatchResult {
+		ensure!(ballot.ballot_type() == poll.ballot_type, Error::<T>::InvalidBallotType);
+
+		// Check options are valid and not repeated.
+		let mut options = ballot.options();
+		ensure!(!options.is_empty(), Error::<T>::InvalidBallot);
+		ensure!(options.iter().all(|option| *option < poll.options_count), Error::<T>::InvalidPollVote);
+		options.sort_unstable();
+		options.dedup();
+		ensure!(options.len() == ballot.options().len(), Error::<T>::InvalidBallot);
+
+		if let Ballot::Quadratic(credits) = ballot {
+			let spent = credits.iter().fold(0u128, |spent, (_, credits)| spent.saturating_add(*credits));
+			ensure!(spent <= votes, Error::<T>::InsufficientCredits);
+		}
+
+		Ok(())
+	}
+
+	/// Keep the vote of an account, ranked voters are indexed for the tally.
+	fn insert_vote(who: &T::AccountId, poll_id: T::PollIndex, account_vote: AccountVote) {
+		if let Ballot::Ranked(_) = account_vote.ballot {
+			RankedVoters::<T>::insert(poll_id, who, ());
+		}
+		VotingOf::<T>::insert((who, poll_id), account_vote);
+	}
 
 	fn poll_status(
 		poll_currency: PollCurrency<AssetIdOf<T>>,
@@ -641,31 +2345,116 @@ impl<T: Config> Pallet<T> {
 	) -> Result<PollDetails<BalanceOf<T>, T::AccountId, AssetIdOf<T>, T::BlockNumber>, DispatchError>
 	{
 		let poll = PollDetailsOf::<T>::get(poll_currency, poll_id).ok_or(Error::<T>::PollInvalid)?;
-		match poll.status.is_ongoing() {
+		match poll.status.is_ongoing() && !Self::is_tallying(&poll, poll_id) {
 			true => Ok(poll),
 			_ => Err(Error::<T>::PollAlreadyFinished.into()),
 		}
 	}
 
-	/// Finish the poll
+	/// Whether the ranked ballots of a poll are being tallied, voting is over then.
+	fn is_tallying(poll: &PollTypeOf<T>, poll_id: T::PollIndex) -> bool {
+		poll.ballot_type == BallotType::RankedChoice && RankedTallies::<T>::contains_key(poll_id)
+	}
+
+	/// Finish the poll, or fail it when its thresholds are not met.
 	fn do_enact_poll_end(
 		poll_currency: PollCurrency<AssetIdOf<T>>,
 		poll_id: T::PollIndex,
-	) -> DispatchResult {
+	) -> Result<PollTypeOf<T>, DispatchError> {
 		let mut poll = PollDetailsOf::<T>::get(poll_currency, poll_id).ok_or(Error::<T>::PollNotFound)?;
 		// Shouldn't be any other status than Ongoing, but better be safe.
 		let end = match poll.status {
 			PollStatus::Ongoing { end, .. } => end,
 			_ => return Err(Error::<T>::PollAlreadyFinished.into()),
 		};
+
+		// Only revealed votes are counted.
+		Self::drop_commitments(poll_currency, poll_id);
+		poll.commitments = 0;
 		
-		let winning_option = poll.votes.winning_option(); // .ok_or(Error::<T>::UnexpectedBehavior)?
-		
-		poll.status = PollStatus::Finished { winning_option: winning_option, end };
+		// Tally the votes, ranked ballots are counted by instant-runoff over as many blocks as needed.
+		let (winning_option, rounds) = match poll.ballot_type {
+			BallotType::RankedChoice => match Self::tally_ranked_ballots(poll_currency, poll_id, poll.options_count) {
+				Some(tally) => tally,
+				None => {
+					PollDetailsOf::<T>::insert(poll_currency, poll_id, poll.clone());
+					return Ok(poll)
+				},
+			},
+			_ => (poll.votes.winning_option(), Vec::new()),
+		};
+		let votes = rounds.last().cloned().unwrap_or_else(|| poll.votes.clone());
+		let result = PollResult { votes, rounds };
+
+		poll.status = match poll.has_quorum() && poll.has_approval(winning_option, &result) {
+			true => PollStatus::Finished { winning_option, end, result },
+			false => PollStatus::Failed(end),
+		};
+
+		// Dispatch the outcome of the winning option.
+		match poll.status {
+			PollStatus::Finished { winning_option: Some(option), .. } =>
+				Self::schedule_outcome(poll_currency, poll_id, option, poll.turnout),
+			_ => Self::clear_outcomes(poll_id),
+		}
 		
 		// Update poll in storage.
-		PollDetailsOf::<T>::insert(poll_currency, poll_id, poll);
-		Ok(())
+		PollDetailsOf::<T>::insert(poll_currency, poll_id, poll.clone());
+		Ok(poll)
+	}
+
+	/// Count a page of up to `MaxRankedBallotsPerBlock` ranked ballots of a poll, returns the
+	/// winning option, if any, and the votes of each round once the instant-runoff is over,
+	/// otherwise schedules the next page.
+	fn tally_ranked_ballots(
+		poll_currency: PollCurrency<AssetIdOf<T>>,
+		poll_id: T::PollIndex,
+		options_count: u8,
+	) -> Option<(Option<u8>, Vec<Votes>)> {
+		let mut tally = RankedTallies::<T>::get(poll_id).unwrap_or_else(|| RankedTally::new(options_count));
+		let mut voters = match tally.cursor.take() {
+			Some(cursor) => RankedVoters::<T>::iter_key_prefix_from(poll_id, cursor),
+			None => RankedVoters::<T>::iter_key_prefix(poll_id),
+		};
+
+		let page = T::MaxRankedBallotsPerBlock::get();
+		let mut counted = 0;
+		for voter in voters.by_ref().take(page as usize) {
+			if let Some(AccountVote { ballot: Ballot::Ranked(ranking), votes }) = VotingOf::<T>::get((&voter, poll_id)) {
+				tally.round.add_ranking(&ranking, votes, &tally.eliminated);
+			}
+			counted += 1;
+		}
+
+		// Decide the round once every ballot is counted.
+		if counted < page {
+			let round = sp_std::mem::replace(&mut tally.round, Votes::new(options_count));
+			tally.rounds.push(round.clone());
+			if let Some(winning_option) = round.runoff(&mut tally.eliminated) {
+				RankedTallies::<T>::remove(poll_id);
+				return Some((winning_option, tally.rounds))
+			}
+		} else {
+			tally.cursor = Some(voters.last_raw_key().to_vec());
+		}
+
+		// Count the next page in the next block.
+		tally.pages = tally.pages.saturating_add(1);
+		if T::Scheduler::schedule_named(
+			(TALLY_ID, poll_currency, poll_id, tally.pages).encode(),
+			DispatchTime::After(Zero::zero()),
+			None,
+			63,
+			frame_system::RawOrigin::Root.into(),
+			Call::enact_poll_end { poll_currency, poll_id }.into(),
+		)
+		.is_err()
+		{
+			frame_support::print("LOGIC ERROR: tally_ranked_ballots/schedule_named failed");
+		}
+
+		RankedTallies::<T>::insert(poll_id, tally);
+		None
 	}
 
 
@@ -674,16 +2463,163 @@ impl<T: Config> Pallet<T> {
 		let mut poll = PollDetailsOf::<T>::get(poll_currency, poll_id).ok_or(Error::<T>::PollNotFound)?;
 		// Check if origin is entitled to cancel the poll.
 		ensure!(poll.created_by.eq(who), Error::<T>::NotPollCreator);
+		// Polls being tallied are over.
+		ensure!(!Self::is_tallying(&poll, poll_id), Error::<T>::PollAlreadyFinished);
 		// Cancel dispatch.
 		T::Scheduler::cancel_named((POLLS_ID, poll.currency, poll_id).encode())
 			.map_err(|_| Error::<T>::UnexpectedBehavior)?;
 		// Set status to Cancelled and update polls storage.
 		let now = <frame_system::Pallet<T>>::block_number();
 		poll.status = PollStatus::Cancelled(now);
+		poll.commitments = 0;
 		PollDetailsOf::<T>::insert(poll_currency, poll_id, poll);
+		let _ = Commitments::<T>::clear_prefix(poll_id, T::MaxCommitments::get(), None);
+		Self::clear_outcomes(poll_id);
+		Ok(())
+	}
+
+	/// The account of the pallet, holds the assets voted with.
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// Lock the balance of a vote, native balance is locked and assets are moved to the pallet account.
+	///
+	/// Like native locks, asset locks of different polls overlap: only the part of the vote above
+	/// the assets already held for the other polls of the account is moved.
+	fn lock_vote(who: &T::AccountId, poll_id: T::PollIndex, lock: VoteLockOf<T>) -> DispatchResult {
+		VoteLocks::<T>::try_mutate(who, |locks| -> DispatchResult {
+			let held = match lock.currency {
+				PollCurrency::Native => Zero::zero(),
+				PollCurrency::Asset(asset_id) => Self::escrowed_asset(asset_id, locks),
+			};
+			locks.try_push((poll_id, lock.clone())).map_err(|_| Error::<T>::TooManyVotes)?;
+
+			match lock.currency {
+				PollCurrency::Native => Self::update_native_lock(who, locks),
+				PollCurrency::Asset(asset_id) => {
+					let amount = lock.amount.saturating_sub(held);
+					if !amount.is_zero() {
+						<T::Fungibles as Transfer<T::AccountId>>::transfer(asset_id, who, &Self::account_id(), amount, false)?;
+					}
+				},
+			}
+
+			Ok(())
+		})
+	}
+
+	/// Unlock the balance of a vote once the poll ended and the conviction lock expired.
+	fn try_unlock(
+		who: &T::AccountId,
+		poll_currency: PollCurrency<AssetIdOf<T>>,
+		poll_id: T::PollIndex,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let poll = PollDetailsOf::<T>::get(poll_currency, poll_id).ok_or(Error::<T>::PollNotFound)?;
+
+		VoteLocks::<T>::try_mutate(who, |locks| -> Result<BalanceOf<T>, DispatchError> {
+			let index = locks
+				.iter()
+				.position(|(id, lock)| *id == poll_id && lock.currency == poll_currency)
+				.ok_or(Error::<T>::NotLocked)?;
+
+			let (_, lock) = locks.remove(index);
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(now >= Self::unlock_block(&poll.status, lock.conviction)?, Error::<T>::VoteStillLocked);
+
+			Self::release_lock(who, locks, &lock)?;
+
+			Ok(lock.amount)
+		})
+	}
+
+	/// Remove the lock of an account for a poll and release its balance.
+	fn remove_lock(who: &T::AccountId, poll_currency: PollCurrency<AssetIdOf<T>>, poll_id: T::PollIndex) -> DispatchResult {
+		VoteLocks::<T>::try_mutate(who, |locks| -> DispatchResult {
+			let index = locks
+				.iter()
+				.position(|(id, lock)| *id == poll_id && lock.currency == poll_currency)
+				.ok_or(Error::<T>::NotLocked)?;
+
+			let (_, lock) = locks.remove(index);
+			Self::release_lock(who, locks, &lock)
+		})
+	}
+
+	/// Set the conviction of the lock of an account for a poll, keeping its balance locked.
+	fn set_lock_conviction(
+		who: &T::AccountId,
+		poll_currency: PollCurrency<AssetIdOf<T>>,
+		poll_id: T::PollIndex,
+		conviction: Conviction,
+	) -> DispatchResult {
+		VoteLocks::<T>::try_mutate(who, |locks| -> DispatchResult {
+			let (_, lock) = locks
+				.iter_mut()
+				.find(|(id, lock)| *id == poll_id && lock.currency == poll_currency)
+				.ok_or(Error::<T>::NotLocked)?;
+
+			lock.conviction = conviction;
+			Ok(())
+		})
+	}
+
+	/// Release the balance of a lock already removed from the remaining `locks` of an account.
+	fn release_lock(who: &T::AccountId, locks: &[(T::PollIndex, VoteLockOf<T>)], lock: &VoteLockOf<T>) -> DispatchResult {
+		match lock.currency {
+			PollCurrency::Native => Self::update_native_lock(who, locks),
+			PollCurrency::Asset(asset_id) => {
+				let amount = lock.amount.saturating_sub(Self::escrowed_asset(asset_id, locks));
+				if !amount.is_zero() {
+					<T::Fungibles as Transfer<T::AccountId>>::transfer(asset_id, &Self::account_id(), who, amount, false)?;
+				}
+			},
+		}
+
 		Ok(())
 	}
 
+	/// Block from which the balance of a vote with `conviction` can be unlocked.
+	fn unlock_block(
+		status: &PollStatus<BlockNumberOf<T>>,
+		conviction: Conviction,
+	) -> Result<BlockNumberOf<T>, DispatchError> {
+		match *status {
+			PollStatus::Ongoing { .. } => Err(Error::<T>::PollStillOngoing.into()),
+			PollStatus::Cancelled(at) => Ok(at),
+			PollStatus::Finished { end, .. } | PollStatus::Failed(end) => {
+				let periods: BlockNumberOf<T> = conviction.lock_periods().into();
+				Ok(end.saturating_add(T::VoteLockingPeriod::get().saturating_mul(periods)))
+			},
+		}
+	}
+
+	/// Lock the largest native balance voted with in the polls an account still has locks in.
+	fn update_native_lock(who: &T::AccountId, locks: &[(T::PollIndex, VoteLockOf<T>)]) {
+		let amount = locks
+			.iter()
+			.filter(|(_, lock)| lock.currency == PollCurrency::Native)
+			.map(|(_, lock)| lock.amount)
+			.max();
+
+		match amount {
+			Some(amount) => T::Currency::set_lock(POLLS_ID, who, amount, WithdrawReasons::all()),
+			None => T::Currency::remove_lock(POLLS_ID, who),
+		}
+	}
+
+	/// Largest balance of `asset_id` voted with in the polls an account still has locks in, the
+	/// amount the pallet account holds for it.
+	fn escrowed_asset(asset_id: AssetIdOf<T>, locks: &[(T::PollIndex, VoteLockOf<T>)]) -> BalanceOf<T> {
+		locks
+			.iter()
+			.filter(|(_, lock)| lock.currency == PollCurrency::Asset(asset_id))
+			.map(|(_, lock)| lock.amount)
+			.max()
+			.unwrap_or_else(Zero::zero)
+	}
+
 	// Note the warning above about saturated conversions
 	pub fn balance_to_u128_saturated(input: <<T as pallet::Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance) -> u128 {
     	input.saturated_into::<u128>()
@@ -692,3 +2628,75 @@ impl<T: Config> Pallet<T> {
 
 	
 }
+
+// Queries of the runtime API.
+impl<T: Config> Pallet<T> {
+	/// Up to `limit` polls created by `creator` starting at `offset`, ordered by poll id, with
+	/// the vote of `voter` in each.
+	///
+	/// `offset` and `limit` count the polls of the creator matching `status`, the polls are read
+	/// from the first one of the creator until the page is full.
+	pub fn polls_of_creator(
+		creator: T::AccountId,
+		status: Option<PollStatusFilter>,
+		voter: Option<T::AccountId>,
+		offset: u32,
+		limit: u32,
+	) -> Vec<PollInfo<T::PollIndex, T::AccountId, AssetIdOf<T>, BalanceOf<T>, BlockNumberOf<T>>> {
+		let polls = (0..CreatorPollCount::<T>::get(&creator)).filter_map(|index| PollsByCreator::<T>::get(&creator, index));
+
+		Self::polls_page(polls, status, voter, offset, limit)
+	}
+
+	/// Up to `limit` polls in `currency` starting at `offset`, ordered by poll id, with the vote
+	/// of `voter` in each.
+	///
+	/// `offset` and `limit` count the polls in the currency matching `status`, the polls are read
+	/// from the first one in the currency until the page is full.
+	pub fn polls_of_currency(
+		currency: PollCurrency<AssetIdOf<T>>,
+		status: Option<PollStatusFilter>,
+		voter: Option<T::AccountId>,
+		offset: u32,
+		limit: u32,
+	) -> Vec<PollInfo<T::PollIndex, T::AccountId, AssetIdOf<T>, BalanceOf<T>, BlockNumberOf<T>>> {
+		let polls = (0..CurrencyPollCount::<T>::get(currency))
+			.filter_map(|index| PollsByCurrency::<T>::get(currency, index))
+			.map(|poll_id| (currency, poll_id));
+
+		Self::polls_page(polls, status, voter, offset, limit)
+	}
+
+	/// A poll with the vote of `voter` in it.
+	pub fn poll_info(
+		currency: PollCurrency<AssetIdOf<T>>,
+		poll_id: T::PollIndex,
+		voter: Option<T::AccountId>,
+	) -> Option<PollInfo<T::PollIndex, T::AccountId, AssetIdOf<T>, BalanceOf<T>, BlockNumberOf<T>>> {
+		let details = PollDetailsOf::<T>::get(currency, poll_id)?;
+		let vote = voter.and_then(|voter| VotingOf::<T>::get((voter, poll_id)));
+
+		Some(PollInfo { poll_id, details, vote })
+	}
+
+	/// Up to `limit` of the `polls` matching `status` after the first `offset` of them, with the
+	/// vote of `voter` in each.
+	fn polls_page(
+		polls: impl Iterator<Item = (PollCurrency<AssetIdOf<T>>, T::PollIndex)>,
+		status: Option<PollStatusFilter>,
+		voter: Option<T::AccountId>,
+		offset: u32,
+		limit: u32,
+	) -> Vec<PollInfo<T::PollIndex, T::AccountId, AssetIdOf<T>, BalanceOf<T>, BlockNumberOf<T>>> {
+		polls
+			.filter_map(|(currency, poll_id)| PollDetailsOf::<T>::get(currency, poll_id).map(|details| (poll_id, details)))
+			.filter(|(_, details)| status.map_or(true, |status| details.status.matches(status)))
+			.skip(offset as usize)
+			.take(limit as usize)
+			.map(|(poll_id, details)| {
+				let vote = voter.clone().and_then(|voter| VotingOf::<T>::get((voter, poll_id)));
+				PollInfo { poll_id, details, vote }
+			})
+			.collect()
+	}
+}
@@ -0,0 +1,177 @@
+//! Storage migrations for the polls pallet.
+
+use super::*;
+
+pub mod v1 {
+	use super::*;
+	use frame_support::{
+		log,
+		storage_alias,
+		traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+		weights::Weight,
+	};
+	use sp_std::collections::btree_map::BTreeMap;
+
+	/// Status of a poll as stored before version 1, finished polls kept no result.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	pub enum OldPollStatus<BlockNumber> {
+		Ongoing { start: BlockNumber, end: BlockNumber },
+		Cancelled(BlockNumber),
+		Finished { winning_option: Option<u8>, end: BlockNumber },
+		Failed(BlockNumber),
+	}
+
+	/// Poll as stored before version 1, with single choice ballots only.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	pub struct OldPollDetails<T: Config> {
+		pub created_by: T::AccountId,
+		pub ipfs_cid: IpfsCid,
+		pub options_count: u8,
+		pub votes: Votes,
+		pub currency: PollCurrency<AssetIdOf<T>>,
+		pub status: OldPollStatus<BlockNumberOf<T>>,
+		pub min_balance: BalanceOf<T>,
+	}
+
+	#[storage_alias]
+	pub type PollDetailsOf<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		PollCurrency<AssetIdOf<T>>,
+		Blake2_128Concat,
+		<T as Config>::PollIndex,
+		OldPollDetails<T>,
+	>;
+
+	#[storage_alias]
+	pub type VotingOf<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		(<T as frame_system::Config>::AccountId, <T as Config>::PollIndex),
+		u8,
+	>;
+
+	/// Migrate polls and votes from version 0 to version 1.
+	///
+	/// - Votes become single choice ballots. Version 0 neither recorded the weight of a vote nor
+	///   locked the balance voted with, so the votes stay in the tally with a weight of 0 and
+	///   can't be removed.
+	/// - Polls become single choice polls without thresholds, reveal period or snapshots, their
+	///   turnout is the sum of their votes and their voters are counted from the votes.
+	/// - Finished polls keep their votes as their result, the creator and currency indexes added
+	///   in version 1 are built in the order the polls were created in.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+			if onchain_version != 0 {
+				log::info!(target: "runtime::polls", "MigrateToV1 should be removed, storage is at {:?}", onchain_version);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut reads: u64 = 1;
+			let mut writes: u64 = 0;
+			let mut voters: BTreeMap<T::PollIndex, u32> = BTreeMap::new();
+			let mut polls: Vec<(T::PollIndex, T::AccountId, PollCurrency<AssetIdOf<T>>)> = Vec::new();
+
+			crate::VotingOf::<T>::translate::<u8, _>(|(_, poll_id), option| {
+				reads += 1;
+				writes += 1;
+				*voters.entry(poll_id).or_default() += 1;
+
+				Some(AccountVote { ballot: Ballot::Single(option), votes: 0 })
+			});
+
+			crate::PollDetailsOf::<T>::translate::<OldPollDetails<T>, _>(|currency, poll_id, old| {
+				reads += 1;
+				writes += 1;
+				polls.push((poll_id, old.created_by.clone(), currency));
+
+				let turnout = old.votes.0.iter().fold(0u128, |turnout, votes| turnout.saturating_add(*votes));
+				let status = match old.status {
+					OldPollStatus::Ongoing { start, end } => PollStatus::Ongoing { start, end },
+					OldPollStatus::Cancelled(at) => PollStatus::Cancelled(at),
+					OldPollStatus::Finished { winning_option, end } => PollStatus::Finished {
+						winning_option,
+						end,
+						result: PollResult { votes: old.votes.clone(), rounds: Vec::new() },
+					},
+					OldPollStatus::Failed(at) => PollStatus::Failed(at),
+				};
+
+				Some(PollDetails {
+					created_by: old.created_by,
+					ipfs_cid: old.ipfs_cid,
+					options_count: old.options_count,
+					ballot_type: BallotType::SingleChoice,
+					votes: old.votes,
+					currency: old.currency,
+					status,
+					min_balance: old.min_balance,
+					thresholds: PollThresholds::default(),
+					turnout,
+					voters: voters.get(&poll_id).copied().unwrap_or_default(),
+					reveal_period: None,
+					commitments: 0,
+					snapshot: false,
+				})
+			});
+
+			polls.sort_by_key(|(poll_id, _, _)| *poll_id);
+			for (poll_id, created_by, currency) in polls.iter() {
+				let creator_index = crate::CreatorPollCount::<T>::get(created_by);
+				crate::PollsByCreator::<T>::insert(created_by, creator_index, (*currency, *poll_id));
+				crate::CreatorPollCount::<T>::insert(created_by, creator_index.saturating_add(1));
+
+				let currency_index = crate::CurrencyPollCount::<T>::get(currency);
+				crate::PollsByCurrency::<T>::insert(currency, currency_index, *poll_id);
+				crate::CurrencyPollCount::<T>::insert(currency, currency_index.saturating_add(1));
+
+				reads += 2;
+				writes += 4;
+			}
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+			writes += 1;
+
+			log::info!(
+				target: "runtime::polls",
+				"Migrated {} polls and {} votes to version 1",
+				polls.len(),
+				voters.values().fold(0u32, |votes, count| votes.saturating_add(*count))
+			);
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			ensure!(Pallet::<T>::on_chain_storage_version() == 0, "polls: storage version is not 0");
+
+			let polls = PollDetailsOf::<T>::iter_keys().count() as u32;
+			let votes = VotingOf::<T>::iter_keys().count() as u32;
+
+			Ok((polls, votes).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let (polls, votes): (u32, u32) =
+				Decode::decode(&mut &state[..]).map_err(|_| "polls: can't decode pre_upgrade state")?;
+
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "polls: storage version is not 1");
+			ensure!(crate::PollDetailsOf::<T>::iter_values().count() as u32 == polls, "polls: polls were lost");
+			ensure!(crate::VotingOf::<T>::iter_values().count() as u32 == votes, "polls: votes were lost");
+
+			let indexed = crate::PollsByCreator::<T>::iter_values().count() as u32;
+			ensure!(indexed == polls, "polls: polls missing from creator index");
+			let indexed = crate::PollsByCurrency::<T>::iter_values().count() as u32;
+			ensure!(indexed == polls, "polls: polls missing from currency index");
+
+			Ok(())
+		}
+	}
+}
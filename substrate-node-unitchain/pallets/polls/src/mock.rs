@@ -1,7 +1,7 @@
 use crate as pallet_polls;
 use crate::*;
 use frame_support::{ 
-parameter_types, assert_ok,
+parameter_types, assert_ok, PalletId,
 traits::{ConstU16, ConstU64, ConstU32, AsEnsureOriginWithArg, EqualPrivilegeOnly, Hooks},
 };
 use frame_system as system;
@@ -124,6 +124,18 @@ impl pallet_scheduler::Config for Test {
 	type Preimages = ();
 }
 
+parameter_types! {
+	pub const PollsPalletId: PalletId = PalletId(*b"unitpoll");
+	pub const MaxVotes: u32 = 10;
+	pub const VoteLockingPeriod: u64 = 10;
+	pub const MaxRankedBallotsPerBlock: u32 = 2;
+	pub const MaxOutcomeLen: u32 = 128;
+	pub const MinOutcomeTurnout: Perbill = Perbill::from_percent(50);
+	pub const MaxCommitments: u32 = 16;
+	pub const MaxDelegators: u32 = 8;
+	pub const MaxDelegationDepth: u32 = 2;
+}
+
 impl pallet_polls::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
@@ -132,6 +144,16 @@ impl pallet_polls::Config for Test {
 	type PollCall = RuntimeCall;
 	type Scheduler = Scheduler;
 	type PalletsOrigin = OriginCaller;
+	type PalletId = PollsPalletId;
+	type MaxVotes = MaxVotes;
+	type VoteLockingPeriod = VoteLockingPeriod;
+	type MaxRankedBallotsPerBlock = MaxRankedBallotsPerBlock;
+	type MaxOutcomeLen = MaxOutcomeLen;
+	type MinOutcomeTurnout = MinOutcomeTurnout;
+	type MaxCommitments = MaxCommitments;
+	type MaxDelegators = MaxDelegators;
+	type MaxDelegationDepth = MaxDelegationDepth;
+	type WeightInfo = ();
 }
 
 // Build genesis storage according to the mock runtime.
@@ -152,17 +174,29 @@ pub fn fast_forward_to(n: u64) {
 }
 
 pub fn begin_poll(who: u64) -> PollIndex {
+	begin_poll_with_ballot(who, BallotType::SingleChoice)
+}
+
+pub fn begin_poll_with_ballot(who: u64, ballot_type: BallotType) -> PollIndex {
+	begin_poll_with(who, ballot_type, PollThresholds::default())
+}
+
+pub fn begin_poll_with(who: u64, ballot_type: BallotType, thresholds: PollThresholds) -> PollIndex {
 	System::set_block_number(0);
 	// create a testint poll
 	let res = Polls::create_poll(
 		RuntimeOrigin::signed(who),
 		(0..46).collect(),
 		4,
+		ballot_type,
 		pallet_polls::PollCurrency::Native,
 		1,
 		10,
 		// set min balance as 0
 		0,
+		thresholds,
+		None,
+		false,
 	);
 	assert_ok!(res);
 	fast_forward_to(2);
@@ -185,11 +219,15 @@ pub fn begin_poll_with_asset(
 		RuntimeOrigin::signed(who),
 		(0..46).collect(),
 		4,
+		BallotType::SingleChoice,
 		PollCurrency::Asset(0),
 		1,
 		10,
 		// set min balance as 0
 		0,
+		PollThresholds::default(),
+		None,
+		false,
 	);
 
 	assert_ok!(res);
@@ -199,6 +237,6 @@ pub fn begin_poll_with_asset(
 
 pub fn set_balances(acc: u64) {
 	assert_ok!(Balances::set_balance(RuntimeOrigin::root(), acc, 20, 0));
-	//assert_ok!(Balances::set_balance(RuntimeOrigin::root(), Polls::account_id(), 1, 0));
+	assert_ok!(Balances::set_balance(RuntimeOrigin::root(), Polls::account_id(), 1, 0));
 	assert_eq!(Balances::free_balance(acc), 20);
 }
@@ -9,7 +9,7 @@ fn vote_without_balance_should_fail() {
 		// Try to vote without balance
 		let v = 2u8;
 		assert_noop!(
-			Polls::vote(RuntimeOrigin::signed(1), PollCurrency::Native, pid, v),
+			Polls::vote(RuntimeOrigin::signed(1), PollCurrency::Native, pid, Ballot::Single(v), Conviction::None),
 			Error::<Test>::InsufficientFunds
 		);
 	});
@@ -27,7 +27,7 @@ fn vote_with_balances_should_work() {
 		// Vote on poll
 		let v = 2u8; // 3rd option, base 0 index
 
-		assert_ok!(Polls::vote(RuntimeOrigin::signed(voter), PollCurrency::Native, pid, v.clone()));
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(voter), PollCurrency::Native, pid, Ballot::Single(v), Conviction::None));
 
 		assert_eq!(Balances::free_balance(voter), 20);
 
@@ -36,7 +36,7 @@ fn vote_with_balances_should_work() {
 
 		
 		assert_noop!(
-			Polls::vote(RuntimeOrigin::signed(voter), PollCurrency::Native, pid, 3u8),
+			Polls::vote(RuntimeOrigin::signed(voter), PollCurrency::Native, pid, Ballot::Single(3u8), Conviction::None),
 			Error::<Test>::AlreadyVoted,
 		);
 		
@@ -44,7 +44,7 @@ fn vote_with_balances_should_work() {
 
 		// Check if winning option is correct.
 		let poll = Polls::poll_details_of(PollCurrency::Native, pid).unwrap();
-		if let PollStatus::Finished { winning_option, end } = poll.status {
+		if let PollStatus::Finished { winning_option, end, .. } = poll.status {
 			assert_eq!(winning_option, Some(2));
 			assert_eq!(end, 10);
 		} else {
@@ -64,7 +64,7 @@ fn emergency_cancel_should_work() {
 		// Vote on poll
 		let v = 2u8; // 3rd option, base 0 index
 
-		assert_ok!(Polls::vote(RuntimeOrigin::signed(voter), PollCurrency::Native, pid, v.clone()));
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(voter), PollCurrency::Native, pid, Ballot::Single(v), Conviction::None));
 
 		next_block();
 
@@ -78,12 +78,63 @@ fn emergency_cancel_should_work() {
 
 		let voter_2 = 6;
 		assert_noop!(
-			Polls::vote(RuntimeOrigin::signed(voter_2), PollCurrency::Native, pid, v.clone()),
+			Polls::vote(RuntimeOrigin::signed(voter_2), PollCurrency::Native, pid, Ballot::Single(v), Conviction::None),
 			Error::<Test>::PollAlreadyFinished,
 		);
 	});
 }
 
+#[test]
+fn update_poll_only_before_start_with_valid_period() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(2);
+		assert_ok!(Polls::create_poll(
+			RuntimeOrigin::signed(1),
+			(0..46).collect(),
+			4,
+			BallotType::SingleChoice,
+			PollCurrency::Native,
+			5,
+			10,
+			0,
+			PollThresholds::default(),
+			None,
+			false,
+		));
+		let pid = Polls::poll_count();
+		let update = |start: u64, end: u64| {
+			Polls::update_poll(
+				RuntimeOrigin::signed(1),
+				pid,
+				(0..46).collect(),
+				4,
+				BallotType::SingleChoice,
+				PollCurrency::Native,
+				start,
+				end,
+				0,
+				PollThresholds::default(),
+				None,
+			)
+		};
+
+		// Start in the past, or end not after start.
+		assert_noop!(update(1, 10), Error::<Test>::InvalidPollPeriod);
+		assert_noop!(update(6, 6), Error::<Test>::InvalidPollPeriod);
+		assert_noop!(update(6, 4), Error::<Test>::InvalidPollPeriod);
+
+		assert_ok!(update(4, 12));
+		assert_eq!(Polls::poll_details_of(PollCurrency::Native, pid).unwrap().status, PollStatus::Ongoing { start: 4, end: 12 });
+
+		fast_forward_to(4);
+		assert_noop!(update(6, 12), Error::<Test>::PollAlreadyStarted);
+
+		// Cancelled polls can't be reopened.
+		assert_ok!(Polls::emergency_cancel(RuntimeOrigin::signed(1), PollCurrency::Native, pid));
+		assert_noop!(update(6, 12), Error::<Test>::PollAlreadyFinished);
+	});
+}
+
 #[test]
 fn vote_with_assets_should_work() {
 	new_test_ext().execute_with(|| {
@@ -97,7 +148,7 @@ fn vote_with_assets_should_work() {
 
 		// Vote on poll
 		let v = 3u8;
-		assert_ok!(Polls::vote(RuntimeOrigin::signed(voter), PollCurrency::Asset(0), pid, v.clone()));
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(voter), PollCurrency::Asset(0), pid, Ballot::Single(v), Conviction::None));
 
 		next_block();
 
@@ -107,7 +158,7 @@ fn vote_with_assets_should_work() {
 
 		// Check if winning option is correct.
 		let poll = Polls::poll_details_of(PollCurrency::Asset(0), pid).unwrap();
-		if let PollStatus::Finished { winning_option, end } = poll.status {
+		if let PollStatus::Finished { winning_option, end, .. } = poll.status {
 			assert_eq!(winning_option, Some(3));
 			assert_eq!(end, 10);
 		} else {
@@ -115,3 +166,1200 @@ fn vote_with_assets_should_work() {
 		}
 	});
 } 
+
+#[test]
+fn vote_locks_native_balance_until_poll_ends() {
+	new_test_ext().execute_with(|| {
+		let voter = 2;
+		set_balances(voter);
+		let pid = begin_poll(1);
+
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(voter), PollCurrency::Native, pid, Ballot::Single(1), Conviction::None));
+		assert_eq!(Polls::vote_locks_of(voter).len(), 1);
+
+		// Locked balance can't be moved to vote again from another account.
+		assert_noop!(
+			Balances::transfer(RuntimeOrigin::signed(voter), 3, 10),
+			pallet_balances::Error::<Test>::LiquidityRestrictions,
+		);
+		assert_noop!(
+			Polls::unlock(RuntimeOrigin::signed(voter), PollCurrency::Native, pid),
+			Error::<Test>::PollStillOngoing,
+		);
+
+		fast_forward_to(11);
+
+		assert_ok!(Polls::unlock(RuntimeOrigin::signed(voter), PollCurrency::Native, pid));
+		System::assert_last_event(Event::Unlocked { who: voter, currency: PollCurrency::Native, poll_id: pid, amount: 20 }.into());
+		assert!(Polls::vote_locks_of(voter).is_empty());
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(voter), 3, 10));
+
+		assert_noop!(
+			Polls::unlock(RuntimeOrigin::signed(voter), PollCurrency::Native, pid),
+			Error::<Test>::NotLocked,
+		);
+	});
+}
+
+#[test]
+fn conviction_multiplies_votes_and_extends_lock() {
+	new_test_ext().execute_with(|| {
+		let voter = 2;
+		set_balances(voter);
+		let pid = begin_poll(1);
+
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(voter), PollCurrency::Native, pid, Ballot::Single(0), Conviction::Locked3x));
+		assert_eq!(Polls::poll_details_of(PollCurrency::Native, pid).unwrap().votes, Votes(vec![60, 0, 0, 0]));
+
+		// Poll ends at 10, Locked3x keeps the balance locked for 2 periods of 10 blocks.
+		fast_forward_to(29);
+		assert_noop!(
+			Polls::unlock(RuntimeOrigin::signed(voter), PollCurrency::Native, pid),
+			Error::<Test>::VoteStillLocked,
+		);
+
+		next_block();
+		assert_ok!(Polls::unlock(RuntimeOrigin::signed(voter), PollCurrency::Native, pid));
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(voter), 3, 10));
+	});
+}
+
+#[test]
+fn vote_holds_assets_until_unlock() {
+	new_test_ext().execute_with(|| {
+		let voter = 2;
+		set_balances(voter);
+		let (pid, asset_id) = begin_poll_with_asset(1, voter, 20);
+
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(voter), PollCurrency::Asset(asset_id), pid, Ballot::Single(0), Conviction::None));
+		assert_eq!(Assets::balance(asset_id, voter), 0);
+		assert_eq!(Assets::balance(asset_id, Polls::account_id()), 20);
+
+		fast_forward_to(11);
+
+		assert_ok!(Polls::unlock(RuntimeOrigin::signed(voter), PollCurrency::Asset(asset_id), pid));
+		assert_eq!(Assets::balance(asset_id, voter), 20);
+		assert_eq!(Assets::balance(asset_id, Polls::account_id()), 0);
+	});
+}
+
+#[test]
+fn votes_in_cancelled_poll_are_unlocked_right_away() {
+	new_test_ext().execute_with(|| {
+		let voter = 2;
+		set_balances(voter);
+		let pid = begin_poll(1);
+
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(voter), PollCurrency::Native, pid, Ballot::Single(0), Conviction::Locked6x));
+		assert_ok!(Polls::emergency_cancel(RuntimeOrigin::signed(1), PollCurrency::Native, pid));
+
+		assert_ok!(Polls::unlock(RuntimeOrigin::signed(voter), PollCurrency::Native, pid));
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(voter), 3, 10));
+	});
+}
+
+#[test]
+fn change_vote_moves_votes_to_new_option() {
+	new_test_ext().execute_with(|| {
+		let voter = 2;
+		set_balances(voter);
+		let pid = begin_poll(1);
+
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(voter), PollCurrency::Native, pid, Ballot::Single(0), Conviction::Locked2x));
+		assert_ok!(Polls::change_vote(RuntimeOrigin::signed(voter), PollCurrency::Native, pid, Ballot::Single(3)));
+
+		System::assert_last_event(Event::VoteChanged { voter, currency: PollCurrency::Native, poll_id: pid, vote: Ballot::Single(3) }.into());
+		assert_eq!(Polls::poll_details_of(PollCurrency::Native, pid).unwrap().votes, Votes(vec![0, 0, 0, 40]));
+		assert_eq!(Polls::voting_of((voter, pid)), Some(AccountVote { ballot: Ballot::Single(3), votes: 40 }));
+
+		assert_noop!(
+			Polls::change_vote(RuntimeOrigin::signed(voter), PollCurrency::Native, pid, Ballot::Single(4)),
+			Error::<Test>::InvalidPollVote,
+		);
+		assert_noop!(
+			Polls::change_vote(RuntimeOrigin::signed(3), PollCurrency::Native, pid, Ballot::Single(1)),
+			Error::<Test>::NotVoted,
+		);
+
+		fast_forward_to(11);
+
+		assert_noop!(
+			Polls::change_vote(RuntimeOrigin::signed(voter), PollCurrency::Native, pid, Ballot::Single(1)),
+			Error::<Test>::PollAlreadyFinished,
+		);
+	});
+}
+
+#[test]
+fn remove_vote_backs_out_votes_and_unlocks() {
+	new_test_ext().execute_with(|| {
+		let voter = 2;
+		set_balances(voter);
+		let pid = begin_poll(1);
+
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(voter), PollCurrency::Native, pid, Ballot::Single(1), Conviction::Locked6x));
+		assert_ok!(Polls::remove_vote(RuntimeOrigin::signed(voter), PollCurrency::Native, pid));
+
+		System::assert_last_event(Event::VoteRemoved { voter, currency: PollCurrency::Native, poll_id: pid }.into());
+		assert_eq!(Polls::poll_details_of(PollCurrency::Native, pid).unwrap().votes, Votes(vec![0, 0, 0, 0]));
+		assert_eq!(Polls::voting_of((voter, pid)), None);
+		assert!(Polls::vote_locks_of(voter).is_empty());
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(voter), 3, 10));
+
+		assert_noop!(
+			Polls::remove_vote(RuntimeOrigin::signed(voter), PollCurrency::Native, pid),
+			Error::<Test>::NotVoted,
+		);
+
+		// The account can vote again after removing its vote.
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(voter), PollCurrency::Native, pid, Ballot::Single(2), Conviction::None));
+		assert_eq!(Polls::poll_details_of(PollCurrency::Native, pid).unwrap().votes, Votes(vec![0, 0, 20, 0]));
+	});
+}
+
+#[test]
+fn remove_vote_returns_held_assets() {
+	new_test_ext().execute_with(|| {
+		let voter = 2;
+		set_balances(voter);
+		let (pid, asset_id) = begin_poll_with_asset(1, voter, 20);
+
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(voter), PollCurrency::Asset(asset_id), pid, Ballot::Single(0), Conviction::None));
+		assert_ok!(Polls::remove_vote(RuntimeOrigin::signed(voter), PollCurrency::Asset(asset_id), pid));
+
+		assert_eq!(Assets::balance(asset_id, voter), 20);
+		assert_eq!(Assets::balance(asset_id, Polls::account_id()), 0);
+		assert_eq!(Polls::poll_details_of(PollCurrency::Asset(asset_id), pid).unwrap().votes, Votes(vec![0, 0, 0, 0]));
+	});
+}
+
+#[test]
+fn assets_held_for_a_vote_count_in_other_polls() {
+	new_test_ext().execute_with(|| {
+		let voter = 2;
+		set_balances(voter);
+		let (first, asset_id) = begin_poll_with_asset(1, voter, 20);
+		assert_ok!(Polls::create_poll(
+			RuntimeOrigin::signed(1),
+			(0..46).collect(),
+			4,
+			BallotType::SingleChoice,
+			PollCurrency::Asset(asset_id),
+			3,
+			20,
+			0,
+			PollThresholds::default(),
+			None,
+			false,
+		));
+		let second = first + 1;
+		fast_forward_to(3);
+
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(voter), PollCurrency::Asset(asset_id), first, Ballot::Single(0), Conviction::None));
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(voter), PollCurrency::Asset(asset_id), second, Ballot::Single(1), Conviction::None));
+
+		assert_eq!(Polls::poll_details_of(PollCurrency::Asset(asset_id), first).unwrap().votes, Votes(vec![20, 0, 0, 0]));
+		assert_eq!(Polls::poll_details_of(PollCurrency::Asset(asset_id), second).unwrap().votes, Votes(vec![0, 20, 0, 0]));
+		assert_eq!(Assets::balance(asset_id, voter), 0);
+		assert_eq!(Assets::balance(asset_id, Polls::account_id()), 20);
+
+		// The assets stay held while the vote in the second poll is locked.
+		fast_forward_to(11);
+		assert_ok!(Polls::unlock(RuntimeOrigin::signed(voter), PollCurrency::Asset(asset_id), first));
+		assert_eq!(Assets::balance(asset_id, voter), 0);
+		assert_eq!(Assets::balance(asset_id, Polls::account_id()), 20);
+
+		assert_ok!(Polls::remove_vote(RuntimeOrigin::signed(voter), PollCurrency::Asset(asset_id), second));
+		assert_eq!(Assets::balance(asset_id, voter), 20);
+		assert_eq!(Assets::balance(asset_id, Polls::account_id()), 0);
+	});
+}
+
+#[test]
+fn approval_ballot_counts_for_every_option() {
+	new_test_ext().execute_with(|| {
+		set_balances(2);
+		set_balances(3);
+		let pid = begin_poll_with_ballot(1, BallotType::Approval);
+
+		assert_noop!(
+			Polls::vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid, Ballot::Single(0), Conviction::None),
+			Error::<Test>::InvalidBallotType,
+		);
+		assert_noop!(
+			Polls::vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid, Ballot::Approval(vec![]), Conviction::None),
+			Error::<Test>::InvalidBallot,
+		);
+		assert_noop!(
+			Polls::vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid, Ballot::Approval(vec![1, 1]), Conviction::None),
+			Error::<Test>::InvalidBallot,
+		);
+
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid, Ballot::Approval(vec![0, 2]), Conviction::None));
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(3), PollCurrency::Native, pid, Ballot::Approval(vec![2]), Conviction::None));
+		assert_eq!(Polls::poll_details_of(PollCurrency::Native, pid).unwrap().votes, Votes(vec![20, 0, 40, 0]));
+
+		fast_forward_to(11);
+
+		assert_eq!(
+			Polls::poll_details_of(PollCurrency::Native, pid).unwrap().status,
+			PollStatus::Finished {
+				winning_option: Some(2),
+				end: 10,
+				result: PollResult { votes: Votes(vec![20, 0, 40, 0]), rounds: vec![] },
+			},
+		);
+	});
+}
+
+#[test]
+fn quadratic_ballot_counts_square_root_of_credits() {
+	new_test_ext().execute_with(|| {
+		set_balances(2);
+		let pid = begin_poll_with_ballot(1, BallotType::Quadratic);
+
+		assert_noop!(
+			Polls::vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid, Ballot::Quadratic(vec![(0, 16), (1, 5)]), Conviction::None),
+			Error::<Test>::InsufficientCredits,
+		);
+
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid, Ballot::Quadratic(vec![(0, 16), (1, 4)]), Conviction::None));
+		assert_eq!(Polls::poll_details_of(PollCurrency::Native, pid).unwrap().votes, Votes(vec![4, 2, 0, 0]));
+
+		// Removing the vote backs out the same square roots.
+		assert_ok!(Polls::remove_vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid));
+		assert_eq!(Polls::poll_details_of(PollCurrency::Native, pid).unwrap().votes, Votes(vec![0, 0, 0, 0]));
+	});
+}
+
+#[test]
+fn ranked_choice_poll_is_tallied_by_instant_runoff() {
+	new_test_ext().execute_with(|| {
+		set_balances(2);
+		set_balances(3);
+		set_balances(4);
+		let pid = begin_poll_with_ballot(1, BallotType::RankedChoice);
+
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid, Ballot::Ranked(vec![0]), Conviction::Locked3x));
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(3), PollCurrency::Native, pid, Ballot::Ranked(vec![1, 0]), Conviction::Locked2x));
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(4), PollCurrency::Native, pid, Ballot::Ranked(vec![2, 1]), Conviction::Locked2x));
+
+		// Only first preferences are counted until the poll ends.
+		assert_eq!(Polls::poll_details_of(PollCurrency::Native, pid).unwrap().votes, Votes(vec![60, 40, 40, 0]));
+
+		// Ballots are counted two per block, so each round takes two blocks and voting is over.
+		fast_forward_to(11);
+		assert!(Polls::poll_details_of(PollCurrency::Native, pid).unwrap().status.is_ongoing());
+		assert_eq!(Polls::ranked_tally_of(pid).unwrap().rounds, vec![Votes(vec![60, 40, 40, 0])]);
+		assert_noop!(
+			Polls::remove_vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid),
+			Error::<Test>::PollAlreadyFinished
+		);
+
+		fast_forward_to(15);
+
+		// Option 3 has no votes, then option 2 is eliminated on a tie with option 1 and its
+		// votes go to option 1, which gets a majority.
+		assert_eq!(
+			Polls::poll_details_of(PollCurrency::Native, pid).unwrap().status,
+			PollStatus::Finished {
+				winning_option: Some(1),
+				end: 10,
+				result: PollResult {
+					votes: Votes(vec![60, 80, 0, 0]),
+					rounds: vec![
+						Votes(vec![60, 40, 40, 0]),
+						Votes(vec![60, 40, 40, 0]),
+						Votes(vec![60, 80, 0, 0]),
+					],
+				},
+			},
+		);
+		assert_eq!(Polls::ranked_tally_of(pid), None);
+	});
+}
+
+#[test]
+fn changing_ranked_ballot_updates_rankings() {
+	new_test_ext().execute_with(|| {
+		set_balances(2);
+		set_balances(3);
+		let pid = begin_poll_with_ballot(1, BallotType::RankedChoice);
+
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid, Ballot::Ranked(vec![0, 1]), Conviction::None));
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(3), PollCurrency::Native, pid, Ballot::Ranked(vec![0, 1]), Conviction::None));
+		assert_eq!((Polls::ranked_voter_of(pid, 2), Polls::ranked_voter_of(pid, 3)), (Some(()), Some(())));
+
+		assert_ok!(Polls::change_vote(RuntimeOrigin::signed(3), PollCurrency::Native, pid, Ballot::Ranked(vec![3])));
+		assert_eq!(Polls::voting_of((3, pid)), Some(AccountVote { ballot: Ballot::Ranked(vec![3]), votes: 20 }));
+		assert_eq!(Polls::poll_details_of(PollCurrency::Native, pid).unwrap().votes, Votes(vec![20, 0, 0, 20]));
+
+		assert_ok!(Polls::remove_vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid));
+		assert_eq!(Polls::ranked_voter_of(pid, 2), None);
+
+		fast_forward_to(11);
+		assert!(matches!(
+			Polls::poll_details_of(PollCurrency::Native, pid).unwrap().status,
+			PollStatus::Finished { winning_option: Some(3), .. }
+		));
+	});
+}
+
+#[test]
+fn instant_runoff_without_majority_is_a_tie() {
+	let (winning_option, rounds) = Votes::instant_runoff(3, &[(vec![0], 10), (vec![1], 10)]);
+
+	assert_eq!(winning_option, None);
+	assert_eq!(rounds, vec![Votes(vec![10, 10, 0]), Votes(vec![10, 10, 0])]);
+}
+
+#[test]
+fn poll_without_enough_voters_fails() {
+	new_test_ext().execute_with(|| {
+		let voter = 2;
+		set_balances(voter);
+		let thresholds = PollThresholds { min_voters: Some(2), ..Default::default() };
+		let pid = begin_poll_with(1, BallotType::SingleChoice, thresholds.clone());
+
+		assert_eq!(Polls::poll_details_of(PollCurrency::Native, pid).unwrap().thresholds, thresholds);
+
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(voter), PollCurrency::Native, pid, Ballot::Single(0), Conviction::None));
+
+		fast_forward_to(11);
+
+		assert_eq!(Polls::poll_details_of(PollCurrency::Native, pid).unwrap().status, PollStatus::Failed(10));
+		System::assert_has_event(Event::PollFailed { currency: PollCurrency::Native, poll_id: pid, thresholds }.into());
+
+		// Votes in failed polls are unlocked like in finished ones.
+		assert_ok!(Polls::unlock(RuntimeOrigin::signed(voter), PollCurrency::Native, pid));
+	});
+}
+
+#[test]
+fn poll_without_enough_turnout_fails() {
+	new_test_ext().execute_with(|| {
+		set_balances(2);
+		set_balances(3);
+		let thresholds = PollThresholds { min_turnout: Some(50), ..Default::default() };
+		let pid = begin_poll_with(1, BallotType::SingleChoice, thresholds);
+
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid, Ballot::Single(0), Conviction::None));
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(3), PollCurrency::Native, pid, Ballot::Single(0), Conviction::None));
+		assert_eq!(Polls::poll_details_of(PollCurrency::Native, pid).unwrap().turnout, 40);
+		assert_eq!(Polls::poll_details_of(PollCurrency::Native, pid).unwrap().voters, 2);
+
+		fast_forward_to(11);
+
+		assert_eq!(Polls::poll_details_of(PollCurrency::Native, pid).unwrap().status, PollStatus::Failed(10));
+	});
+}
+
+#[test]
+fn winning_option_below_approval_threshold_fails() {
+	new_test_ext().execute_with(|| {
+		set_balances(2);
+		set_balances(3);
+		set_balances(4);
+		let thresholds = PollThresholds { approval: Some(Perbill::from_percent(51)), ..Default::default() };
+		let pid = begin_poll_with(1, BallotType::SingleChoice, thresholds);
+
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid, Ballot::Single(0), Conviction::None));
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(3), PollCurrency::Native, pid, Ballot::Single(1), Conviction::Locked2x));
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(4), PollCurrency::Native, pid, Ballot::Single(2), Conviction::None));
+
+		fast_forward_to(11);
+
+		// Option 1 has 40 of 80 votes.
+		assert_eq!(Polls::poll_details_of(PollCurrency::Native, pid).unwrap().status, PollStatus::Failed(10));
+	});
+}
+
+#[test]
+fn winning_option_with_approval_threshold_finishes() {
+	new_test_ext().execute_with(|| {
+		set_balances(2);
+		set_balances(3);
+		set_balances(4);
+		let thresholds = PollThresholds {
+			min_turnout: Some(80),
+			min_voters: Some(3),
+			approval: Some(Perbill::from_percent(50)),
+		};
+		let pid = begin_poll_with(1, BallotType::SingleChoice, thresholds);
+
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid, Ballot::Single(0), Conviction::None));
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(3), PollCurrency::Native, pid, Ballot::Single(1), Conviction::Locked2x));
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(4), PollCurrency::Native, pid, Ballot::Single(2), Conviction::None));
+
+		fast_forward_to(11);
+
+		let poll = Polls::poll_details_of(PollCurrency::Native, pid).unwrap();
+		assert!(matches!(poll.status, PollStatus::Finished { winning_option: Some(1), .. }));
+		System::assert_has_event(Event::Finished { currency: PollCurrency::Native, poll_id: pid }.into());
+	});
+}
+
+#[test]
+fn outcome_of_winning_option_is_dispatched_by_token_holders() {
+	new_test_ext().execute_with(|| {
+		let voter = 2;
+		set_balances(voter);
+		System::set_block_number(1);
+		assert_ok!(Polls::create_poll(
+			RuntimeOrigin::signed(1),
+			(0..46).collect(),
+			2,
+			BallotType::SingleChoice,
+			PollCurrency::Native,
+			2,
+			10,
+			0,
+			PollThresholds { min_turnout: Some(20), ..Default::default() },
+			None,
+			false,
+		));
+		let pid = Polls::poll_count();
+
+		let remark = |len: usize| Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![1; len] }));
+		assert_noop!(
+			Polls::set_outcome(RuntimeOrigin::signed(voter), PollCurrency::Native, pid, 1, Some(remark(1))),
+			Error::<Test>::NotPollCreator,
+		);
+		assert_noop!(
+			Polls::set_outcome(RuntimeOrigin::signed(1), PollCurrency::Native, pid, 2, Some(remark(1))),
+			Error::<Test>::InvalidPollVote,
+		);
+		assert_noop!(
+			Polls::set_outcome(RuntimeOrigin::signed(1), PollCurrency::Native, pid, 1, Some(remark(200))),
+			Error::<Test>::OutcomeTooLong,
+		);
+		assert_ok!(Polls::set_outcome(RuntimeOrigin::signed(1), PollCurrency::Native, pid, 0, Some(remark(1))));
+		assert_ok!(Polls::set_outcome(RuntimeOrigin::signed(1), PollCurrency::Native, pid, 1, Some(remark(1))));
+
+		fast_forward_to(2);
+		assert_noop!(
+			Polls::set_outcome(RuntimeOrigin::signed(1), PollCurrency::Native, pid, 1, None),
+			Error::<Test>::PollAlreadyStarted,
+		);
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(voter), PollCurrency::Native, pid, Ballot::Single(1), Conviction::None));
+
+		fast_forward_to(10);
+		System::assert_has_event(Event::OutcomeScheduled { currency: PollCurrency::Native, poll_id: pid, option: 1 }.into());
+		assert_eq!(Polls::outcome_of(pid, 0), None);
+		assert_eq!(Polls::outcome_of(pid, 1), None);
+
+		// The outcome is dispatched in the next block.
+		next_block();
+		let name = sp_io::hashing::blake2_256(&(OUTCOME_ID, PollCurrency::<u32>::Native, pid).encode());
+		assert!(System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::Scheduler(pallet_scheduler::Event::Dispatched { id: Some(id), result: Ok(()), .. }) if id == name
+		)));
+	});
+}
+
+#[test]
+fn outcome_the_scheduler_rejects_is_reported() {
+	new_test_ext().execute_with(|| {
+		let voter = 2;
+		set_balances(voter);
+		let pid = begin_poll(1);
+
+		// Longer than the scheduler bounds inline, as if `MaxOutcomeLen` was lowered after it was set.
+		let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![1; 200] });
+		Outcomes::<Test>::insert(pid, 1, remark);
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(voter), PollCurrency::Native, pid, Ballot::Single(1), Conviction::None));
+
+		fast_forward_to(10);
+		System::assert_has_event(Event::OutcomeNotScheduled { currency: PollCurrency::Native, poll_id: pid, option: 1 }.into());
+		assert_eq!(Polls::outcome_of(pid, 1), None);
+		assert!(matches!(
+			Polls::poll_details_of(PollCurrency::Native, pid).unwrap().status,
+			PollStatus::Finished { winning_option: Some(1), .. }
+		));
+	});
+}
+
+#[test]
+fn outcome_requires_min_turnout_of_issuance() {
+	new_test_ext().execute_with(|| {
+		let voter = 2;
+		set_balances(voter);
+		System::set_block_number(1);
+		// Half of the issuance of 21 must vote.
+		let thresholds = |min_turnout| PollThresholds { min_turnout, ..Default::default() };
+		assert_ok!(Polls::create_poll(
+			RuntimeOrigin::signed(1),
+			(0..46).collect(),
+			2,
+			BallotType::SingleChoice,
+			PollCurrency::Native,
+			2,
+			10,
+			0,
+			thresholds(Some(9)),
+			None,
+			false,
+		));
+		let pid = Polls::poll_count();
+		let remark = || Some(Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![1] })));
+		let update = |min_turnout| {
+			Polls::update_poll(
+				RuntimeOrigin::signed(1),
+				pid,
+				(0..46).collect(),
+				2,
+				BallotType::SingleChoice,
+				PollCurrency::Native,
+				2,
+				10,
+				0,
+				thresholds(min_turnout),
+				None,
+			)
+		};
+
+		assert_noop!(
+			Polls::set_outcome(RuntimeOrigin::signed(1), PollCurrency::Native, pid, 1, remark()),
+			Error::<Test>::OutcomeTurnoutTooLow,
+		);
+		assert_ok!(update(Some(10)));
+		assert_ok!(Polls::set_outcome(RuntimeOrigin::signed(1), PollCurrency::Native, pid, 1, remark()));
+		assert_noop!(update(None), Error::<Test>::OutcomeTurnoutTooLow);
+
+		fast_forward_to(2);
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(voter), PollCurrency::Native, pid, Ballot::Single(1), Conviction::None));
+
+		// The issuance grows past twice the turnout while the poll runs.
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), 3, 30, 0));
+
+		fast_forward_to(10);
+		assert!(matches!(
+			Polls::poll_details_of(PollCurrency::Native, pid).unwrap().status,
+			PollStatus::Finished { winning_option: Some(1), .. }
+		));
+		System::assert_has_event(Event::OutcomeNotScheduled { currency: PollCurrency::Native, poll_id: pid, option: 1 }.into());
+		assert_eq!(Polls::outcome_of(pid, 1), None);
+	});
+}
+
+#[test]
+fn outcomes_of_failed_poll_are_cleared() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Polls::create_poll(
+			RuntimeOrigin::signed(1),
+			(0..46).collect(),
+			2,
+			BallotType::SingleChoice,
+			PollCurrency::Native,
+			2,
+			10,
+			0,
+			PollThresholds { min_voters: Some(1), ..Default::default() },
+			None,
+			false,
+		));
+		let pid = Polls::poll_count();
+		let remark = Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![1] }));
+		assert_ok!(Polls::set_outcome(RuntimeOrigin::signed(1), PollCurrency::Native, pid, 0, Some(remark)));
+
+		fast_forward_to(11);
+
+		assert_eq!(Polls::poll_details_of(PollCurrency::Native, pid).unwrap().status, PollStatus::Failed(10));
+		assert_eq!(Polls::outcome_of(pid, 0), None);
+		assert!(!System::events()
+			.iter()
+			.any(|record| matches!(record.event, RuntimeEvent::Polls(Event::OutcomeScheduled { .. }))));
+	});
+}
+
+#[test]
+fn ensure_token_holders_only_accepts_token_holders() {
+	new_test_ext().execute_with(|| {
+		let origin = RuntimeOrigin::from(RawOrigin::TokenHolders(PollCurrency::Asset(0)));
+
+		assert_eq!(EnsureTokenHolders::<Test>::try_origin(origin.clone()).ok(), Some(PollCurrency::Asset(0)));
+		assert!(EnsureNativeTokenHolders::<Test>::try_origin(origin).is_err());
+		let native = RuntimeOrigin::from(RawOrigin::TokenHolders(PollCurrency::Native));
+		assert_eq!(EnsureNativeTokenHolders::<Test>::try_origin(native).ok(), Some(()));
+		assert!(EnsureNativeTokenHolders::<Test>::try_origin(RuntimeOrigin::signed(1)).is_err());
+		assert!(EnsureTokenHolders::<Test>::try_origin(RuntimeOrigin::signed(1)).is_err());
+		assert!(EnsureTokenHolders::<Test>::try_origin(RuntimeOrigin::root()).is_err());
+	});
+}
+
+#[test]
+fn polls_are_queried_by_creator_and_currency() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), 0, 1, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 10));
+		for (creator, currency) in [
+			(1, PollCurrency::Native),
+			(2, PollCurrency::Native),
+			(1, PollCurrency::Asset(0)),
+			(1, PollCurrency::Native),
+		] {
+			assert_ok!(Polls::create_poll(
+				RuntimeOrigin::signed(creator),
+				(0..46).collect(),
+				4,
+				BallotType::SingleChoice,
+				currency,
+				2,
+				10,
+				0,
+				PollThresholds::default(),
+				None,
+				false,
+			));
+		}
+		assert_ok!(Polls::emergency_cancel(RuntimeOrigin::signed(1), PollCurrency::Native, 4));
+
+		let ids = |polls: Vec<PollInfo<_, _, _, _, _>>| polls.into_iter().map(|poll| poll.poll_id).collect::<Vec<_>>();
+
+		assert_eq!(ids(Polls::polls_of_creator(1, None, None, 0, 10)), vec![1, 3, 4]);
+		assert_eq!(ids(Polls::polls_of_creator(2, None, None, 0, 10)), vec![2]);
+		assert_eq!(ids(Polls::polls_of_currency(PollCurrency::Native, None, None, 0, 10)), vec![1, 2, 4]);
+		assert_eq!(ids(Polls::polls_of_currency(PollCurrency::Asset(0), None, None, 0, 10)), vec![3]);
+
+		// Filter by status.
+		assert_eq!(ids(Polls::polls_of_creator(1, Some(PollStatusFilter::Ongoing), None, 0, 10)), vec![1, 3]);
+		assert_eq!(ids(Polls::polls_of_creator(1, Some(PollStatusFilter::Cancelled), None, 0, 10)), vec![4]);
+		assert!(Polls::polls_of_creator(1, Some(PollStatusFilter::Finished), None, 0, 10).is_empty());
+
+		// Pages.
+		assert_eq!(ids(Polls::polls_of_creator(1, None, None, 1, 1)), vec![3]);
+		assert_eq!(ids(Polls::polls_of_currency(PollCurrency::Native, None, None, 2, 10)), vec![4]);
+		assert!(Polls::polls_of_currency(PollCurrency::Native, None, None, 3, 10).is_empty());
+
+		// Polls are indexed in the order they were created, pages count the polls matching the status.
+		assert_eq!((Polls::creator_poll_count(1), Polls::currency_poll_count(PollCurrency::Native)), (3, 3));
+		assert_eq!(Polls::polls_by_creator(1, 1), Some((PollCurrency::Asset(0), 3)));
+		assert_eq!(Polls::polls_by_currency(PollCurrency::Native, 1), Some(2));
+		assert_eq!(ids(Polls::polls_of_creator(1, Some(PollStatusFilter::Ongoing), None, 1, 2)), vec![3]);
+		assert_eq!(ids(Polls::polls_of_creator(1, Some(PollStatusFilter::Cancelled), None, 0, 1)), vec![4]);
+		assert_eq!(ids(Polls::polls_of_currency(PollCurrency::Native, Some(PollStatusFilter::Ongoing), None, 1, 1)), vec![2]);
+		assert!(Polls::polls_of_currency(PollCurrency::Native, Some(PollStatusFilter::Cancelled), None, 1, 10).is_empty());
+	});
+}
+
+#[test]
+fn queried_polls_include_tally_and_vote_of_voter() {
+	new_test_ext().execute_with(|| {
+		let pid = begin_poll(1);
+		set_balances(2);
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid, Ballot::Single(1), Conviction::None));
+
+		let poll = Polls::poll_info(PollCurrency::Native, pid, Some(2)).unwrap();
+		assert_eq!(poll.details.votes, Polls::poll_details_of(PollCurrency::Native, pid).unwrap().votes);
+		assert_eq!(poll.vote, Polls::voting_of((2, pid)));
+		assert!(poll.vote.is_some());
+		assert_eq!(Polls::poll_info(PollCurrency::Native, pid, Some(3)).unwrap().vote, None);
+		assert_eq!(Polls::poll_info(PollCurrency::Native, pid, None).unwrap().vote, None);
+		assert_eq!(Polls::poll_info(PollCurrency::Native, pid + 1, Some(2)), None);
+
+		let polls = Polls::polls_of_currency(PollCurrency::Native, None, Some(2), 0, 10);
+		assert_eq!(polls, vec![poll]);
+	});
+}
+
+#[test]
+fn only_revealed_votes_are_counted() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Polls::create_poll(
+			RuntimeOrigin::signed(1),
+			(0..46).collect(),
+			4,
+			BallotType::SingleChoice,
+			PollCurrency::Native,
+			2,
+			10,
+			0,
+			PollThresholds::default(),
+			Some(5),
+			false,
+		));
+		let pid = Polls::poll_count();
+		set_balances(2);
+		set_balances(3);
+		fast_forward_to(2);
+
+		assert_noop!(
+			Polls::vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid, Ballot::Single(1), Conviction::None),
+			Error::<Test>::CommitRevealPoll
+		);
+
+		let commitment = Polls::commitment_hash(&2, pid, &Ballot::Single(1), &[1; 32]);
+		assert_ok!(Polls::commit_vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid, commitment, Conviction::None));
+		let commitment = Polls::commitment_hash(&3, pid, &Ballot::Single(2), &[2; 32]);
+		assert_ok!(Polls::commit_vote(RuntimeOrigin::signed(3), PollCurrency::Native, pid, commitment, Conviction::Locked2x));
+		assert_noop!(
+			Polls::commit_vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid, commitment, Conviction::None),
+			Error::<Test>::AlreadyVoted
+		);
+
+		// The tally stays empty while voting.
+		let poll = Polls::poll_details_of(PollCurrency::Native, pid).unwrap();
+		assert_eq!(poll.votes, Votes::new(4));
+		assert_eq!((poll.turnout, poll.voters, poll.commitments), (0, 0, 2));
+		assert_eq!(Polls::commitment_of(pid, 3).map(|c| c.votes), Some(40));
+		assert_noop!(
+			Polls::reveal_vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid, Ballot::Single(1), [1; 32]),
+			Error::<Test>::NotRevealPeriod
+		);
+
+		fast_forward_to(10);
+		assert_noop!(
+			Polls::commit_vote(RuntimeOrigin::signed(4), PollCurrency::Native, pid, commitment, Conviction::None),
+			Error::<Test>::PollAlreadyFinished
+		);
+		assert_noop!(
+			Polls::reveal_vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid, Ballot::Single(1), [2; 32]),
+			Error::<Test>::InvalidReveal
+		);
+		assert_noop!(
+			Polls::reveal_vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid, Ballot::Single(2), [1; 32]),
+			Error::<Test>::InvalidReveal
+		);
+		assert_ok!(Polls::reveal_vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid, Ballot::Single(1), [1; 32]));
+		assert_eq!(Polls::voting_of((2, pid)), Some(AccountVote { ballot: Ballot::Single(1), votes: 20 }));
+		assert_eq!(Polls::poll_details_of(PollCurrency::Native, pid).unwrap().votes.0, vec![0, 20, 0, 0]);
+
+		// Tallied once the reveal period ends, without the vote of 3.
+		fast_forward_to(15);
+		System::assert_has_event(Event::CommitmentDropped { voter: 3, currency: PollCurrency::Native, poll_id: pid }.into());
+		assert_eq!(Polls::commitment_of(pid, 3), None);
+		let poll = Polls::poll_details_of(PollCurrency::Native, pid).unwrap();
+		assert_eq!((poll.turnout, poll.voters, poll.commitments), (20, 1, 0));
+		assert!(matches!(poll.status, PollStatus::Finished { winning_option: Some(1), end: 10, .. }));
+
+		// The balance of the dropped vote stays locked as committed.
+		assert_noop!(
+			Polls::unlock(RuntimeOrigin::signed(3), PollCurrency::Native, pid),
+			Error::<Test>::VoteStillLocked
+		);
+		fast_forward_to(20);
+		assert_ok!(Polls::unlock(RuntimeOrigin::signed(3), PollCurrency::Native, pid));
+	});
+}
+
+#[test]
+fn commitments_are_bound_to_account_and_poll() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for _ in 0..2 {
+			assert_ok!(Polls::create_poll(
+				RuntimeOrigin::signed(1),
+				(0..46).collect(),
+				4,
+				BallotType::SingleChoice,
+				PollCurrency::Native,
+				2,
+				10,
+				0,
+				PollThresholds::default(),
+				Some(5),
+				false,
+			));
+		}
+		set_balances(2);
+		set_balances(3);
+		fast_forward_to(2);
+
+		// 3 copies the commitment of 2, and 2 reuses it in another poll.
+		let commitment = Polls::commitment_hash(&2, 1, &Ballot::Single(1), &[1; 32]);
+		assert_ok!(Polls::commit_vote(RuntimeOrigin::signed(2), PollCurrency::Native, 1, commitment, Conviction::None));
+		assert_ok!(Polls::commit_vote(RuntimeOrigin::signed(3), PollCurrency::Native, 1, commitment, Conviction::None));
+		assert_ok!(Polls::commit_vote(RuntimeOrigin::signed(2), PollCurrency::Native, 2, commitment, Conviction::None));
+
+		fast_forward_to(10);
+		assert_noop!(
+			Polls::reveal_vote(RuntimeOrigin::signed(3), PollCurrency::Native, 1, Ballot::Single(1), [1; 32]),
+			Error::<Test>::InvalidReveal
+		);
+		assert_noop!(
+			Polls::reveal_vote(RuntimeOrigin::signed(2), PollCurrency::Native, 2, Ballot::Single(1), [1; 32]),
+			Error::<Test>::InvalidReveal
+		);
+		assert_ok!(Polls::reveal_vote(RuntimeOrigin::signed(2), PollCurrency::Native, 1, Ballot::Single(1), [1; 32]));
+	});
+}
+
+#[test]
+fn votes_are_committed_only_to_commit_reveal_polls() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			Polls::create_poll(
+				RuntimeOrigin::signed(1),
+				(0..46).collect(),
+				4,
+				BallotType::SingleChoice,
+				PollCurrency::Native,
+				2,
+				10,
+				0,
+				PollThresholds::default(),
+				Some(0),
+				false,
+			),
+			Error::<Test>::InvalidPollPeriod
+		);
+
+		let pid = begin_poll(1);
+		set_balances(2);
+		let commitment = Polls::commitment_hash(&2, pid, &Ballot::Single(1), &[1; 32]);
+		assert_noop!(
+			Polls::commit_vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid, commitment, Conviction::None),
+			Error::<Test>::NotCommitReveal
+		);
+		assert_noop!(
+			Polls::reveal_vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid, Ballot::Single(1), [1; 32]),
+			Error::<Test>::NotCommitReveal
+		);
+	});
+}
+
+#[test]
+fn delegated_votes_count_unless_delegator_votes() {
+	new_test_ext().execute_with(|| {
+		let pid = begin_poll(1);
+		set_balances(2);
+		set_balances(3);
+		set_balances(4);
+
+		// 4 delegates to 3 in the poll, 3 delegates to 2 in every native poll.
+		assert_ok!(Polls::delegate(RuntimeOrigin::signed(3), PollCurrency::Native, 2, None));
+		assert_ok!(Polls::delegate(RuntimeOrigin::signed(4), PollCurrency::Native, 3, Some(pid)));
+		System::assert_last_event(
+			Event::Delegated { delegator: 4, delegate: 3, currency: PollCurrency::Native, poll_id: Some(pid) }.into(),
+		);
+
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid, Ballot::Single(1), Conviction::None));
+		assert_eq!(Polls::voting_of((2, pid)).map(|v| v.votes), Some(60));
+		assert_eq!(Polls::delegated_votes_of(pid, 2).into_inner(), vec![(3, 20), (4, 20)]);
+		assert_eq!(Polls::counted_by((4, pid)), Some(2));
+		assert!(Polls::vote_locks_of(4).is_empty());
+		let poll = Polls::poll_details_of(PollCurrency::Native, pid).unwrap();
+		assert_eq!((poll.votes.0, poll.turnout, poll.voters), (vec![0, 60, 0, 0], 60, 3));
+
+		// 3 votes directly, with the votes of 4 delegated to it.
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(3), PollCurrency::Native, pid, Ballot::Single(2), Conviction::None));
+		assert_eq!(Polls::voting_of((2, pid)).map(|v| v.votes), Some(20));
+		assert_eq!(Polls::voting_of((3, pid)).map(|v| v.votes), Some(40));
+		assert!(Polls::delegated_votes_of(pid, 2).is_empty());
+		assert_eq!(Polls::counted_by((4, pid)), Some(3));
+		assert!(Polls::vote_locks_of(4).is_empty());
+		let poll = Polls::poll_details_of(PollCurrency::Native, pid).unwrap();
+		assert_eq!((poll.votes.0, poll.turnout, poll.voters), (vec![0, 20, 40, 0], 60, 3));
+
+		// 4 votes directly too.
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(4), PollCurrency::Native, pid, Ballot::Single(3), Conviction::None));
+		assert_eq!(Polls::counted_by((4, pid)), None);
+		let poll = Polls::poll_details_of(PollCurrency::Native, pid).unwrap();
+		assert_eq!((poll.votes.0, poll.turnout, poll.voters), (vec![0, 20, 20, 20], 60, 3));
+	});
+}
+
+#[test]
+fn removing_a_vote_releases_delegators() {
+	new_test_ext().execute_with(|| {
+		let pid = begin_poll(1);
+		set_balances(2);
+		set_balances(3);
+		assert_ok!(Polls::delegate(RuntimeOrigin::signed(3), PollCurrency::Native, 2, None));
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid, Ballot::Single(1), Conviction::None));
+		assert_eq!(Polls::counted_by((3, pid)), Some(2));
+
+		assert_ok!(Polls::remove_vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid));
+
+		assert_eq!(Polls::counted_by((3, pid)), None);
+		let poll = Polls::poll_details_of(PollCurrency::Native, pid).unwrap();
+		assert_eq!((poll.votes.0, poll.turnout, poll.voters), (vec![0, 0, 0, 0], 0, 0));
+	});
+}
+
+#[test]
+fn removing_a_vote_recounts_delegators_in_the_next_vote_up() {
+	new_test_ext().execute_with(|| {
+		let pid = begin_poll(1);
+		for account in 2..5 {
+			set_balances(account);
+		}
+		// 4 delegates to 3, 3 delegates to 2, and both 2 and 3 vote.
+		assert_ok!(Polls::delegate(RuntimeOrigin::signed(3), PollCurrency::Native, 2, None));
+		assert_ok!(Polls::delegate(RuntimeOrigin::signed(4), PollCurrency::Native, 3, None));
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid, Ballot::Single(1), Conviction::None));
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(3), PollCurrency::Native, pid, Ballot::Single(2), Conviction::None));
+		assert_eq!(Polls::voting_of((2, pid)).map(|v| v.votes), Some(20));
+		assert_eq!(Polls::voting_of((3, pid)).map(|v| v.votes), Some(40));
+
+		// 3 and 4 are counted in the vote of 2 once 3 removes its vote.
+		assert_ok!(Polls::remove_vote(RuntimeOrigin::signed(3), PollCurrency::Native, pid));
+		assert_eq!(Polls::voting_of((2, pid)).map(|v| v.votes), Some(60));
+		assert_eq!(Polls::delegated_votes_of(pid, 2).into_inner(), vec![(3, 20), (4, 20)]);
+		assert_eq!(Polls::counted_by((4, pid)), Some(2));
+		let poll = Polls::poll_details_of(PollCurrency::Native, pid).unwrap();
+		assert_eq!((poll.votes.0, poll.turnout, poll.voters), (vec![0, 60, 0, 0], 60, 3));
+	});
+}
+
+#[test]
+fn changing_a_delegation_recounts_open_polls() {
+	new_test_ext().execute_with(|| {
+		let pid = begin_poll(1);
+		for account in 2..6 {
+			set_balances(account);
+		}
+		assert_ok!(Polls::delegate(RuntimeOrigin::signed(3), PollCurrency::Native, 2, None));
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid, Ballot::Single(1), Conviction::None));
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(5), PollCurrency::Native, pid, Ballot::Single(2), Conviction::None));
+		assert_eq!(Polls::voting_of((2, pid)).map(|v| v.votes), Some(40));
+
+		// The delegated balance is counted without being locked.
+		assert!(Polls::vote_locks_of(3).is_empty());
+		assert_ok!(Balances::transfer(RuntimeOrigin::signed(3), 4, 10));
+
+		// Revoking the delegation takes 3 out of the vote of 2.
+		assert_ok!(Polls::undelegate(RuntimeOrigin::signed(3), PollCurrency::Native, None));
+		assert_eq!(Polls::voting_of((2, pid)).map(|v| v.votes), Some(20));
+		assert_eq!(Polls::counted_by((3, pid)), None);
+		let poll = Polls::poll_details_of(PollCurrency::Native, pid).unwrap();
+		assert_eq!((poll.votes.0, poll.turnout, poll.voters), (vec![0, 20, 20, 0], 40, 2));
+
+		// Delegating again counts 3, along with 4 delegating to it.
+		assert_ok!(Polls::delegate(RuntimeOrigin::signed(4), PollCurrency::Native, 3, None));
+		assert_ok!(Polls::delegate(RuntimeOrigin::signed(3), PollCurrency::Native, 2, None));
+		assert_eq!(Polls::voting_of((2, pid)).map(|v| v.votes), Some(60));
+		assert_eq!(Polls::delegated_votes_of(pid, 2).into_inner(), vec![(3, 10), (4, 30)]);
+		let poll = Polls::poll_details_of(PollCurrency::Native, pid).unwrap();
+		assert_eq!((poll.votes.0, poll.turnout, poll.voters), (vec![0, 60, 20, 0], 80, 4));
+
+		// A delegation in the poll moves 3 and 4 to the vote of 5.
+		assert_ok!(Polls::delegate(RuntimeOrigin::signed(3), PollCurrency::Native, 5, Some(pid)));
+		assert_eq!(Polls::voting_of((2, pid)).map(|v| v.votes), Some(20));
+		assert_eq!(Polls::voting_of((5, pid)).map(|v| v.votes), Some(60));
+		assert_eq!(Polls::counted_by((4, pid)), Some(5));
+		let poll = Polls::poll_details_of(PollCurrency::Native, pid).unwrap();
+		assert_eq!((poll.votes.0, poll.turnout, poll.voters), (vec![0, 20, 60, 0], 80, 4));
+	});
+}
+
+#[test]
+fn delegations_are_bounded_and_revocable() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			Polls::delegate(RuntimeOrigin::signed(2), PollCurrency::Native, 2, None),
+			Error::<Test>::DelegationCycle
+		);
+		assert_ok!(Polls::delegate(RuntimeOrigin::signed(2), PollCurrency::Native, 3, None));
+		assert_noop!(
+			Polls::delegate(RuntimeOrigin::signed(3), PollCurrency::Native, 2, None),
+			Error::<Test>::DelegationCycle
+		);
+
+		// Chains are at most 2 delegations deep.
+		assert_ok!(Polls::delegate(RuntimeOrigin::signed(3), PollCurrency::Native, 4, None));
+		assert_noop!(
+			Polls::delegate(RuntimeOrigin::signed(5), PollCurrency::Native, 2, None),
+			Error::<Test>::DelegationTooDeep
+		);
+
+		// A delegate has at most 8 delegators.
+		for delegator in 10..17 {
+			assert_ok!(Polls::delegate(RuntimeOrigin::signed(delegator), PollCurrency::Native, 4, None));
+		}
+		assert_noop!(
+			Polls::delegate(RuntimeOrigin::signed(17), PollCurrency::Native, 4, None),
+			Error::<Test>::TooManyDelegators
+		);
+
+		// Delegating again replaces the delegation.
+		assert_ok!(Polls::delegate(RuntimeOrigin::signed(10), PollCurrency::Native, 5, None));
+		assert_eq!(Polls::delegation_of(10, PollCurrency::Native), Some(5));
+		assert_eq!(Polls::delegators_of(5, PollCurrency::Native).into_inner(), vec![10]);
+		assert_ok!(Polls::delegate(RuntimeOrigin::signed(17), PollCurrency::Native, 4, None));
+
+		assert_ok!(Polls::undelegate(RuntimeOrigin::signed(2), PollCurrency::Native, None));
+		assert_eq!(Polls::delegation_of(2, PollCurrency::Native), None);
+		assert!(Polls::delegators_of(3, PollCurrency::Native).is_empty());
+		assert_noop!(
+			Polls::undelegate(RuntimeOrigin::signed(2), PollCurrency::Native, None),
+			Error::<Test>::NotDelegating
+		);
+	});
+}
+
+#[test]
+fn snapshot_fixes_voting_power_before_start() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Polls::create_poll(
+			RuntimeOrigin::signed(1),
+			(0..46).collect(),
+			4,
+			BallotType::SingleChoice,
+			PollCurrency::Native,
+			3,
+			10,
+			0,
+			PollThresholds::default(),
+			None,
+			true,
+		));
+		let pid = Polls::poll_count();
+		set_balances(2);
+		set_balances(3);
+
+		assert_ok!(Polls::snapshot_balance(RuntimeOrigin::signed(2), PollCurrency::Native, pid));
+		System::assert_last_event(Event::BalanceSnapshotted { who: 2, currency: PollCurrency::Native, poll_id: pid, amount: 20 }.into());
+		assert_eq!(Polls::snapshot_of(pid, 2), Some(20));
+
+		// The snapshotted balance can't be moved to another account.
+		assert_noop!(
+			Balances::transfer(RuntimeOrigin::signed(2), 3, 10),
+			pallet_balances::Error::<Test>::LiquidityRestrictions,
+		);
+
+		fast_forward_to(3);
+		assert_noop!(
+			Polls::snapshot_balance(RuntimeOrigin::signed(3), PollCurrency::Native, pid),
+			Error::<Test>::PollAlreadyStarted
+		);
+		assert_noop!(
+			Polls::vote(RuntimeOrigin::signed(3), PollCurrency::Native, pid, Ballot::Single(0), Conviction::None),
+			Error::<Test>::NoSnapshot
+		);
+
+		// Balance gained while voting is open adds no votes.
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), 2, 50, 0));
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid, Ballot::Single(1), Conviction::Locked2x));
+		assert_eq!(Polls::voting_of((2, pid)).map(|v| v.votes), Some(40));
+		assert_eq!(Polls::vote_locks_of(2).len(), 1);
+
+		// Removing the vote keeps the snapshot locked, to vote again with.
+		assert_ok!(Polls::remove_vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid));
+		assert_eq!(Polls::vote_locks_of(2).into_inner()[0].1.conviction, Conviction::None);
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid, Ballot::Single(2), Conviction::None));
+		assert_eq!(Polls::voting_of((2, pid)).map(|v| v.votes), Some(20));
+
+		fast_forward_to(11);
+		assert_ok!(Polls::unlock(RuntimeOrigin::signed(2), PollCurrency::Native, pid));
+		assert!(Polls::vote_locks_of(2).is_empty());
+	});
+}
+
+#[test]
+fn snapshot_polls_only_count_snapshotted_delegators() {
+	new_test_ext().execute_with(|| {
+		let pid = begin_poll(1);
+		assert_noop!(
+			Polls::snapshot_balance(RuntimeOrigin::signed(2), PollCurrency::Native, pid),
+			Error::<Test>::NotSnapshotPoll
+		);
+
+		assert_ok!(Polls::create_poll(
+			RuntimeOrigin::signed(1),
+			(0..46).collect(),
+			4,
+			BallotType::SingleChoice,
+			PollCurrency::Native,
+			3,
+			10,
+			0,
+			PollThresholds::default(),
+			None,
+			true,
+		));
+		let pid = Polls::poll_count();
+		for account in 2..5 {
+			set_balances(account);
+		}
+		assert_ok!(Polls::snapshot_balance(RuntimeOrigin::signed(2), PollCurrency::Native, pid));
+		assert_ok!(Polls::snapshot_balance(RuntimeOrigin::signed(3), PollCurrency::Native, pid));
+		assert_ok!(Polls::delegate(RuntimeOrigin::signed(3), PollCurrency::Native, 2, None));
+		assert_ok!(Polls::delegate(RuntimeOrigin::signed(4), PollCurrency::Native, 2, None));
+
+		fast_forward_to(3);
+		assert_ok!(Polls::vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid, Ballot::Single(1), Conviction::None));
+		assert_eq!(Polls::voting_of((2, pid)).map(|v| v.votes), Some(40));
+		assert_eq!(Polls::delegated_votes_of(pid, 2).into_inner(), vec![(3, 20)]);
+		assert!(Polls::vote_locks_of(4).is_empty());
+
+		// Released delegators keep their snapshot locked.
+		assert_ok!(Polls::remove_vote(RuntimeOrigin::signed(2), PollCurrency::Native, pid));
+		assert_eq!(Polls::counted_by((3, pid)), None);
+		assert_eq!(Polls::vote_locks_of(3).len(), 1);
+	});
+}
+
+#[test]
+fn migration_to_v1_keeps_polls_and_votes() {
+	use crate::migrations::v1;
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Polls>();
+
+		let old_poll = |status| v1::OldPollDetails::<Test> {
+			created_by: 1,
+			ipfs_cid: (0..46).collect(),
+			options_count: 3,
+			votes: Votes(vec![20, 0, 40]),
+			currency: PollCurrency::Native,
+			status,
+			min_balance: 0,
+		};
+		v1::PollDetailsOf::<Test>::insert(PollCurrency::Native, 2, old_poll(v1::OldPollStatus::Ongoing { start: 1, end: 10 }));
+		v1::PollDetailsOf::<Test>::insert(
+			PollCurrency::Native,
+			1,
+			old_poll(v1::OldPollStatus::Finished { winning_option: Some(2), end: 5 }),
+		);
+		v1::VotingOf::<Test>::insert((2, 2), 0);
+		v1::VotingOf::<Test>::insert((3, 2), 2);
+		v1::VotingOf::<Test>::insert((4, 2), 2);
+		PollCount::<Test>::put(2);
+
+		v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Polls::on_chain_storage_version(), 1);
+		assert_eq!(
+			Polls::voting_of((3, 2)),
+			Some(AccountVote { ballot: Ballot::Single(2), votes: 0 })
+		);
+
+		let poll = Polls::poll_details_of(PollCurrency::Native, 2).unwrap();
+		assert_eq!(poll.ballot_type, BallotType::SingleChoice);
+		assert_eq!(poll.votes, Votes(vec![20, 0, 40]));
+		assert_eq!(poll.turnout, 60);
+		assert_eq!(poll.voters, 3);
+		assert_eq!(poll.status, PollStatus::Ongoing { start: 1, end: 10 });
+
+		let poll = Polls::poll_details_of(PollCurrency::Native, 1).unwrap();
+		assert_eq!(poll.voters, 0);
+		assert_eq!(
+			poll.status,
+			PollStatus::Finished {
+				winning_option: Some(2),
+				end: 5,
+				result: PollResult { votes: Votes(vec![20, 0, 40]), rounds: vec![] },
+			}
+		);
+
+		// Polls are indexed in the order they were created in.
+		assert_eq!(Polls::creator_poll_count(1), 2);
+		assert_eq!(Polls::polls_by_creator(1, 0), Some((PollCurrency::Native, 1)));
+		assert_eq!(Polls::polls_by_creator(1, 1), Some((PollCurrency::Native, 2)));
+		assert_eq!(Polls::currency_poll_count(PollCurrency::Native), 2);
+		assert_eq!(Polls::polls_by_currency(PollCurrency::Native, 0), Some(1));
+		assert_eq!(Polls::polls_by_currency(PollCurrency::Native, 1), Some(2));
+
+		// Votes of version 0 were not locked, so they can't be removed.
+		assert_noop!(Polls::remove_vote(RuntimeOrigin::signed(2), PollCurrency::Native, 2), Error::<Test>::NotLocked);
+	});
+}
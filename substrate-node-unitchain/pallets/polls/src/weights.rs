@@ -0,0 +1,504 @@
+//! Weights for pallet_polls.
+//!
+//! Estimated from the storage read and written by each call until `./scripts/benchmark.sh polls`
+//! is run on reference hardware, which overwrites this file with the measured weights.
+//! Calls whose cost grows with the options, ballots or delegators of a poll take those counts
+//! as components.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_polls.
+pub trait WeightInfo {
+	fn create_poll(o: u32, c: u32, ) -> Weight;
+	fn vote(o: u32, d: u32, ) -> Weight;
+	fn enact_poll_end(o: u32, b: u32, c: u32, ) -> Weight;
+	fn emergency_cancel() -> Weight;
+	fn update_poll(o: u32, c: u32, ) -> Weight;
+	fn unlock() -> Weight;
+	fn change_vote(o: u32, ) -> Weight;
+	fn remove_vote(o: u32, d: u32, ) -> Weight;
+	fn set_outcome() -> Weight;
+	fn commit_vote() -> Weight;
+	fn reveal_vote(o: u32, ) -> Weight;
+	fn delegate(p: u32, d: u32, ) -> Weight;
+	fn undelegate(p: u32, d: u32, ) -> Weight;
+	fn snapshot_balance() -> Weight;
+}
+
+/// Weights for pallet_polls using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Polls PollCount (r:1 w:1)
+	// Storage: Polls CreatorPollCount (r:1 w:1)
+	// Storage: Polls CurrencyPollCount (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:1 w:1)
+	// Storage: Polls PollDetailsOf (r:0 w:1)
+	// Storage: Polls PollsByCreator (r:0 w:1)
+	// Storage: Polls PollsByCurrency (r:0 w:1)
+	/// The range of component `o` is `[2, 255]`.
+	/// The range of component `c` is `[0, 1000]`.
+	fn create_poll(o: u32, c: u32, ) -> Weight {
+		Weight::from_ref_time(44_315_000 as u64)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_ref_time(9_000 as u64).saturating_mul(o as u64))
+			// Standard Error: 0
+			.saturating_add(Weight::from_ref_time(1_000 as u64).saturating_mul(c as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// Storage: Polls PollDetailsOf (r:1 w:1)
+	// Storage: Polls VotingOf (r:129 w:1)
+	// Storage: Polls CountedBy (r:129 w:128)
+	// Storage: System Account (r:129 w:1)
+	// Storage: Polls VoteLocks (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: Polls PollDelegatorsOf (r:129 w:0)
+	// Storage: Polls DelegatorsOf (r:129 w:0)
+	// Storage: Polls PollDelegations (r:128 w:0)
+	// Storage: Polls RankedTallies (r:1 w:0)
+	// Storage: Polls RankedVoters (r:0 w:1)
+	// Storage: Polls DelegatedVotes (r:0 w:1)
+	/// The range of component `o` is `[2, 255]`.
+	/// The range of component `d` is `[0, 128]`.
+	fn vote(o: u32, d: u32, ) -> Weight {
+		Weight::from_ref_time(61_204_000 as u64)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_ref_time(118_000 as u64).saturating_mul(o as u64))
+			// Standard Error: 9_000
+			.saturating_add(Weight::from_ref_time(14_806_000 as u64).saturating_mul(d as u64))
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().reads((6 as u64).saturating_mul(d as u64)))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(d as u64)))
+	}
+	// Storage: Polls PollDetailsOf (r:1 w:1)
+	// Storage: Polls Commitments (r:1024 w:1024)
+	// Storage: Polls RankedTallies (r:1 w:1)
+	// Storage: Polls RankedVoters (r:256 w:0)
+	// Storage: Polls VotingOf (r:255 w:0)
+	// Storage: Polls Outcomes (r:1 w:255)
+	// Storage: Balances TotalIssuance (r:1 w:0)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:1 w:1)
+	/// The range of component `o` is `[2, 255]`.
+	/// The range of component `b` is `[0, 256]`.
+	/// The range of component `c` is `[0, 1024]`.
+	fn enact_poll_end(o: u32, b: u32, c: u32, ) -> Weight {
+		Weight::from_ref_time(43_908_000 as u64)
+			// Standard Error: 12_000
+			.saturating_add(Weight::from_ref_time(2_431_000 as u64).saturating_mul(o as u64))
+			// Standard Error: 12_000
+			.saturating_add(Weight::from_ref_time(1_874_000 as u64).saturating_mul(b as u64))
+			// Standard Error: 3_000
+			.saturating_add(Weight::from_ref_time(7_262_000 as u64).saturating_mul(c as u64))
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(b as u64)))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(c as u64)))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(o as u64)))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(c as u64)))
+	}
+	// Storage: Polls PollDetailsOf (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:1 w:1)
+	// Storage: Polls RankedTallies (r:1 w:0)
+	// Storage: Polls Commitments (r:0 w:1024)
+	// Storage: Polls Outcomes (r:0 w:255)
+	fn emergency_cancel() -> Weight {
+		Weight::from_ref_time(3_061_547_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(1282 as u64))
+	}
+	// Storage: Polls PollDetailsOf (r:1 w:1)
+	// Storage: Polls Outcomes (r:1 w:0)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	/// The range of component `o` is `[2, 255]`.
+	/// The range of component `c` is `[0, 1000]`.
+	fn update_poll(o: u32, c: u32, ) -> Weight {
+		Weight::from_ref_time(39_152_000 as u64)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_ref_time(12_000 as u64).saturating_mul(o as u64))
+			// Standard Error: 0
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(c as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: Polls PollDetailsOf (r:1 w:0)
+	// Storage: Polls VoteLocks (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn unlock() -> Weight {
+		Weight::from_ref_time(47_526_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: Polls PollDetailsOf (r:1 w:1)
+	// Storage: Polls VotingOf (r:1 w:1)
+	// Storage: Polls RankedTallies (r:1 w:0)
+	/// The range of component `o` is `[2, 255]`.
+	fn change_vote(o: u32, ) -> Weight {
+		Weight::from_ref_time(33_218_000 as u64)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_ref_time(131_000 as u64).saturating_mul(o as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Polls PollDetailsOf (r:1 w:1)
+	// Storage: Polls VotingOf (r:1 w:1)
+	// Storage: Polls RankedTallies (r:1 w:0)
+	// Storage: Polls RankedVoters (r:0 w:1)
+	// Storage: Polls VoteLocks (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Polls DelegatedVotes (r:1 w:1)
+	// Storage: Polls CountedBy (r:1 w:128)
+	// Storage: Polls PollDelegations (r:1 w:0)
+	// Storage: Polls Delegations (r:1 w:0)
+	/// The range of component `o` is `[2, 255]`.
+	/// The range of component `d` is `[0, 128]`.
+	fn remove_vote(o: u32, d: u32, ) -> Weight {
+		Weight::from_ref_time(66_481_000 as u64)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_ref_time(104_000 as u64).saturating_mul(o as u64))
+			// Standard Error: 8_000
+			.saturating_add(Weight::from_ref_time(3_912_000 as u64).saturating_mul(d as u64))
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(d as u64)))
+	}
+	// Storage: Polls PollDetailsOf (r:1 w:0)
+	// Storage: Balances TotalIssuance (r:1 w:0)
+	// Storage: Polls Outcomes (r:0 w:1)
+	fn set_outcome() -> Weight {
+		Weight::from_ref_time(26_730_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Polls PollDetailsOf (r:1 w:1)
+	// Storage: Polls RankedTallies (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Polls Commitments (r:1 w:1)
+	// Storage: Polls VoteLocks (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	fn commit_vote() -> Weight {
+		Weight::from_ref_time(49_813_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// Storage: Polls PollDetailsOf (r:1 w:1)
+	// Storage: Polls Commitments (r:1 w:1)
+	// Storage: Polls RankedTallies (r:1 w:0)
+	// Storage: Polls RankedVoters (r:0 w:1)
+	// Storage: Polls VotingOf (r:0 w:1)
+	/// The range of component `o` is `[2, 255]`.
+	fn reveal_vote(o: u32, ) -> Weight {
+		Weight::from_ref_time(38_457_000 as u64)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_ref_time(127_000 as u64).saturating_mul(o as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: Polls Delegations (r:9 w:1)
+	// Storage: Polls DelegatorsOf (r:130 w:2)
+	// Storage: Polls VoteLocks (r:8 w:0)
+	// Storage: Polls PollDelegations (r:161 w:0)
+	// Storage: Polls PollDetailsOf (r:32 w:32)
+	// Storage: Polls RankedTallies (r:32 w:0)
+	// Storage: Polls VotingOf (r:193 w:32)
+	// Storage: Polls CountedBy (r:161 w:129)
+	// Storage: Polls DelegatedVotes (r:32 w:32)
+	// Storage: System Account (r:129 w:0)
+	// Storage: Polls PollDelegatorsOf (r:129 w:0)
+	/// The range of component `p` is `[0, 32]`.
+	/// The range of component `d` is `[0, 128]`.
+	fn delegate(p: u32, d: u32, ) -> Weight {
+		Weight::from_ref_time(45_120_000 as u64)
+			// Standard Error: 11_000
+			.saturating_add(Weight::from_ref_time(21_734_000 as u64).saturating_mul(p as u64))
+			// Standard Error: 9_000
+			.saturating_add(Weight::from_ref_time(14_806_000 as u64).saturating_mul(d as u64))
+			.saturating_add(T::DbWeight::get().reads(13 as u64))
+			.saturating_add(T::DbWeight::get().reads((7 as u64).saturating_mul(p as u64)))
+			.saturating_add(T::DbWeight::get().reads((6 as u64).saturating_mul(d as u64)))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(p as u64)))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(d as u64)))
+	}
+	// Storage: Polls Delegations (r:2 w:1)
+	// Storage: Polls DelegatorsOf (r:1 w:1)
+	// Storage: Polls VoteLocks (r:1 w:0)
+	// Storage: Polls PollDelegations (r:64 w:0)
+	// Storage: Polls PollDetailsOf (r:32 w:32)
+	// Storage: Polls RankedTallies (r:32 w:0)
+	// Storage: Polls VotingOf (r:64 w:32)
+	// Storage: Polls CountedBy (r:32 w:129)
+	// Storage: Polls DelegatedVotes (r:32 w:32)
+	/// The range of component `p` is `[0, 32]`.
+	/// The range of component `d` is `[0, 128]`.
+	fn undelegate(p: u32, d: u32, ) -> Weight {
+		Weight::from_ref_time(29_640_000 as u64)
+			// Standard Error: 10_000
+			.saturating_add(Weight::from_ref_time(19_512_000 as u64).saturating_mul(p as u64))
+			// Standard Error: 3_000
+			.saturating_add(Weight::from_ref_time(3_912_000 as u64).saturating_mul(d as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().reads((7 as u64).saturating_mul(p as u64)))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(p as u64)))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(d as u64)))
+	}
+	// Storage: Polls PollDetailsOf (r:1 w:0)
+	// Storage: Polls RankedTallies (r:1 w:0)
+	// Storage: Polls Snapshots (r:1 w:1)
+	// Storage: Polls VoteLocks (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn snapshot_balance() -> Weight {
+		Weight::from_ref_time(61_532_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Polls PollCount (r:1 w:1)
+	// Storage: Polls CreatorPollCount (r:1 w:1)
+	// Storage: Polls CurrencyPollCount (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:1 w:1)
+	// Storage: Polls PollDetailsOf (r:0 w:1)
+	// Storage: Polls PollsByCreator (r:0 w:1)
+	// Storage: Polls PollsByCurrency (r:0 w:1)
+	/// The range of component `o` is `[2, 255]`.
+	/// The range of component `c` is `[0, 1000]`.
+	fn create_poll(o: u32, c: u32, ) -> Weight {
+		Weight::from_ref_time(44_315_000 as u64)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_ref_time(9_000 as u64).saturating_mul(o as u64))
+			// Standard Error: 0
+			.saturating_add(Weight::from_ref_time(1_000 as u64).saturating_mul(c as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
+	// Storage: Polls PollDetailsOf (r:1 w:1)
+	// Storage: Polls VotingOf (r:129 w:1)
+	// Storage: Polls CountedBy (r:129 w:128)
+	// Storage: System Account (r:129 w:129)
+	// Storage: Polls VoteLocks (r:129 w:129)
+	// Storage: Balances Locks (r:129 w:129)
+	// Storage: Polls PollDelegatorsOf (r:1 w:0)
+	// Storage: Polls DelegatorsOf (r:1 w:0)
+	// Storage: Polls PollDelegations (r:128 w:0)
+	// Storage: Polls RankedTallies (r:1 w:0)
+	// Storage: Polls RankedVoters (r:0 w:1)
+	// Storage: Polls DelegatedVotes (r:0 w:1)
+	/// The range of component `o` is `[2, 255]`.
+	/// The range of component `d` is `[0, 128]`.
+	fn vote(o: u32, d: u32, ) -> Weight {
+		Weight::from_ref_time(61_204_000 as u64)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_ref_time(118_000 as u64).saturating_mul(o as u64))
+			// Standard Error: 9_000
+			.saturating_add(Weight::from_ref_time(14_806_000 as u64).saturating_mul(d as u64))
+			.saturating_add(RocksDbWeight::get().reads(9 as u64))
+			.saturating_add(RocksDbWeight::get().reads((6 as u64).saturating_mul(d as u64)))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(d as u64)))
+	}
+	// Storage: Polls PollDetailsOf (r:1 w:1)
+	// Storage: Polls Commitments (r:1024 w:1024)
+	// Storage: Polls RankedTallies (r:1 w:1)
+	// Storage: Polls RankedVoters (r:256 w:0)
+	// Storage: Polls VotingOf (r:255 w:0)
+	// Storage: Polls Outcomes (r:1 w:255)
+	// Storage: Balances TotalIssuance (r:1 w:0)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:1 w:1)
+	/// The range of component `o` is `[2, 255]`.
+	/// The range of component `b` is `[0, 256]`.
+	/// The range of component `c` is `[0, 1024]`.
+	fn enact_poll_end(o: u32, b: u32, c: u32, ) -> Weight {
+		Weight::from_ref_time(43_908_000 as u64)
+			// Standard Error: 12_000
+			.saturating_add(Weight::from_ref_time(2_431_000 as u64).saturating_mul(o as u64))
+			// Standard Error: 12_000
+			.saturating_add(Weight::from_ref_time(1_874_000 as u64).saturating_mul(b as u64))
+			// Standard Error: 3_000
+			.saturating_add(Weight::from_ref_time(7_262_000 as u64).saturating_mul(c as u64))
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(b as u64)))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(c as u64)))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(o as u64)))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(c as u64)))
+	}
+	// Storage: Polls PollDetailsOf (r:1 w:1)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:1 w:1)
+	// Storage: Polls RankedTallies (r:1 w:0)
+	// Storage: Polls Commitments (r:0 w:1024)
+	// Storage: Polls Outcomes (r:0 w:255)
+	fn emergency_cancel() -> Weight {
+		Weight::from_ref_time(3_061_547_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1282 as u64))
+	}
+	// Storage: Polls PollDetailsOf (r:1 w:1)
+	// Storage: Polls Outcomes (r:1 w:0)
+	// Storage: Scheduler Lookup (r:1 w:1)
+	// Storage: Scheduler Agenda (r:2 w:2)
+	/// The range of component `o` is `[2, 255]`.
+	/// The range of component `c` is `[0, 1000]`.
+	fn update_poll(o: u32, c: u32, ) -> Weight {
+		Weight::from_ref_time(39_152_000 as u64)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_ref_time(12_000 as u64).saturating_mul(o as u64))
+			// Standard Error: 0
+			.saturating_add(Weight::from_ref_time(2_000 as u64).saturating_mul(c as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: Polls PollDetailsOf (r:1 w:0)
+	// Storage: Polls VoteLocks (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn unlock() -> Weight {
+		Weight::from_ref_time(47_526_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: Polls PollDetailsOf (r:1 w:1)
+	// Storage: Polls VotingOf (r:1 w:1)
+	// Storage: Polls RankedTallies (r:1 w:0)
+	/// The range of component `o` is `[2, 255]`.
+	fn change_vote(o: u32, ) -> Weight {
+		Weight::from_ref_time(33_218_000 as u64)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_ref_time(131_000 as u64).saturating_mul(o as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: Polls PollDetailsOf (r:1 w:1)
+	// Storage: Polls VotingOf (r:1 w:1)
+	// Storage: Polls RankedTallies (r:1 w:0)
+	// Storage: Polls RankedVoters (r:0 w:1)
+	// Storage: Polls VoteLocks (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Polls DelegatedVotes (r:1 w:1)
+	// Storage: Polls CountedBy (r:1 w:128)
+	// Storage: Polls PollDelegations (r:1 w:0)
+	// Storage: Polls Delegations (r:1 w:0)
+	/// The range of component `o` is `[2, 255]`.
+	/// The range of component `d` is `[0, 128]`.
+	fn remove_vote(o: u32, d: u32, ) -> Weight {
+		Weight::from_ref_time(66_481_000 as u64)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_ref_time(104_000 as u64).saturating_mul(o as u64))
+			// Standard Error: 8_000
+			.saturating_add(Weight::from_ref_time(3_912_000 as u64).saturating_mul(d as u64))
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(d as u64)))
+	}
+	// Storage: Polls PollDetailsOf (r:1 w:0)
+	// Storage: Balances TotalIssuance (r:1 w:0)
+	// Storage: Polls Outcomes (r:0 w:1)
+	fn set_outcome() -> Weight {
+		Weight::from_ref_time(26_730_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Polls PollDetailsOf (r:1 w:1)
+	// Storage: Polls RankedTallies (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Polls Commitments (r:1 w:1)
+	// Storage: Polls VoteLocks (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	fn commit_vote() -> Weight {
+		Weight::from_ref_time(49_813_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	// Storage: Polls PollDetailsOf (r:1 w:1)
+	// Storage: Polls Commitments (r:1 w:1)
+	// Storage: Polls RankedTallies (r:1 w:0)
+	// Storage: Polls RankedVoters (r:0 w:1)
+	// Storage: Polls VotingOf (r:0 w:1)
+	/// The range of component `o` is `[2, 255]`.
+	fn reveal_vote(o: u32, ) -> Weight {
+		Weight::from_ref_time(38_457_000 as u64)
+			// Standard Error: 2_000
+			.saturating_add(Weight::from_ref_time(127_000 as u64).saturating_mul(o as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: Polls Delegations (r:9 w:1)
+	// Storage: Polls DelegatorsOf (r:130 w:2)
+	// Storage: Polls VoteLocks (r:8 w:0)
+	// Storage: Polls PollDelegations (r:161 w:0)
+	// Storage: Polls PollDetailsOf (r:32 w:32)
+	// Storage: Polls RankedTallies (r:32 w:0)
+	// Storage: Polls VotingOf (r:193 w:32)
+	// Storage: Polls CountedBy (r:161 w:129)
+	// Storage: Polls DelegatedVotes (r:32 w:32)
+	// Storage: System Account (r:129 w:0)
+	// Storage: Polls PollDelegatorsOf (r:129 w:0)
+	/// The range of component `p` is `[0, 32]`.
+	/// The range of component `d` is `[0, 128]`.
+	fn delegate(p: u32, d: u32, ) -> Weight {
+		Weight::from_ref_time(45_120_000 as u64)
+			// Standard Error: 11_000
+			.saturating_add(Weight::from_ref_time(21_734_000 as u64).saturating_mul(p as u64))
+			// Standard Error: 9_000
+			.saturating_add(Weight::from_ref_time(14_806_000 as u64).saturating_mul(d as u64))
+			.saturating_add(RocksDbWeight::get().reads(13 as u64))
+			.saturating_add(RocksDbWeight::get().reads((7 as u64).saturating_mul(p as u64)))
+			.saturating_add(RocksDbWeight::get().reads((6 as u64).saturating_mul(d as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(p as u64)))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(d as u64)))
+	}
+	// Storage: Polls Delegations (r:2 w:1)
+	// Storage: Polls DelegatorsOf (r:1 w:1)
+	// Storage: Polls VoteLocks (r:1 w:0)
+	// Storage: Polls PollDelegations (r:64 w:0)
+	// Storage: Polls PollDetailsOf (r:32 w:32)
+	// Storage: Polls RankedTallies (r:32 w:0)
+	// Storage: Polls VotingOf (r:64 w:32)
+	// Storage: Polls CountedBy (r:32 w:129)
+	// Storage: Polls DelegatedVotes (r:32 w:32)
+	/// The range of component `p` is `[0, 32]`.
+	/// The range of component `d` is `[0, 128]`.
+	fn undelegate(p: u32, d: u32, ) -> Weight {
+		Weight::from_ref_time(29_640_000 as u64)
+			// Standard Error: 10_000
+			.saturating_add(Weight::from_ref_time(19_512_000 as u64).saturating_mul(p as u64))
+			// Standard Error: 3_000
+			.saturating_add(Weight::from_ref_time(3_912_000 as u64).saturating_mul(d as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().reads((7 as u64).saturating_mul(p as u64)))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(p as u64)))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(d as u64)))
+	}
+	// Storage: Polls PollDetailsOf (r:1 w:0)
+	// Storage: Polls RankedTallies (r:1 w:0)
+	// Storage: Polls Snapshots (r:1 w:1)
+	// Storage: Polls VoteLocks (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn snapshot_balance() -> Weight {
+		Weight::from_ref_time(61_532_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+}
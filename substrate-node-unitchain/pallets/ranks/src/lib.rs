@@ -191,3 +191,24 @@ pub mod pallet {
 		}
 	}
 }
+
+/// Lookup of the ranks of an asset, used by other pallets to gate actions on a rank.
+pub trait RankInspect<AssetId, Balance> {
+	/// Minimum amount of tokens of `asset_id` needed to hold the rank called `name`.
+	fn rank_min_tokens(asset_id: AssetId, name: &[u8]) -> Option<Balance>;
+}
+
+impl<T: Config> RankInspect<T::AssetId, BalanceOf<T>> for Pallet<T> {
+	fn rank_min_tokens(asset_id: T::AssetId, name: &[u8]) -> Option<BalanceOf<T>> {
+		Ranks::<T>::get(asset_id)?
+			.into_iter()
+			.find(|rank| rank.name == name)
+			.map(|rank| rank.min_tokens)
+	}
+}
+
+impl<AssetId, Balance> RankInspect<AssetId, Balance> for () {
+	fn rank_min_tokens(_asset_id: AssetId, _name: &[u8]) -> Option<Balance> {
+		None
+	}
+}
@@ -128,10 +128,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 102,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -263,8 +263,7 @@ impl pallet_grandpa::Config for Runtime {
 
 
 parameter_types! {
-//  pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * BlockWeights::get().max_block;
-  pub MaximumSchedulerWeight: Weight = Weight::zero().set_ref_time(10).set_proof_size(10);
+  pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * BlockWeights::get().max_block;
   pub const MaxScheduledPerBlock: u32 = 50;
 }
 impl pallet_scheduler::Config for Runtime {
@@ -444,7 +443,7 @@ impl pallet_dex::Config for Runtime {
     // type AssetId = u32;
     type Assets = Assets;
     type AssetRegistry = Assets;
-    type WeightInfo = ();
+    type WeightInfo = pallet_dex::weights::SubstrateWeight<Runtime>;
     // Provider fee is 0.3%
     type ProviderFeeNumerator = ConstU128<2>;
     type ProviderFeeDenominator = ConstU128<100>;
@@ -471,6 +470,19 @@ impl pallet_papers::Config for Runtime {
 
 type PollIndex = u64;
 
+parameter_types! {
+	pub const PollsPalletId: PalletId = PalletId(*b"unitpoll");
+	pub const MaxVotes: u32 = 32;
+	pub const VoteLockingPeriod: BlockNumber = 7 * DAYS;
+	pub const MaxRankedBallotsPerBlock: u32 = 256;
+	// The scheduler has no preimages, it only accepts calls bounded inline.
+	pub const MaxOutcomeLen: u32 = 128;
+	pub const MinOutcomeTurnout: Perbill = Perbill::from_percent(10);
+	pub const MaxCommitments: u32 = 1024;
+	pub const MaxDelegators: u32 = 128;
+	pub const MaxDelegationDepth: u32 = 4;
+}
+
 impl pallet_polls::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
@@ -479,6 +491,16 @@ impl pallet_polls::Config for Runtime {
 	type PollCall = RuntimeCall;
 	type Scheduler = Scheduler;
 	type PalletsOrigin = OriginCaller;
+	type PalletId = PollsPalletId;
+	type MaxVotes = MaxVotes;
+	type VoteLockingPeriod = VoteLockingPeriod;
+	type MaxRankedBallotsPerBlock = MaxRankedBallotsPerBlock;
+	type MaxOutcomeLen = MaxOutcomeLen;
+	type MinOutcomeTurnout = MinOutcomeTurnout;
+	type MaxCommitments = MaxCommitments;
+	type MaxDelegators = MaxDelegators;
+	type MaxDelegationDepth = MaxDelegationDepth;
+	type WeightInfo = pallet_polls::weights::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
@@ -487,12 +509,20 @@ parameter_types! {
     pub const MinTitleLength: u32 = 10;
     pub const MaxTokenSymbolLength: u32 = 10;
     pub const MinTokenSymbolLength: u32 = 3;
-    pub const MaxContestEndDateLength: u32 = 12;
-    pub const MinContestEndDateLength: u32 = 8;
+    pub const MinContestDuration: BlockNumber = HOURS;
     pub const MaxDescriptionLength: u32 = 350;
     pub const MinDescriptionLength: u32 = 50;
     pub const MinTokenAmount: u32 = 10;
     pub const MinTokenWinner: u32 = 1;
+    pub const MaxJudges: u32 = 5;
+    pub const MaxPrizeTiers: u32 = 10;
+    pub const MaxPrizeComponents: u32 = 5;
+    pub const MaxSponsorships: u32 = 20;
+    pub const MaxSubmissionLength: u32 = 100;
+    pub const MaxEntriesPerAccount: u32 = 3;
+    pub const MaxEntriesPerContest: u32 = 500;
+    pub const MaxRankNameLength: u32 = 32;
+    pub const DisputeDeposit: Balance = DOLLARS;
 }
 
 impl pallet_contests::Config for Runtime {
@@ -500,17 +530,35 @@ impl pallet_contests::Config for Runtime {
     type Assets = Assets;
     type AssetBalance = u128;
     type AssetId = u32;
+    type Currency = Balances;
+    type Ranks = Ranks;
     type PalletId = ContestPalletId;
     type MaxTitleLength = MaxTitleLength;
     type MinTitleLength = MinTitleLength;
     type MaxTokenSymbolLength = MaxTokenSymbolLength;
     type MinTokenSymbolLength = MinTokenSymbolLength;
-    type MaxContestEndDateLength = MaxContestEndDateLength;
-    type MinContestEndDateLength = MinContestEndDateLength;
+    type MinContestDuration = MinContestDuration;
     type MaxDescriptionLength = MaxDescriptionLength;
     type MinDescriptionLength = MinDescriptionLength;
     type MinTokenAmount = MinTokenAmount;
     type MinTokenWinner = MinTokenWinner;
+    type MaxJudges = MaxJudges;
+    type MaxPrizeTiers = MaxPrizeTiers;
+    type MaxPrizeComponents = MaxPrizeComponents;
+    type MaxSponsorships = MaxSponsorships;
+    type MaxSubmissionLength = MaxSubmissionLength;
+    type MaxEntriesPerAccount = MaxEntriesPerAccount;
+    type MaxEntriesPerContest = MaxEntriesPerContest;
+    type MaxRankNameLength = MaxRankNameLength;
+    type DisputeDeposit = DisputeDeposit;
+    // Disputed payouts are resolved by root or by a poll of the native token holders.
+    type DisputeOrigin = pallet_polls::EnsureNativeTokenHolders<Runtime>;
+    type PalletsOrigin = OriginCaller;
+    type ContestCall = RuntimeCall;
+    type Scheduler = Scheduler;
+    type WeightInfo = pallet_contests::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = pallet_contests::AssetsBenchmarkHelper<Assets>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -572,6 +620,11 @@ pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+/// Storage migrations run on runtime upgrade.
+pub type Migrations = (
+	pallet_contests::migrations::v1::MigrateToV1<Runtime>,
+	pallet_polls::migrations::v1::MigrateToV1<Runtime>,
+);
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -579,6 +632,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -593,6 +647,9 @@ mod benches {
 		[pallet_balances, Balances]
 		[pallet_timestamp, Timestamp]
 		[pallet_template, TemplateModule]
+		[pallet_contests, Contests]
+		[pallet_polls, Polls]
+		[pallet_dex, Dex]
 	);
 }
 
@@ -712,6 +769,87 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_contests_runtime_api::ContestsApi<Block, AccountId, u32, Balance, BlockNumber> for Runtime {
+		fn contests_by_asset(
+			asset_id: u32,
+			open: bool,
+		) -> Vec<pallet_contests_runtime_api::ContestInfo<AccountId, u32, Balance, BlockNumber>> {
+			Contests::contests_by_asset(asset_id, open)
+		}
+		fn contests_by_creator(
+			creator: AccountId,
+			open: bool,
+		) -> Vec<pallet_contests_runtime_api::ContestInfo<AccountId, u32, Balance, BlockNumber>> {
+			Contests::contests_of_creator(creator, open)
+		}
+		fn contest_entries(
+			contest_id: u32,
+			offset: u32,
+			limit: u32,
+		) -> Vec<pallet_contests_runtime_api::EntryInfo<AccountId>> {
+			Contests::contest_entries_page(contest_id, offset, limit)
+		}
+		fn contest_escrow(
+			contest_id: u32,
+		) -> Option<pallet_contests_runtime_api::EscrowInfo<u32, Balance>> {
+			Contests::contest_escrow(contest_id)
+		}
+		fn contest_payouts(
+			contest_id: u32,
+		) -> Vec<pallet_contests_runtime_api::PayoutInfo<AccountId, Balance, BlockNumber>> {
+			Contests::contest_payouts(contest_id)
+		}
+	}
+
+	impl pallet_polls_runtime_api::PollsApi<Block, AccountId, u32, Balance, BlockNumber, PollIndex> for Runtime {
+		fn polls_by_creator(
+			creator: AccountId,
+			status: Option<pallet_polls_runtime_api::PollStatusFilter>,
+			voter: Option<AccountId>,
+			offset: u32,
+			limit: u32,
+		) -> Vec<pallet_polls_runtime_api::PollInfo<PollIndex, AccountId, u32, Balance, BlockNumber>> {
+			Polls::polls_of_creator(creator, status, voter, offset, limit)
+		}
+		fn polls_by_currency(
+			currency: pallet_polls_runtime_api::PollCurrency<u32>,
+			status: Option<pallet_polls_runtime_api::PollStatusFilter>,
+			voter: Option<AccountId>,
+			offset: u32,
+			limit: u32,
+		) -> Vec<pallet_polls_runtime_api::PollInfo<PollIndex, AccountId, u32, Balance, BlockNumber>> {
+			Polls::polls_of_currency(currency, status, voter, offset, limit)
+		}
+		fn poll(
+			currency: pallet_polls_runtime_api::PollCurrency<u32>,
+			poll_id: PollIndex,
+			voter: Option<AccountId>,
+		) -> Option<pallet_polls_runtime_api::PollInfo<PollIndex, AccountId, u32, Balance, BlockNumber>> {
+			Polls::poll_info(currency, poll_id, voter)
+		}
+	}
+
+	impl pallet_dex_runtime_api::DexApi<Block, AssetId, Balance> for Runtime {
+		fn swap_path(
+			asset_in: pallet_dex_runtime_api::SwapAsset<AssetId>,
+			asset_out: pallet_dex_runtime_api::SwapAsset<AssetId>,
+		) -> Vec<pallet_dex_runtime_api::SwapAsset<AssetId>> {
+			Dex::swap_path(asset_in, asset_out)
+		}
+		fn quote_exact_in(
+			path: Vec<pallet_dex_runtime_api::SwapAsset<AssetId>>,
+			amount_in: Balance,
+		) -> Option<Balance> {
+			Dex::quote_exact_in(path, amount_in)
+		}
+		fn quote_exact_out(
+			path: Vec<pallet_dex_runtime_api::SwapAsset<AssetId>>,
+			amount_out: Balance,
+		) -> Option<Balance> {
+			Dex::quote_exact_out(path, amount_out)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,
@@ -0,0 +1,24 @@
+#!/usr/bin/env bash
+# This script is meant to be run on Unix/Linux based systems
+set -e
+
+echo "*** Benchmarking pallets and writing their weights ***"
+
+cd $(dirname ${BASH_SOURCE[0]})/..
+
+PALLETS=${@:-contests polls dex}
+
+cargo build --release --features runtime-benchmarks
+
+for pallet in $PALLETS; do
+	./target/release/node-unitchain benchmark pallet \
+		--chain=dev \
+		--execution=wasm \
+		--wasm-execution=compiled \
+		--pallet=pallet_$pallet \
+		--extrinsic='*' \
+		--steps=50 \
+		--repeat=20 \
+		--output=./pallets/$pallet/src/weights.rs \
+		--template=./.maintain/frame-weight-template.hbs
+done